@@ -0,0 +1,49 @@
+// compares dijkstra and astar on a 100 x 100 map with random walls
+// run with: cargo bench --bench map_two_dim_pathfinding
+
+use my_lib::my_compass::Compass;
+use my_lib::my_map_point::MapPoint;
+use my_lib::my_map_two_dim::MyMap2D;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const X: usize = 100;
+const Y: usize = 100;
+const ITERATIONS: u32 = 100;
+
+fn time_per_iteration(mut f: impl FnMut()) -> Duration {
+    // warm up
+    f();
+    let timer = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    timer.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut map: MyMap2D<bool, X, Y> = MyMap2D::init(true);
+    for _ in 0..(X * Y / 5) {
+        map.set(
+            MapPoint::new(rng.gen_range(0..X), rng.gen_range(0..Y)),
+            false,
+        );
+    }
+    let start = MapPoint::<X, Y>::NW;
+    let end = MapPoint::<X, Y>::SE;
+    map.set(start, true);
+    map.set(end, true);
+    let cost_fn =
+        |_: MapPoint<X, Y>, free: &bool, _: Compass, _: MapPoint<X, Y>, _: &bool| free.then_some(1);
+
+    let dijkstra = time_per_iteration(|| {
+        black_box(map.dijkstra(black_box(start), black_box(end), cost_fn));
+    });
+    let astar = time_per_iteration(|| {
+        black_box(map.astar_manhattan(black_box(start), black_box(end), cost_fn));
+    });
+    println!("dijkstra: {:?} per search", dijkstra);
+    println!("astar:    {:?} per search", astar);
+}
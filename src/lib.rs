@@ -15,3 +15,4 @@ pub mod my_simple_binary_tree;
 pub mod my_steady_solution_tree;
 pub mod my_tic_tac_toe;
 pub mod my_tree;
+pub mod my_union_find;
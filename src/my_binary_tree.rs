@@ -3,6 +3,13 @@ use std::cmp::Ordering;
 use std::rc::Rc;
 use std::rc::Weak;
 
+type SplitTrees<N> = (Option<Rc<BinaryTreeNode<N>>>, Option<Rc<BinaryTreeNode<N>>>);
+type SplitTreesAtValue<N> = (
+    Option<Rc<BinaryTreeNode<N>>>,
+    Option<Rc<BinaryTreeNode<N>>>,
+    Option<Rc<BinaryTreeNode<N>>>,
+);
+
 struct PreOrderTraversal<N> {
     next_node: Rc<BinaryTreeNode<N>>,
     horizontal: bool, // false: left, true: right
@@ -375,9 +382,46 @@ impl<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone> Iterator for PathToNod
     }
 }
 
+struct RangeIter<N> {
+    next_node: Option<Rc<BinaryTreeNode<N>>>,
+    hi: N,
+}
+
+impl<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone> RangeIter<N> {
+    fn new(root: Rc<BinaryTreeNode<N>>, lo: N, hi: N) -> Self {
+        // descend to first node >= lo
+        let mut next_node: Option<Rc<BinaryTreeNode<N>>> = None;
+        let mut current_node = Some(root);
+        while let Some(node) = current_node {
+            if node.value >= lo {
+                current_node = node.get_left();
+                next_node = Some(node);
+            } else {
+                current_node = node.get_right();
+            }
+        }
+        RangeIter { next_node, hi }
+    }
+}
+
+impl<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone> Iterator for RangeIter<N> {
+    type Item = Rc<BinaryTreeNode<N>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next_node.take()?;
+        if node.value > self.hi {
+            return None;
+        }
+        self.next_node = node.successor();
+        Some(node)
+    }
+}
+
 pub struct BinaryTreeNode<N> {
     value: N,
     count: RefCell<usize>,
+    balance: RefCell<i8>, // height of right subtree - height of left subtree; only used by balanced functions
+    subtree_size: RefCell<usize>, // number of nodes in subtree including self
     node: RefCell<Weak<BinaryTreeNode<N>>>,
     parent: RefCell<Weak<BinaryTreeNode<N>>>,
     left: RefCell<Option<Rc<BinaryTreeNode<N>>>>,
@@ -389,6 +433,8 @@ impl<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone> BinaryTreeNode<N> {
         let result = Rc::new(BinaryTreeNode {
             value,
             count: RefCell::new(1),
+            balance: RefCell::new(0),
+            subtree_size: RefCell::new(1),
             node: RefCell::new(Weak::new()), // weak reference on itself!
             parent: RefCell::new(Weak::new()),
             left: RefCell::new(None),
@@ -398,6 +444,49 @@ impl<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone> BinaryTreeNode<N> {
         *result.node.borrow_mut() = node;
         result
     }
+    pub fn from_sorted_slice(sorted: &[N]) -> Rc<BinaryTreeNode<N>> {
+        // builds perfectly balanced tree; equal values are merged into count of one node
+        // balance factors are set, therefore tree can be modified with balanced functions
+        if sorted.is_empty() {
+            panic!("line {}, slice must not be empty", line!());
+        }
+        let mut values: Vec<(N, usize)> = Vec::with_capacity(sorted.len());
+        for value in sorted.iter() {
+            match values.last_mut() {
+                Some((last, count)) if *last == *value => *count += 1,
+                Some((last, _)) if *last > *value => {
+                    panic!("line {}, slice is not sorted", line!())
+                }
+                _ => values.push((*value, 1)),
+            }
+        }
+        BinaryTreeNode::build_balanced(&values[..]).unwrap().0
+    }
+    fn build_balanced(values: &[(N, usize)]) -> Option<(Rc<BinaryTreeNode<N>>, usize)> {
+        // returns root of subtree and its height
+        if values.is_empty() {
+            return None;
+        }
+        let mid = values.len() / 2;
+        let node = BinaryTreeNode::new(values[mid].0);
+        *node.count.borrow_mut() = values[mid].1;
+        let mut heights = [0, 0];
+        for (index, subtree) in [&values[..mid], &values[mid + 1..]].iter().enumerate() {
+            if let Some((child, height)) = BinaryTreeNode::build_balanced(subtree) {
+                *child.parent.borrow_mut() = Rc::downgrade(&node);
+                if index == 0 {
+                    *node.left.borrow_mut() = Some(child);
+                } else {
+                    *node.right.borrow_mut() = Some(child);
+                }
+                heights[index] = height;
+            }
+        }
+        *node.balance.borrow_mut() = heights[1] as i8 - heights[0] as i8;
+        node.update_subtree_size();
+        let height = 1 + heights[0].max(heights[1]);
+        Some((node, height))
+    }
     pub fn append_value(&self, value: N) -> Rc<BinaryTreeNode<N>> {
         match self.value.cmp(&value) {
             Ordering::Equal => {
@@ -413,6 +502,7 @@ impl<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone> BinaryTreeNode<N> {
                 } else {
                     let left = BinaryTreeNode::new(value);
                     *left.parent.borrow_mut() = self.node.borrow().clone();
+                    self.increment_subtree_sizes();
                     let node = Rc::downgrade(&left);
                     *left.node.borrow_mut() = node;
                     Some(left)
@@ -427,6 +517,7 @@ impl<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone> BinaryTreeNode<N> {
                 } else {
                     let right = BinaryTreeNode::new(value);
                     *right.parent.borrow_mut() = self.node.borrow().clone();
+                    self.increment_subtree_sizes();
                     let node = Rc::downgrade(&right);
                     *right.node.borrow_mut() = node;
                     Some(right)
@@ -436,12 +527,662 @@ impl<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone> BinaryTreeNode<N> {
             }
         }
     }
+    pub fn remove(&self, value: N) -> Option<Rc<BinaryTreeNode<N>>> {
+        // call remove on root of tree. If value has count > 1, only count is decremented.
+        // Returns new root of tree or None, if tree is empty after removal.
+        self.remove_value(value, false)
+    }
+    pub fn remove_balanced(&self, value: N) -> Option<Rc<BinaryTreeNode<N>>> {
+        // same as remove(), but rebalances tree afterward. Use only on trees build with append_value_balanced()
+        debug_assert!(
+            self.has_valid_balance_on_path(value),
+            "stale balance factors; tree was modified with unbalanced functions"
+        );
+        self.remove_value(value, true)
+    }
+    fn remove_value(&self, value: N, rebalance: bool) -> Option<Rc<BinaryTreeNode<N>>> {
+        let root = self.get_self().unwrap();
+        // descend only through children, since get_next_smaller() and get_next_bigger() may return a parent
+        let mut target = root.clone();
+        while target.value != value {
+            let child = match value.cmp(&target.value) {
+                Ordering::Less => target.get_left(),
+                _ => target.get_right(),
+            };
+            target = match child {
+                Some(node) => node,
+                None => return Some(root),
+            };
+        }
+        if target.get_count() > 1 {
+            *target.count.borrow_mut() -= 1;
+            return Some(root);
+        }
+        // retrace_start: node and side (true: left), on which height of subtree decreased
+        let (replacement, retrace_start) = match (target.get_left(), target.get_right()) {
+            (Some(left), Some(right)) => {
+                // in order successor is smallest node of right subtree
+                let mut successor = right.clone();
+                while let Some(node) = successor.get_left() {
+                    successor = node;
+                }
+                let retrace_start = if Rc::ptr_eq(&successor, &right) {
+                    (successor.clone(), false)
+                } else {
+                    let successor_parent = successor.get_parent().unwrap();
+                    successor.replace_in_parent(successor.get_right());
+                    *successor.right.borrow_mut() = Some(right.clone());
+                    *right.parent.borrow_mut() = Rc::downgrade(&successor);
+                    (successor_parent, true)
+                };
+                *successor.left.borrow_mut() = Some(left.clone());
+                *left.parent.borrow_mut() = Rc::downgrade(&successor);
+                *successor.balance.borrow_mut() = target.get_balance();
+                (Some(successor), Some(retrace_start))
+            }
+            (left, right) => {
+                let retrace_start = target
+                    .get_parent()
+                    .map(|parent| (parent.clone(), parent.is_left_child(&target)));
+                (left.or(right), retrace_start)
+            }
+        };
+        target.replace_in_parent(replacement.clone());
+        *target.left.borrow_mut() = None;
+        *target.right.borrow_mut() = None;
+        *target.subtree_size.borrow_mut() = 1;
+        if let Some((ref node, _)) = retrace_start {
+            let mut current_node = Some(node.clone());
+            while let Some(node) = current_node {
+                node.update_subtree_size();
+                current_node = node.get_parent();
+            }
+        }
+        if rebalance {
+            if let Some((node, decreased_left)) = retrace_start {
+                return Some(node.retrace_removal(decreased_left));
+            }
+        }
+        if Rc::ptr_eq(&target, &root) {
+            replacement
+        } else {
+            Some(root)
+        }
+    }
+    pub fn append_value_balanced(&self, value: N) -> Rc<BinaryTreeNode<N>> {
+        // call append_value_balanced on root of tree. Rotations keep tree balanced (AVL tree), which
+        // may change root of tree. Returns new root of tree.
+        // Do not mix with append_value(), since append_value() does not update balance factors.
+        debug_assert!(
+            self.has_valid_balance_on_path(value),
+            "stale balance factors; tree was modified with unbalanced functions"
+        );
+        self.append_value(value);
+        // append_value() returns child of self, therefore search appended node
+        let mut new_node = self.get_self().unwrap();
+        while new_node.value != value {
+            new_node = match value.cmp(&new_node.value) {
+                Ordering::Less => new_node.get_left().unwrap(),
+                _ => new_node.get_right().unwrap(),
+            };
+        }
+        if new_node.get_count() > 1 {
+            return self.get_root();
+        }
+        let mut child = new_node;
+        while let Some(parent) = child.get_parent() {
+            *parent.balance.borrow_mut() += if parent.is_left_child(&child) { -1 } else { 1 };
+            match parent.get_balance() {
+                0 => break,
+                -1 | 1 => child = parent,
+                _ => {
+                    // after insertion rotation restores height of subtree
+                    // new root of subtree may be new root of tree, which is only kept alive by
+                    // return value of rotate_for_balance()
+                    let (subtree_root, _) = parent.rotate_for_balance();
+                    return subtree_root.get_root();
+                }
+            }
+        }
+        self.get_root()
+    }
+    fn has_valid_balance_on_path(&self, value: N) -> bool {
+        // checks balance factors on search path of value against local AVL invariants. Detects stale
+        // balance factors of nodes, which were changed by append_value() or remove(), in O(log n).
+        let is_leaf =
+            |node: &Rc<BinaryTreeNode<N>>| node.get_left().is_none() && node.get_right().is_none();
+        let mut current_node = self.get_self();
+        while let Some(node) = current_node {
+            let valid = match (node.get_left(), node.get_right()) {
+                (Some(_), Some(_)) => node.get_balance().abs() <= 1,
+                (Some(left), None) => node.get_balance() == -1 && is_leaf(&left),
+                (None, Some(right)) => node.get_balance() == 1 && is_leaf(&right),
+                (None, None) => node.get_balance() == 0,
+            };
+            if !valid {
+                return false;
+            }
+            current_node = match value.cmp(&node.value) {
+                Ordering::Less => node.get_left(),
+                Ordering::Greater => node.get_right(),
+                Ordering::Equal => None,
+            };
+        }
+        true
+    }
+    fn retrace_removal(&self, mut decreased_left: bool) -> Rc<BinaryTreeNode<N>> {
+        // update balance factors from self up to root after removal; returns root of tree
+        let mut node = self.get_self().unwrap();
+        loop {
+            *node.balance.borrow_mut() += if decreased_left { 1 } else { -1 };
+            let subtree_root = match node.get_balance() {
+                -1 | 1 => return node.get_root(), // height of subtree is unchanged
+                0 => node,
+                _ => {
+                    let (subtree_root, height_unchanged) = node.rotate_for_balance();
+                    if height_unchanged {
+                        return subtree_root.get_root();
+                    }
+                    subtree_root
+                }
+            };
+            match subtree_root.get_parent() {
+                Some(parent) => {
+                    decreased_left = parent.is_left_child(&subtree_root);
+                    node = parent;
+                }
+                None => return subtree_root,
+            }
+        }
+    }
+    fn rotate_for_balance(&self) -> (Rc<BinaryTreeNode<N>>, bool) {
+        // rotates subtree of self with balance factor +2 or -2. Returns new root of subtree and true,
+        // if height of subtree is unchanged by rotation (only possible after removal)
+        let x = self.get_self().unwrap();
+        if x.get_balance() > 0 {
+            let z = x.get_right().unwrap();
+            let z_balance = z.get_balance();
+            if z_balance >= 0 {
+                // right right case
+                x.rotate_left();
+                if z_balance == 0 {
+                    *x.balance.borrow_mut() = 1;
+                    *z.balance.borrow_mut() = -1;
+                } else {
+                    *x.balance.borrow_mut() = 0;
+                    *z.balance.borrow_mut() = 0;
+                }
+                (z, z_balance == 0)
+            } else {
+                // right left case
+                let y = z.get_left().unwrap();
+                let y_balance = y.get_balance();
+                z.rotate_right();
+                x.rotate_left();
+                *x.balance.borrow_mut() = if y_balance > 0 { -1 } else { 0 };
+                *z.balance.borrow_mut() = if y_balance < 0 { 1 } else { 0 };
+                *y.balance.borrow_mut() = 0;
+                (y, false)
+            }
+        } else {
+            let z = x.get_left().unwrap();
+            let z_balance = z.get_balance();
+            if z_balance <= 0 {
+                // left left case
+                x.rotate_right();
+                if z_balance == 0 {
+                    *x.balance.borrow_mut() = -1;
+                    *z.balance.borrow_mut() = 1;
+                } else {
+                    *x.balance.borrow_mut() = 0;
+                    *z.balance.borrow_mut() = 0;
+                }
+                (z, z_balance == 0)
+            } else {
+                // left right case
+                let y = z.get_right().unwrap();
+                let y_balance = y.get_balance();
+                z.rotate_left();
+                x.rotate_right();
+                *x.balance.borrow_mut() = if y_balance < 0 { 1 } else { 0 };
+                *z.balance.borrow_mut() = if y_balance > 0 { -1 } else { 0 };
+                *y.balance.borrow_mut() = 0;
+                (y, false)
+            }
+        }
+    }
+    fn rotate_left(&self) {
+        // right child of self takes position of self, self becomes left child of it
+        let x = self.get_self().unwrap();
+        let z = x.get_right().unwrap();
+        x.replace_in_parent(Some(z.clone()));
+        let t = z.left.borrow_mut().take();
+        if let Some(ref node) = t {
+            *node.parent.borrow_mut() = Rc::downgrade(&x);
+        }
+        *x.right.borrow_mut() = t;
+        *x.parent.borrow_mut() = Rc::downgrade(&z);
+        *z.left.borrow_mut() = Some(x.clone());
+        x.update_subtree_size();
+        z.update_subtree_size();
+    }
+    fn rotate_right(&self) {
+        // left child of self takes position of self, self becomes right child of it
+        let x = self.get_self().unwrap();
+        let z = x.get_left().unwrap();
+        x.replace_in_parent(Some(z.clone()));
+        let t = z.right.borrow_mut().take();
+        if let Some(ref node) = t {
+            *node.parent.borrow_mut() = Rc::downgrade(&x);
+        }
+        *x.left.borrow_mut() = t;
+        *x.parent.borrow_mut() = Rc::downgrade(&z);
+        *z.right.borrow_mut() = Some(x.clone());
+        x.update_subtree_size();
+        z.update_subtree_size();
+    }
+    fn increment_subtree_sizes(&self) {
+        // new leave was added to self: increment subtree size of self and all parents
+        *self.subtree_size.borrow_mut() += 1;
+        let mut current_node = self.get_parent();
+        while let Some(node) = current_node {
+            *node.subtree_size.borrow_mut() += 1;
+            current_node = node.get_parent();
+        }
+    }
+    fn update_subtree_size(&self) {
+        *self.subtree_size.borrow_mut() = 1
+            + self.get_left().map_or(0, |n| n.get_subtree_size())
+            + self.get_right().map_or(0, |n| n.get_subtree_size());
+    }
+    pub fn get_subtree_size(&self) -> usize {
+        *self.subtree_size.borrow()
+    }
+    pub fn kth_smallest(&self, k: usize) -> Option<Rc<BinaryTreeNode<N>>> {
+        // k-th smallest node (1-indexed) of subtree; count of nodes is not considered
+        let mut k = k;
+        let mut current_node = self.get_self();
+        while let Some(node) = current_node {
+            let left_size = node.get_left().map_or(0, |n| n.get_subtree_size());
+            match k.cmp(&(left_size + 1)) {
+                Ordering::Less => current_node = node.get_left(),
+                Ordering::Equal => return Some(node),
+                Ordering::Greater => {
+                    k -= left_size + 1;
+                    current_node = node.get_right();
+                }
+            }
+        }
+        None
+    }
+    pub fn rank(&self, value: N) -> usize {
+        // 1-based rank of value in subtree: 1 + number of nodes with smaller values
+        let mut rank = 1;
+        let mut current_node = self.get_self();
+        while let Some(node) = current_node {
+            let left_size = node.get_left().map_or(0, |n| n.get_subtree_size());
+            match value.cmp(&node.value) {
+                Ordering::Less => current_node = node.get_left(),
+                Ordering::Equal => return rank + left_size,
+                Ordering::Greater => {
+                    rank += left_size + 1;
+                    current_node = node.get_right();
+                }
+            }
+        }
+        rank
+    }
+    pub fn split(&self, value: N) -> SplitTrees<N> {
+        // call split on root of tree. Splits tree by rewiring nodes along search path of value into
+        // tree with all values < value and tree with all values >= value.
+        // Balance factors are not updated, therefore do not use balanced functions on resulting trees.
+        let (left, right) = BinaryTreeNode::split_subtree(self.get_self(), value);
+        for root in [&left, &right].into_iter().flatten() {
+            *root.parent.borrow_mut() = Weak::new();
+        }
+        (left, right)
+    }
+    fn split_subtree(node: Option<Rc<BinaryTreeNode<N>>>, value: N) -> SplitTrees<N> {
+        // walks down search path of value: nodes < value are chained as right children of left
+        // tree, nodes >= value are chained as left children of right tree
+        let mut left_path: Vec<Rc<BinaryTreeNode<N>>> = Vec::new();
+        let mut right_path: Vec<Rc<BinaryTreeNode<N>>> = Vec::new();
+        let mut current_node = node;
+        while let Some(node) = current_node {
+            if node.value < value {
+                current_node = node.get_right();
+                if let Some(parent) = left_path.last() {
+                    parent.set_child(Some(node.clone()), false);
+                }
+                left_path.push(node);
+            } else {
+                current_node = node.get_left();
+                if let Some(parent) = right_path.last() {
+                    parent.set_child(Some(node.clone()), true);
+                }
+                right_path.push(node);
+            }
+        }
+        // cut links of last nodes into other tree, than update subtree sizes bottom up
+        if let Some(node) = left_path.last() {
+            node.set_child(None, false);
+        }
+        if let Some(node) = right_path.last() {
+            node.set_child(None, true);
+        }
+        for node in left_path.iter().rev().chain(right_path.iter().rev()) {
+            node.update_subtree_size();
+        }
+        (left_path.first().cloned(), right_path.first().cloned())
+    }
+    pub fn join(
+        left: Rc<BinaryTreeNode<N>>,
+        right: Rc<BinaryTreeNode<N>>,
+    ) -> Rc<BinaryTreeNode<N>> {
+        // joins two trees, if all values of left are smaller than all values of right. Biggest node
+        // of left becomes new root with remaining left tree and right tree as children.
+        // Balance factors are not updated, therefore do not use balanced functions on resulting tree.
+        let mut root = left.clone();
+        while let Some(node) = root.get_right() {
+            root = node;
+        }
+        let mut smallest_right = right.clone();
+        while let Some(node) = smallest_right.get_left() {
+            smallest_right = node;
+        }
+        if root.value >= smallest_right.value {
+            panic!(
+                "line {}, values of left must be smaller than values of right",
+                line!()
+            );
+        }
+        let remaining_left = if Rc::ptr_eq(&root, &left) {
+            root.get_left()
+        } else {
+            let parent = root.get_parent().unwrap();
+            root.replace_in_parent(root.get_left());
+            *root.left.borrow_mut() = None;
+            let mut current_node = Some(parent);
+            while let Some(node) = current_node {
+                node.update_subtree_size();
+                current_node = node.get_parent();
+            }
+            Some(left)
+        };
+        root.set_child(remaining_left, true);
+        root.set_child(Some(right), false);
+        *root.parent.borrow_mut() = Weak::new();
+        root
+    }
+    pub fn union(a: &BinaryTreeNode<N>, b: &BinaryTreeNode<N>) -> Rc<BinaryTreeNode<N>> {
+        // call union on roots of both trees. Returns new tree with all values of a and b;
+        // counts of shared values are summed. a and b are not modified.
+        // Balance factors are not updated, therefore do not use balanced functions on resulting tree.
+        BinaryTreeNode::union_consuming(a.copy_subtree(), b.copy_subtree())
+    }
+    pub fn union_consuming(
+        a: Rc<BinaryTreeNode<N>>,
+        b: Rc<BinaryTreeNode<N>>,
+    ) -> Rc<BinaryTreeNode<N>> {
+        // same as union(), but nodes of a and b are split and rewired like split() and join(),
+        // therefore a and b are consumed without copying them
+        BinaryTreeNode::union_subtrees(Some(a), Some(b)).unwrap()
+    }
+    fn union_subtrees(
+        a: Option<Rc<BinaryTreeNode<N>>>,
+        b: Option<Rc<BinaryTreeNode<N>>>,
+    ) -> Option<Rc<BinaryTreeNode<N>>> {
+        let (root, b) = match (a, b) {
+            (None, b) => return b,
+            (a, None) => return a,
+            (Some(a), Some(b)) => (a, b),
+        };
+        let (left_a, right_a) = root.detach_children();
+        let (left_b, equal_b, right_b) = BinaryTreeNode::split_at_value(b, root.value);
+        if let Some(equal) = equal_b {
+            *root.count.borrow_mut() += equal.get_count();
+        }
+        root.set_child(BinaryTreeNode::union_subtrees(left_a, left_b), true);
+        root.set_child(BinaryTreeNode::union_subtrees(right_a, right_b), false);
+        Some(root)
+    }
+    pub fn intersection(
+        a: &BinaryTreeNode<N>,
+        b: &BinaryTreeNode<N>,
+    ) -> Option<Rc<BinaryTreeNode<N>>> {
+        // call intersection on roots of both trees. Returns new tree with all values, which are
+        // in a and b, or None if there are no shared values. Count of a shared value is the
+        // minimum of both counts. a and b are not modified.
+        // Balance factors are not updated, therefore do not use balanced functions on resulting tree.
+        BinaryTreeNode::intersection_consuming(a.copy_subtree(), b.copy_subtree())
+    }
+    pub fn intersection_consuming(
+        a: Rc<BinaryTreeNode<N>>,
+        b: Rc<BinaryTreeNode<N>>,
+    ) -> Option<Rc<BinaryTreeNode<N>>> {
+        // same as intersection(), but a and b are consumed like in union_consuming()
+        BinaryTreeNode::intersection_subtrees(Some(a), Some(b))
+    }
+    fn intersection_subtrees(
+        a: Option<Rc<BinaryTreeNode<N>>>,
+        b: Option<Rc<BinaryTreeNode<N>>>,
+    ) -> Option<Rc<BinaryTreeNode<N>>> {
+        let (root, b) = match (a, b) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return None,
+        };
+        let (left_a, right_a) = root.detach_children();
+        let (left_b, equal_b, right_b) = BinaryTreeNode::split_at_value(b, root.value);
+        let left = BinaryTreeNode::intersection_subtrees(left_a, left_b);
+        let right = BinaryTreeNode::intersection_subtrees(right_a, right_b);
+        match equal_b {
+            Some(equal) => {
+                let count = root.get_count().min(equal.get_count());
+                *root.count.borrow_mut() = count;
+                root.set_child(left, true);
+                root.set_child(right, false);
+                Some(root)
+            }
+            None => BinaryTreeNode::join_subtrees(left, right),
+        }
+    }
+    pub fn difference(
+        a: &BinaryTreeNode<N>,
+        b: &BinaryTreeNode<N>,
+    ) -> Option<Rc<BinaryTreeNode<N>>> {
+        // call difference on roots of both trees. Returns new tree with all values of a, which
+        // remain after removing values of b, or None if nothing remains. Count of b is subtracted
+        // from count of a for shared values. a and b are not modified.
+        // Balance factors are not updated, therefore do not use balanced functions on resulting tree.
+        BinaryTreeNode::difference_consuming(a.copy_subtree(), b.copy_subtree())
+    }
+    pub fn difference_consuming(
+        a: Rc<BinaryTreeNode<N>>,
+        b: Rc<BinaryTreeNode<N>>,
+    ) -> Option<Rc<BinaryTreeNode<N>>> {
+        // same as difference(), but a and b are consumed like in union_consuming()
+        BinaryTreeNode::difference_subtrees(Some(a), Some(b))
+    }
+    fn difference_subtrees(
+        a: Option<Rc<BinaryTreeNode<N>>>,
+        b: Option<Rc<BinaryTreeNode<N>>>,
+    ) -> Option<Rc<BinaryTreeNode<N>>> {
+        let (root, b) = match (a, b) {
+            (None, _) => return None,
+            (a, None) => return a,
+            (Some(a), Some(b)) => (a, b),
+        };
+        let (left_a, right_a) = root.detach_children();
+        let (left_b, equal_b, right_b) = BinaryTreeNode::split_at_value(b, root.value);
+        let left = BinaryTreeNode::difference_subtrees(left_a, left_b);
+        let right = BinaryTreeNode::difference_subtrees(right_a, right_b);
+        let count = root
+            .get_count()
+            .saturating_sub(equal_b.map_or(0, |n| n.get_count()));
+        if count == 0 {
+            return BinaryTreeNode::join_subtrees(left, right);
+        }
+        *root.count.borrow_mut() = count;
+        root.set_child(left, true);
+        root.set_child(right, false);
+        Some(root)
+    }
+    fn split_at_value(root: Rc<BinaryTreeNode<N>>, value: N) -> SplitTreesAtValue<N> {
+        // splits tree into values < value, detached node of value (if any), and values > value
+        let (left, right) = root.split(value);
+        let Some(right) = right else {
+            return (left, None, None);
+        };
+        let mut smallest = right.clone();
+        while let Some(node) = smallest.get_left() {
+            smallest = node;
+        }
+        if smallest.value != value {
+            return (left, None, Some(right));
+        }
+        let remaining_right = if Rc::ptr_eq(&smallest, &right) {
+            smallest.detach_children().1
+        } else {
+            let parent = smallest.get_parent().unwrap();
+            smallest.replace_in_parent(smallest.get_right());
+            *smallest.right.borrow_mut() = None;
+            smallest.update_subtree_size();
+            let mut current_node = Some(parent);
+            while let Some(node) = current_node {
+                node.update_subtree_size();
+                current_node = node.get_parent();
+            }
+            Some(right)
+        };
+        (left, Some(smallest), remaining_right)
+    }
+    fn join_subtrees(
+        left: Option<Rc<BinaryTreeNode<N>>>,
+        right: Option<Rc<BinaryTreeNode<N>>>,
+    ) -> Option<Rc<BinaryTreeNode<N>>> {
+        match (left, right) {
+            (Some(left), Some(right)) => Some(BinaryTreeNode::join(left, right)),
+            (left, None) => left,
+            (None, right) => right,
+        }
+    }
+    fn detach_children(&self) -> SplitTrees<N> {
+        // removes children from self; children become roots of their subtrees
+        let left = self.left.borrow_mut().take();
+        let right = self.right.borrow_mut().take();
+        for child in [&left, &right].into_iter().flatten() {
+            *child.parent.borrow_mut() = Weak::new();
+        }
+        self.update_subtree_size();
+        (left, right)
+    }
+    pub fn copy_subtree(&self) -> Rc<BinaryTreeNode<N>> {
+        // returns root of new tree with same structure, values, counts and balance factors
+        let root = self.copy_node();
+        let mut stack = vec![(self.get_self().unwrap(), root.clone())];
+        while let Some((node, copy)) = stack.pop() {
+            for (child, left) in [(node.get_left(), true), (node.get_right(), false)] {
+                if let Some(child) = child {
+                    let child_copy = child.copy_node();
+                    *child_copy.parent.borrow_mut() = Rc::downgrade(&copy);
+                    if left {
+                        *copy.left.borrow_mut() = Some(child_copy.clone());
+                    } else {
+                        *copy.right.borrow_mut() = Some(child_copy.clone());
+                    }
+                    stack.push((child, child_copy));
+                }
+            }
+        }
+        root
+    }
+    fn copy_node(&self) -> Rc<BinaryTreeNode<N>> {
+        // copy of self without parent and children, subtree size is kept for copy_subtree()
+        let node = BinaryTreeNode::new(self.value);
+        *node.count.borrow_mut() = self.get_count();
+        *node.balance.borrow_mut() = self.get_balance();
+        *node.subtree_size.borrow_mut() = self.get_subtree_size();
+        node
+    }
+    fn set_child(&self, child: Option<Rc<BinaryTreeNode<N>>>, left: bool) {
+        // child takes position of left or right child of self; subtree size of self is updated
+        if let Some(ref node) = child {
+            *node.parent.borrow_mut() = self.node.borrow().clone();
+        }
+        if left {
+            *self.left.borrow_mut() = child;
+        } else {
+            *self.right.borrow_mut() = child;
+        }
+        self.update_subtree_size();
+    }
+    fn is_left_child(&self, child: &Rc<BinaryTreeNode<N>>) -> bool {
+        self.left
+            .borrow()
+            .as_ref()
+            .is_some_and(|n| Rc::ptr_eq(n, child))
+    }
+    fn get_root(&self) -> Rc<BinaryTreeNode<N>> {
+        let mut node = self.get_self().unwrap();
+        while let Some(parent) = node.get_parent() {
+            node = parent;
+        }
+        node
+    }
+    pub fn get_height(&self) -> usize {
+        // number of levels of subtree starting at self
+        1 + self
+            .get_left()
+            .map_or(0, |n| n.get_height())
+            .max(self.get_right().map_or(0, |n| n.get_height()))
+    }
+    pub fn is_balanced(&self) -> bool {
+        // true, if heights of left and right subtree differ at most by one for all nodes of subtree
+        fn balanced_height<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone>(
+            node: Option<Rc<BinaryTreeNode<N>>>,
+        ) -> Option<usize> {
+            match node {
+                Some(node) => {
+                    let left = balanced_height(node.get_left())?;
+                    let right = balanced_height(node.get_right())?;
+                    if left.abs_diff(right) > 1 {
+                        return None;
+                    }
+                    Some(1 + left.max(right))
+                }
+                None => Some(0),
+            }
+        }
+        balanced_height(self.get_self()).is_some()
+    }
+    fn replace_in_parent(&self, replacement: Option<Rc<BinaryTreeNode<N>>>) {
+        // replacement takes position of self at parent of self
+        let parent = self.parent.replace(Weak::new());
+        if let Some(ref node) = replacement {
+            *node.parent.borrow_mut() = parent.clone();
+        }
+        if let Some(parent) = parent.upgrade() {
+            let is_left = parent
+                .left
+                .borrow()
+                .as_ref()
+                .is_some_and(|n| std::ptr::eq(Rc::as_ptr(n), self));
+            if is_left {
+                *parent.left.borrow_mut() = replacement;
+            } else {
+                *parent.right.borrow_mut() = replacement;
+            }
+        }
+    }
     pub fn get_value(&self) -> N {
         self.value
     }
     pub fn get_count(&self) -> usize {
         *self.count.borrow()
     }
+    pub fn get_balance(&self) -> i8 {
+        *self.balance.borrow()
+    }
     pub fn get_self(&self) -> Option<Rc<BinaryTreeNode<N>>> {
         self.node.borrow().upgrade().as_ref().cloned()
     }
@@ -490,6 +1231,72 @@ impl<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone> BinaryTreeNode<N> {
         }
         None
     }
+    pub fn successor(&self) -> Option<Rc<BinaryTreeNode<N>>> {
+        // smallest node bigger than self
+        if let Some(mut node) = self.get_right() {
+            while let Some(left) = node.get_left() {
+                node = left;
+            }
+            return Some(node);
+        }
+        let mut current_node = self.get_self().unwrap();
+        while let Some(parent) = current_node.get_parent() {
+            if parent.is_left_child(&current_node) {
+                return Some(parent);
+            }
+            current_node = parent;
+        }
+        None
+    }
+    pub fn predecessor(&self) -> Option<Rc<BinaryTreeNode<N>>> {
+        // biggest node smaller than self
+        if let Some(mut node) = self.get_left() {
+            while let Some(right) = node.get_right() {
+                node = right;
+            }
+            return Some(node);
+        }
+        let mut current_node = self.get_self().unwrap();
+        while let Some(parent) = current_node.get_parent() {
+            if !parent.is_left_child(&current_node) {
+                return Some(parent);
+            }
+            current_node = parent;
+        }
+        None
+    }
+    pub fn floor(&self, value: N) -> Option<Rc<BinaryTreeNode<N>>> {
+        // call floor on root of tree; returns node with biggest value <= value
+        let mut result = None;
+        let mut current_node = self.get_self();
+        while let Some(node) = current_node {
+            match value.cmp(&node.value) {
+                Ordering::Equal => return Some(node),
+                Ordering::Less => current_node = node.get_left(),
+                Ordering::Greater => {
+                    current_node = node.get_right();
+                    result = Some(node);
+                }
+            }
+        }
+        result
+    }
+    pub fn ceiling(&self, value: N) -> Option<Rc<BinaryTreeNode<N>>> {
+        // call ceiling on root of tree; returns node with smallest value >= value
+        let mut result = None;
+        let mut current_node = self.get_self();
+        while let Some(node) = current_node {
+            match value.cmp(&node.value) {
+                Ordering::Equal => return Some(node),
+                Ordering::Greater => current_node = node.get_right(),
+                Ordering::Less => {
+                    current_node = node.get_left();
+                    result = Some(node);
+                }
+            }
+        }
+        result
+    }
     pub fn get_node(&self, value: N) -> Option<Rc<BinaryTreeNode<N>>> {
         self.iter_path_to_node(value).find(|n| n.value == value)
     }
@@ -513,7 +1320,415 @@ impl<N: Ord + Eq + PartialOrd + PartialEq + Copy + Clone> BinaryTreeNode<N> {
     ) -> impl Iterator<Item = (Rc<BinaryTreeNode<N>>, usize)> {
         LevelOrderTraversal::new(self.get_self().unwrap())
     }
+    pub fn range_iter(&self, lo: N, hi: N) -> impl Iterator<Item = Rc<BinaryTreeNode<N>>> {
+        // nodes with lo <= value <= hi in ascending order; call on root of tree
+        RangeIter::new(self.get_self().unwrap(), lo, hi)
+    }
     pub fn iter_path_to_node(&self, value: N) -> impl Iterator<Item = Rc<BinaryTreeNode<N>>> {
         PathToNode::new(self.get_self().unwrap(), value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_remove() {
+        let mut rng = thread_rng();
+        let values: Vec<u32> = (0..100).map(|_| rng.gen_range(0..1000)).collect();
+        let root = BinaryTreeNode::new(values[0]);
+        for value in values.iter().skip(1) {
+            root.append_value(*value);
+        }
+        let mut root = Some(root);
+        for value in values.iter().take(50) {
+            root = root.unwrap().remove(*value);
+        }
+        let root = root.unwrap();
+        assert!(root.get_parent().is_none());
+        let mut remaining: Vec<u32> = values.iter().skip(50).copied().collect();
+        remaining.sort();
+        let in_order: Vec<u32> = root
+            .iter_in_order_traversal()
+            .flat_map(|n| std::iter::repeat_n(n.get_value(), n.get_count()))
+            .collect();
+        assert_eq!(in_order, remaining);
+        // parent links are consistent
+        for node in root.iter_pre_order_traversal() {
+            if let Some(left) = node.get_left() {
+                assert!(Rc::ptr_eq(&left.get_parent().unwrap(), &node));
+            }
+            if let Some(right) = node.get_right() {
+                assert!(Rc::ptr_eq(&right.get_parent().unwrap(), &node));
+            }
+        }
+        // removing a value, which is not in tree, does not change tree
+        let root = root.remove(1000).unwrap();
+        // remove all remaining values
+        let mut root = Some(root);
+        for value in remaining.iter() {
+            root = root.unwrap().remove(*value);
+        }
+        assert!(root.is_none());
+    }
+
+    #[test]
+    fn test_avl_balancing() {
+        let mut root = BinaryTreeNode::new(0_u32);
+        for value in 1..10_000 {
+            root = root.append_value_balanced(value);
+        }
+        assert!(root.is_balanced());
+        assert!(root.get_height() <= 14);
+        assert!(root.get_parent().is_none());
+        let check_balance_factors = |root: &Rc<BinaryTreeNode<u32>>| {
+            for node in root.iter_pre_order_traversal() {
+                let left = node.get_left().map_or(0, |n| n.get_height()) as i8;
+                let right = node.get_right().map_or(0, |n| n.get_height()) as i8;
+                assert_eq!(node.get_balance(), right - left);
+            }
+        };
+        check_balance_factors(&root);
+        let in_order: Vec<u32> = root
+            .iter_in_order_traversal()
+            .map(|n| n.get_value())
+            .collect();
+        assert_eq!(in_order, (0..10_000).collect::<Vec<u32>>());
+
+        // remove random half of values
+        let mut rng = thread_rng();
+        let mut values: Vec<u32> = (0..10_000).collect();
+        values.shuffle(&mut rng);
+        let mut root = Some(root);
+        for value in values.iter().take(5_000) {
+            root = root.unwrap().remove_balanced(*value);
+        }
+        let root = root.unwrap();
+        assert!(root.is_balanced());
+        check_balance_factors(&root);
+        let mut remaining: Vec<u32> = values.iter().skip(5_000).copied().collect();
+        remaining.sort();
+        let in_order: Vec<u32> = root
+            .iter_in_order_traversal()
+            .map(|n| n.get_value())
+            .collect();
+        assert_eq!(in_order, remaining);
+        let mut root = Some(root);
+        for value in remaining.iter() {
+            root = root.unwrap().remove_balanced(*value);
+        }
+        assert!(root.is_none());
+
+        // double rotation at root: new root is inserted node
+        let mut root = BinaryTreeNode::new(9_u32);
+        for value in [2, 4] {
+            root = root.append_value_balanced(value);
+        }
+        assert_eq!(root.get_value(), 4);
+        assert_eq!(root.iter_pre_order_traversal().count(), 3);
+        assert!(root.get_parent().is_none());
+
+        // unbalanced tree
+        let root = BinaryTreeNode::new(0_u32);
+        for value in 1..4 {
+            root.append_value(value);
+        }
+        assert!(!root.is_balanced());
+    }
+
+    #[test]
+    fn test_range_iter() {
+        let mut rng = thread_rng();
+        let values: Vec<u32> = (0..200).map(|_| rng.gen_range(0..1000)).collect();
+        let root = BinaryTreeNode::new(values[0]);
+        for value in values.iter().skip(1) {
+            root.append_value(*value);
+        }
+        for (lo, hi) in [(0, 999), (100, 300), (250, 250), (990, 2000), (500, 400)] {
+            let range: Vec<u32> = root.range_iter(lo, hi).map(|n| n.get_value()).collect();
+            let expected: Vec<u32> = root
+                .iter_in_order_traversal()
+                .map(|n| n.get_value())
+                .filter(|v| *v >= lo && *v <= hi)
+                .collect();
+            assert_eq!(range, expected);
+        }
+    }
+
+    #[test]
+    fn test_from_sorted_slice() {
+        for n in [1_usize, 2, 7, 8, 100, 1_000] {
+            let values: Vec<usize> = (0..n).collect();
+            let root = BinaryTreeNode::from_sorted_slice(&values);
+            // max level of perfectly balanced tree is floor(log2(n))
+            assert_eq!(root.get_max_level(), n.ilog2() as usize);
+            assert!(root.is_balanced());
+            let in_order: Vec<usize> = root
+                .iter_in_order_traversal()
+                .map(|n| n.get_value())
+                .collect();
+            assert_eq!(in_order, values);
+        }
+        let root = BinaryTreeNode::from_sorted_slice(&[1, 2, 2, 3]);
+        assert_eq!(root.get_node(2).unwrap().get_count(), 2);
+        // balance factors are valid for balanced functions
+        let mut root = BinaryTreeNode::from_sorted_slice(&(0..100).collect::<Vec<u32>>());
+        for value in 100..200 {
+            root = root.append_value_balanced(value);
+        }
+        assert!(root.is_balanced());
+    }
+
+    #[test]
+    fn test_split_and_join() {
+        let check_tree = |root: &Rc<BinaryTreeNode<u32>>, expected: Vec<u32>| {
+            assert!(root.get_parent().is_none());
+            assert_eq!(root.get_subtree_size(), expected.len());
+            for node in root.iter_pre_order_traversal() {
+                let left = node.get_left().map_or(0, |n| n.get_subtree_size());
+                let right = node.get_right().map_or(0, |n| n.get_subtree_size());
+                assert_eq!(node.get_subtree_size(), 1 + left + right);
+                for child in [node.get_left(), node.get_right()].into_iter().flatten() {
+                    assert!(Rc::ptr_eq(&child.get_parent().unwrap(), &node));
+                }
+            }
+            let in_order: Vec<u32> = root
+                .iter_in_order_traversal()
+                .map(|n| n.get_value())
+                .collect();
+            assert_eq!(in_order, expected);
+        };
+        let mut rng = thread_rng();
+        let mut values: Vec<u32> = (0..100).collect();
+        values.shuffle(&mut rng);
+        let root = BinaryTreeNode::new(values[0]);
+        for value in values.iter().skip(1) {
+            root.append_value(*value);
+        }
+        root.append_value(40);
+
+        let (left, right) = root.split(40);
+        let (left, right) = (left.unwrap(), right.unwrap());
+        check_tree(&left, (0..40).collect());
+        check_tree(&right, (40..100).collect());
+        assert_eq!(right.get_node(40).unwrap().get_count(), 2);
+
+        let root = BinaryTreeNode::join(left, right);
+        check_tree(&root, (0..100).collect());
+        assert!(root.get_height() <= values.len());
+
+        // split outside of range of values
+        let (left, right) = root.split(0);
+        assert!(left.is_none());
+        let (left, right) = right.unwrap().split(100);
+        assert!(right.is_none());
+        let root = left.unwrap();
+        check_tree(&root, (0..100).collect());
+
+        // degenerate tree: split walks along whole chain of nodes
+        let root = BinaryTreeNode::new(0);
+        for value in 1..1000 {
+            root.append_value(value);
+        }
+        let (left, right) = root.split(500);
+        check_tree(&left.unwrap(), (0..500).collect());
+        check_tree(&right.unwrap(), (500..1000).collect());
+
+        // join of single nodes
+        let root = BinaryTreeNode::join(BinaryTreeNode::new(1), BinaryTreeNode::new(2));
+        check_tree(&root, vec![1, 2]);
+        assert_eq!(root.get_value(), 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "stale balance factors")]
+    fn test_avl_balancing_stale_balance_factors() {
+        let root = BinaryTreeNode::new(5_u32);
+        root.append_value(3);
+        root.append_value(1);
+        root.append_value_balanced(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_join_overlapping() {
+        let left = BinaryTreeNode::from_sorted_slice(&[1, 2, 3]);
+        let right = BinaryTreeNode::from_sorted_slice(&[3, 4, 5]);
+        BinaryTreeNode::join(left, right);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let values_with_counts = |root: &Rc<BinaryTreeNode<u32>>| -> Vec<(u32, usize)> {
+            assert!(root.get_parent().is_none());
+            for node in root.iter_pre_order_traversal() {
+                let left = node.get_left().map_or(0, |n| n.get_subtree_size());
+                let right = node.get_right().map_or(0, |n| n.get_subtree_size());
+                assert_eq!(node.get_subtree_size(), 1 + left + right);
+            }
+            root.iter_in_order_traversal()
+                .map(|n| (n.get_value(), n.get_count()))
+                .collect()
+        };
+        let a = BinaryTreeNode::from_sorted_slice(&[1, 2, 2, 3, 5, 8, 13]);
+        let b = BinaryTreeNode::from_sorted_slice(&[2, 3, 3, 4, 8, 16]);
+
+        let union = BinaryTreeNode::union(&a, &b);
+        assert_eq!(
+            values_with_counts(&union),
+            vec![
+                (1, 1),
+                (2, 3),
+                (3, 3),
+                (4, 1),
+                (5, 1),
+                (8, 2),
+                (13, 1),
+                (16, 1)
+            ]
+        );
+
+        let intersection = BinaryTreeNode::intersection(&a, &b).unwrap();
+        assert_eq!(
+            values_with_counts(&intersection),
+            vec![(2, 1), (3, 1), (8, 1)]
+        );
+
+        let difference = BinaryTreeNode::difference(&a, &b).unwrap();
+        assert_eq!(
+            values_with_counts(&difference),
+            vec![(1, 1), (2, 1), (5, 1), (13, 1)]
+        );
+        let difference = BinaryTreeNode::difference(&b, &a).unwrap();
+        assert_eq!(
+            values_with_counts(&difference),
+            vec![(3, 1), (4, 1), (16, 1)]
+        );
+
+        // input trees are not modified
+        assert_eq!(
+            values_with_counts(&a),
+            vec![(1, 1), (2, 2), (3, 1), (5, 1), (8, 1), (13, 1)]
+        );
+        assert!(a.is_balanced());
+        assert_eq!(
+            values_with_counts(&b),
+            vec![(2, 1), (3, 2), (4, 1), (8, 1), (16, 1)]
+        );
+
+        // empty results
+        let c = BinaryTreeNode::from_sorted_slice(&[20, 21]);
+        assert!(BinaryTreeNode::intersection(&a, &c).is_none());
+        assert!(BinaryTreeNode::difference(&a, &a).is_none());
+
+        // consuming variants reuse nodes of input trees
+        let union = BinaryTreeNode::union_consuming(a.copy_subtree(), b.copy_subtree());
+        assert_eq!(union.get_subtree_size(), 8);
+        assert_eq!(union.get_node(2).unwrap().get_count(), 3);
+        let intersection =
+            BinaryTreeNode::intersection_consuming(a.copy_subtree(), b.copy_subtree()).unwrap();
+        assert_eq!(
+            values_with_counts(&intersection),
+            vec![(2, 1), (3, 1), (8, 1)]
+        );
+        let difference = BinaryTreeNode::difference_consuming(b, a).unwrap();
+        assert_eq!(
+            values_with_counts(&difference),
+            vec![(3, 1), (4, 1), (16, 1)]
+        );
+        assert!(BinaryTreeNode::intersection_consuming(difference, c).is_none());
+    }
+
+    #[test]
+    fn test_floor_ceiling_predecessor_successor() {
+        let root = BinaryTreeNode::new(50);
+        for value in [30, 70, 20, 40, 60, 80, 35, 45, 65] {
+            root.append_value(value);
+        }
+        assert_eq!(root.floor(45).unwrap().get_value(), 45);
+        assert_eq!(root.floor(47).unwrap().get_value(), 45);
+        assert_eq!(root.floor(59).unwrap().get_value(), 50);
+        assert_eq!(root.floor(100).unwrap().get_value(), 80);
+        assert!(root.floor(19).is_none());
+        assert_eq!(root.ceiling(45).unwrap().get_value(), 45);
+        assert_eq!(root.ceiling(46).unwrap().get_value(), 50);
+        assert_eq!(root.ceiling(31).unwrap().get_value(), 35);
+        assert_eq!(root.ceiling(0).unwrap().get_value(), 20);
+        assert!(root.ceiling(81).is_none());
+
+        let sorted: Vec<u32> = root
+            .iter_in_order_traversal()
+            .map(|n| n.get_value())
+            .collect();
+        for (index, value) in sorted.iter().enumerate() {
+            let node = root.get_node(*value).unwrap();
+            assert_eq!(
+                node.predecessor().map(|n| n.get_value()),
+                index.checked_sub(1).map(|i| sorted[i])
+            );
+            assert_eq!(
+                node.successor().map(|n| n.get_value()),
+                sorted.get(index + 1).copied()
+            );
+        }
+        // 45 has no right child; its successor is an ancestor and not the direct parent
+        assert_eq!(
+            root.get_node(45).unwrap().successor().unwrap().get_value(),
+            50
+        );
+        // 60 has no left child; its predecessor is the root
+        assert_eq!(
+            root.get_node(60)
+                .unwrap()
+                .predecessor()
+                .unwrap()
+                .get_value(),
+            50
+        );
+    }
+
+    #[test]
+    fn test_kth_smallest_and_rank() {
+        let mut rng = thread_rng();
+        let mut values: Vec<u32> = (0..500).collect();
+        values.shuffle(&mut rng);
+        let plain_root = BinaryTreeNode::new(values[0]);
+        let mut balanced_root = BinaryTreeNode::new(values[0]);
+        for value in values.iter().skip(1) {
+            plain_root.append_value(*value);
+            balanced_root = balanced_root.append_value_balanced(*value);
+        }
+        // duplicates do not change subtree size
+        plain_root.append_value(values[150]);
+        let mut plain_root = Some(plain_root);
+        let mut balanced_root = Some(balanced_root);
+        for value in values.iter().take(100) {
+            plain_root = plain_root.unwrap().remove(*value);
+            balanced_root = balanced_root.unwrap().remove_balanced(*value);
+        }
+        let mut remaining: Vec<u32> = values.iter().skip(100).copied().collect();
+        remaining.sort();
+        for root in [plain_root.unwrap(), balanced_root.unwrap()] {
+            assert_eq!(root.get_subtree_size(), 400);
+            for node in root.iter_pre_order_traversal() {
+                let left = node.get_left().map_or(0, |n| n.get_subtree_size());
+                let right = node.get_right().map_or(0, |n| n.get_subtree_size());
+                assert_eq!(node.get_subtree_size(), 1 + left + right);
+            }
+            for (index, value) in remaining.iter().enumerate() {
+                assert_eq!(root.kth_smallest(index + 1).unwrap().get_value(), *value);
+                assert_eq!(root.rank(*value), index + 1);
+            }
+            assert!(root.kth_smallest(0).is_none());
+            assert!(root.kth_smallest(401).is_none());
+        }
+        let root = BinaryTreeNode::from_sorted_slice(&[10, 20, 30]);
+        assert_eq!(root.get_subtree_size(), 3);
+        assert_eq!(root.rank(25), 3);
+        assert_eq!(root.rank(5), 1);
+    }
+}
@@ -82,6 +82,26 @@ impl Compass {
             Compass::W,
         ]
     }
+    pub fn iter_all_directions() -> impl Iterator<Item = Compass> {
+        // clockwise starting with N
+        [
+            Compass::N,
+            Compass::NE,
+            Compass::E,
+            Compass::SE,
+            Compass::S,
+            Compass::SW,
+            Compass::W,
+            Compass::NW,
+        ]
+        .into_iter()
+    }
+    pub fn iter_cardinal_directions() -> impl Iterator<Item = Compass> {
+        Compass::cardinals().into_iter()
+    }
+    pub fn iter_ordinal_directions() -> impl Iterator<Item = Compass> {
+        [Compass::NE, Compass::SE, Compass::SW, Compass::NW].into_iter()
+    }
     pub fn flip(&self) -> Self {
         match self {
             Compass::N => Compass::S,
@@ -215,6 +235,51 @@ impl Compass {
     pub fn is_center(&self) -> bool {
         *self == Compass::Center
     }
+    pub fn is_opposite(&self, other: Compass) -> bool {
+        // Center has no opposite direction
+        !self.is_center() && self.flip() == other
+    }
+    pub fn to_radians(&self) -> f64 {
+        // clockwise angle starting with N = 0, E = PI / 2, S = PI, W = 3 * PI / 2
+        let eighths = match self {
+            Compass::N => 0.0,
+            Compass::NE => 1.0,
+            Compass::E => 2.0,
+            Compass::SE => 3.0,
+            Compass::S => 4.0,
+            Compass::SW => 5.0,
+            Compass::W => 6.0,
+            Compass::NW => 7.0,
+            Compass::Center => panic!("line {}, center has no angle", line!()),
+        };
+        eighths * std::f64::consts::FRAC_PI_4
+    }
+    pub fn from_radians(angle: f64) -> Self {
+        // snaps angle to nearest of 8 directions; angle is measured like to_radians()
+        let eighths = (angle.rem_euclid(std::f64::consts::TAU) / std::f64::consts::FRAC_PI_4)
+            .round() as usize;
+        match eighths % 8 {
+            0 => Compass::N,
+            1 => Compass::NE,
+            2 => Compass::E,
+            3 => Compass::SE,
+            4 => Compass::S,
+            5 => Compass::SW,
+            6 => Compass::W,
+            _ => Compass::NW,
+        }
+    }
+    pub fn to_unit_vector(&self) -> (f64, f64) {
+        // same axes as (i32, i32) of Compass: y increases to S; Center is (0.0, 0.0)
+        let (x, y): (i32, i32) = (*self).into();
+        let length = ((x * x + y * y) as f64).sqrt().max(1.0);
+        (x as f64 / length, y as f64 / length)
+    }
+    pub fn angular_distance(&self, other: Compass) -> f64 {
+        // minimum angle between both directions in range 0.0..=PI
+        let delta = (self.to_radians() - other.to_radians()).abs();
+        delta.min(std::f64::consts::TAU - delta)
+    }
 }
 
 
@@ -234,4 +299,57 @@ mod tests {
         ].into();
         assert_eq!(Compass::from_u8(nesw), nesw_vec);
     }
+
+    #[test]
+    fn test_radians() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        assert_eq!(Compass::N.to_radians(), 0.0);
+        assert_eq!(Compass::E.to_radians(), FRAC_PI_2);
+        assert_eq!(Compass::NW.to_radians(), 7.0 * FRAC_PI_4);
+        let mut direction = Compass::N;
+        for _ in 0..8 {
+            assert_eq!(Compass::from_radians(direction.to_radians()), direction);
+            let (x, y) = direction.to_unit_vector();
+            assert!((x * x + y * y - 1.0).abs() < 1e-12);
+            // unit vector points in same direction as angle
+            assert!((x - direction.to_radians().sin()).abs() < 1e-12);
+            assert!((y + direction.to_radians().cos()).abs() < 1e-12);
+            direction = direction.clockwise();
+        }
+        assert_eq!(Compass::Center.to_unit_vector(), (0.0, 0.0));
+        // snap to nearest direction
+        assert_eq!(Compass::from_radians(0.3), Compass::N);
+        assert_eq!(Compass::from_radians(0.5), Compass::NE);
+        assert_eq!(Compass::from_radians(-0.5), Compass::NW);
+        assert_eq!(Compass::from_radians(2.0 * PI + FRAC_PI_2), Compass::E);
+        assert_eq!(Compass::from_radians(2.0 * PI - 0.1), Compass::N);
+
+        assert_eq!(Compass::N.angular_distance(Compass::N), 0.0);
+        assert_eq!(Compass::N.angular_distance(Compass::S), PI);
+        assert_eq!(Compass::NW.angular_distance(Compass::NE), FRAC_PI_2);
+        assert_eq!(Compass::NE.angular_distance(Compass::NW), FRAC_PI_2);
+        assert_eq!(Compass::W.angular_distance(Compass::SE), 3.0 * FRAC_PI_4);
+    }
+
+    #[test]
+    fn test_iter_directions() {
+        let all: Vec<Compass> = Compass::iter_all_directions().collect();
+        assert_eq!(all.len(), 8);
+        for (direction, next) in all.iter().zip(all.iter().cycle().skip(1)) {
+            assert_eq!(direction.clockwise(), *next);
+        }
+        assert!(Compass::iter_cardinal_directions().eq(Compass::cardinals()));
+        assert!(Compass::iter_cardinal_directions().all(|c| c.is_cardinal()));
+        assert_eq!(Compass::iter_ordinal_directions().count(), 4);
+        assert!(Compass::iter_ordinal_directions().all(|c| c.is_ordinal()));
+
+        for direction in Compass::iter_all_directions() {
+            assert!(direction.is_opposite(direction.flip()));
+            assert!(!direction.is_opposite(direction));
+            assert!(!direction.is_opposite(direction.clockwise()));
+        }
+        assert!(Compass::N.is_opposite(Compass::S));
+        assert!(Compass::SW.is_opposite(Compass::NE));
+        assert!(!Compass::Center.is_opposite(Compass::Center));
+    }
 }
\ No newline at end of file
@@ -72,7 +72,7 @@ impl<T: Copy + Clone + Default + From<char> + Debug, const N: usize> CubeMap<T,
         assert_eq!(index_surface, 6);
         // set edge relations from surface block positions
         for (block, si) in cj.flat_map_surfaces.iter() {
-            for dir in [Compass::N, Compass::E, Compass::S, Compass::W] {
+            for dir in Compass::iter_cardinal_directions() {
                 if let Some(neighbor_si) = cj.flat_map_surfaces.get(&block.add(dir.into())) {
                     cj.edges.insert((*si, dir), (*neighbor_si, dir.flip()));
                 }
@@ -138,8 +138,7 @@ impl<T: Copy + Clone + Default + From<char> + Debug, const N: usize> CubeMap<T,
 
         // add remaining edge relations from cube corner coordinates
         for i in 0..6 {
-            let missing_edges: Vec<Compass> = [Compass::N, Compass::E, Compass::S, Compass::W]
-                .into_iter()
+            let missing_edges: Vec<Compass> = Compass::iter_cardinal_directions()
                 .filter(|c| !cj.edges.contains_key(&(i, *c)))
                 .collect();
             for missing_edge in missing_edges {
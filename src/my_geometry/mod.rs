@@ -2,11 +2,15 @@
 // at the moment I use integer, if possible, since a lot of challenges
 // use integers or pixels
 
+pub mod my_affine_transform;
+pub mod my_bezier;
 pub mod my_circle;
 pub mod my_diamond;
 pub mod my_line;
 pub mod my_point;
+pub mod my_polygon;
 pub mod my_rectangle;
+pub mod my_triangle;
 
 pub enum FormOrdering {
     Identical,
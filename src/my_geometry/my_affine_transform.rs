@@ -0,0 +1,132 @@
+use super::{
+    my_circle::Circle,
+    my_point::{FloatPoint, Point},
+    my_polygon::Polygon,
+};
+
+// AffineTransform: 3x3 matrix in homogeneous coordinates
+// | a b tx |   | x |
+// | c d ty | * | y |
+// | 0 0 1  |   | 1 |
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffineTransform {
+    matrix: [[f64; 3]; 3],
+}
+
+impl Default for AffineTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl AffineTransform {
+    pub fn identity() -> Self {
+        Self {
+            matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Self {
+            matrix: [[1.0, 0.0, dx], [0.0, 1.0, dy], [0.0, 0.0, 1.0]],
+        }
+    }
+    pub fn rotate(angle_rad: f64) -> Self {
+        // counterclockwise rotation around origin
+        let (sin, cos) = angle_rad.sin_cos();
+        Self {
+            matrix: [[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self {
+            matrix: [[sx, 0.0, 0.0], [0.0, sy, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+    pub fn get_matrix(&self) -> [[f64; 3]; 3] {
+        self.matrix
+    }
+    pub fn compose(&self, other: &Self) -> Self {
+        // resulting transform first applies self, then other
+        // -> matrix = other * self
+        let mut matrix = [[0.0; 3]; 3];
+        for (row, matrix_row) in matrix.iter_mut().enumerate() {
+            for (col, value) in matrix_row.iter_mut().enumerate() {
+                *value = (0..3)
+                    .map(|k| other.matrix[row][k] * self.matrix[k][col])
+                    .sum();
+            }
+        }
+        Self { matrix }
+    }
+    pub fn apply_to_point(&self, p: Point) -> FloatPoint {
+        self.apply_to_float_point(p.into())
+    }
+    pub fn apply_to_float_point(&self, p: FloatPoint) -> FloatPoint {
+        let m = &self.matrix;
+        FloatPoint::new(
+            m[0][0] * p.x + m[0][1] * p.y + m[0][2],
+            m[1][0] * p.x + m[1][1] * p.y + m[1][2],
+        )
+    }
+    pub fn apply_to_polygon(&self, poly: &Polygon) -> Polygon {
+        // vertices are rounded to nearest integer point
+        // panics, if transformed polygon is degenerated, e.g. if scaled by zero
+        let vertices: Vec<Point> = poly
+            .vertices()
+            .iter()
+            .map(|v| Point::try_from(self.apply_to_point(*v)).unwrap())
+            .collect();
+        Polygon::new(vertices).unwrap()
+    }
+    pub fn apply_to_circle(&self, c: Circle) -> Circle {
+        // transformed circle is an ellipse, if scaling is not uniform
+        // approximation: circle with same area as ellipse, which is scaled by sqrt(|det|)
+        let m = &self.matrix;
+        let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+        let center = Point::try_from(self.apply_to_point(c.get_center())).unwrap();
+        let radius = (c.get_radius() as f64 * det.abs().sqrt()).round() as i64;
+        Circle::new(center, radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_affine_transform() {
+        let point = Point::new(2, 1);
+        assert_eq!(
+            AffineTransform::identity().apply_to_point(point),
+            FloatPoint::new(2.0, 1.0)
+        );
+        // first rotate by 90°, then translate
+        let transform =
+            AffineTransform::rotate(FRAC_PI_2).compose(&AffineTransform::translate(10.0, 0.0));
+        let transformed = transform.apply_to_point(point);
+        assert!(transformed.distance(FloatPoint::new(9.0, 2.0)) < 1e-9);
+        // first translate, then rotate by 90°
+        let transform =
+            AffineTransform::translate(10.0, 0.0).compose(&AffineTransform::rotate(FRAC_PI_2));
+        let transformed = transform.apply_to_point(point);
+        assert!(transformed.distance(FloatPoint::new(-1.0, 12.0)) < 1e-9);
+
+        let polygon =
+            Polygon::new(vec![Point::new(0, 0), Point::new(2, 0), Point::new(0, 2)]).unwrap();
+        let scaled = AffineTransform::scale(3.0, 2.0).apply_to_polygon(&polygon);
+        assert_eq!(
+            scaled.vertices(),
+            &[Point::new(0, 0), Point::new(6, 0), Point::new(0, 4)]
+        );
+        assert_eq!(scaled.area(), polygon.area() * 6.0);
+
+        let circle = Circle::new(Point::new(1, 1), 10);
+        let transformed = AffineTransform::scale(4.0, 4.0)
+            .compose(&AffineTransform::translate(-4.0, 0.0))
+            .apply_to_circle(circle);
+        assert_eq!(transformed.get_center(), Point::new(0, 4));
+        assert_eq!(transformed.get_radius(), 40);
+    }
+}
@@ -0,0 +1,205 @@
+use super::{
+    my_point::{FloatPoint, Point},
+    my_rectangle::Rectangle,
+};
+
+// BezierCurve: defined by start point, one or two control points and end point
+// Quadratic(start, control, end)
+// Cubic(start, control_1, control_2, end)
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BezierCurve {
+    Quadratic(Point, Point, Point),
+    Cubic(Point, Point, Point, Point),
+}
+
+impl BezierCurve {
+    pub fn control_points(&self) -> Vec<Point> {
+        match *self {
+            BezierCurve::Quadratic(p0, p1, p2) => vec![p0, p1, p2],
+            BezierCurve::Cubic(p0, p1, p2, p3) => vec![p0, p1, p2, p3],
+        }
+    }
+    fn de_casteljau(&self, t: f64) -> Vec<Vec<FloatPoint>> {
+        // all levels of de Casteljau's algorithm
+        // first level are control points, last level is point of curve at t
+        let mut levels: Vec<Vec<FloatPoint>> = vec![self
+            .control_points()
+            .into_iter()
+            .map(FloatPoint::from)
+            .collect()];
+        while levels.last().unwrap().len() > 1 {
+            let next_level: Vec<FloatPoint> = levels
+                .last()
+                .unwrap()
+                .windows(2)
+                .map(|w| w[0].lerp(w[1], t))
+                .collect();
+            levels.push(next_level);
+        }
+        levels
+    }
+    pub fn evaluate(&self, t: f64) -> FloatPoint {
+        assert!((0.0..=1.0).contains(&t));
+        self.de_casteljau(t).last().unwrap()[0]
+    }
+    pub fn to_polyline(&self, segments: usize) -> Vec<FloatPoint> {
+        assert!(segments > 0);
+        (0..=segments)
+            .map(|i| self.evaluate(i as f64 / segments as f64))
+            .collect()
+    }
+    pub fn arc_length(&self, samples: usize) -> f32 {
+        // approximation by length of polyline with samples segments
+        self.to_polyline(samples)
+            .windows(2)
+            .map(|w| w[0].distance(w[1]))
+            .sum::<f64>() as f32
+    }
+    fn extrema_parameters(&self, axis: fn(&FloatPoint) -> f64) -> Vec<f64> {
+        // parameters t in (0.0, 1.0), at which derivative of one axis is zero
+        let c: Vec<f64> = self
+            .control_points()
+            .into_iter()
+            .map(|p| axis(&FloatPoint::from(p)))
+            .collect();
+        let mut roots: Vec<f64> = Vec::new();
+        match self {
+            BezierCurve::Quadratic(..) => {
+                // B'(t) / 2 = (1 - t) * (c1 - c0) + t * (c2 - c1)
+                let denominator = c[0] - 2.0 * c[1] + c[2];
+                if denominator != 0.0 {
+                    roots.push((c[0] - c[1]) / denominator);
+                }
+            }
+            BezierCurve::Cubic(..) => {
+                // B'(t) / 3 = (1 - t)² * d0 + 2 * (1 - t) * t * d1 + t² * d2
+                // with di = c(i+1) - ci
+                // -> a * t² + b * t + d0 = 0
+                let d0 = c[1] - c[0];
+                let d1 = c[2] - c[1];
+                let d2 = c[3] - c[2];
+                let a = d0 - 2.0 * d1 + d2;
+                let b = 2.0 * (d1 - d0);
+                if a == 0.0 {
+                    if b != 0.0 {
+                        roots.push(-d0 / b);
+                    }
+                } else {
+                    let discriminant = b.powi(2) - 4.0 * a * d0;
+                    if discriminant >= 0.0 {
+                        roots.push((-b + discriminant.sqrt()) / (2.0 * a));
+                        roots.push((-b - discriminant.sqrt()) / (2.0 * a));
+                    }
+                }
+            }
+        }
+        roots.retain(|t| *t > 0.0 && *t < 1.0);
+        roots
+    }
+    pub fn bounding_box(&self) -> Rectangle {
+        // smallest integer rectangle containing curve
+        // since Rectangle requires a size > 0, bounding box of straight horizontal
+        // or vertical curve is extended by 1 in missing dimension
+        let mut parameters = vec![0.0, 1.0];
+        parameters.extend(self.extrema_parameters(|p| p.x));
+        parameters.extend(self.extrema_parameters(|p| p.y));
+        let points: Vec<FloatPoint> = parameters.into_iter().map(|t| self.evaluate(t)).collect();
+        let min_x = points.iter().map(|p| p.x).fold(f64::MAX, f64::min).floor() as i64;
+        let max_x = points.iter().map(|p| p.x).fold(f64::MIN, f64::max).ceil() as i64;
+        let min_y = points.iter().map(|p| p.y).fold(f64::MAX, f64::min).floor() as i64;
+        let max_y = points.iter().map(|p| p.y).fold(f64::MIN, f64::max).ceil() as i64;
+        Rectangle::new(
+            Point::new(min_x, max_y.max(min_y + 1)),
+            Point::new(max_x.max(min_x + 1), min_y),
+        )
+    }
+    pub fn subdivide(&self, t: f64) -> (BezierCurve, BezierCurve) {
+        // de Casteljau's algorithm: first points of each level are control points of first curve,
+        // last points of each level in reverse order are control points of second curve
+        // since BezierCurve uses integer points, new control points are rounded to nearest point
+        assert!((0.0..=1.0).contains(&t));
+        let levels = self.de_casteljau(t);
+        let round = |p: &FloatPoint| Point::try_from(*p).unwrap();
+        let first: Vec<Point> = levels.iter().map(|l| round(&l[0])).collect();
+        let second: Vec<Point> = levels
+            .iter()
+            .rev()
+            .map(|l| round(l.last().unwrap()))
+            .collect();
+        match self {
+            BezierCurve::Quadratic(..) => (
+                BezierCurve::Quadratic(first[0], first[1], first[2]),
+                BezierCurve::Quadratic(second[0], second[1], second[2]),
+            ),
+            BezierCurve::Cubic(..) => (
+                BezierCurve::Cubic(first[0], first[1], first[2], first[3]),
+                BezierCurve::Cubic(second[0], second[1], second[2], second[3]),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_bezier_curve() {
+        let quadratic =
+            BezierCurve::Quadratic(Point::new(0, 0), Point::new(10, 20), Point::new(20, 0));
+        assert_eq!(quadratic.evaluate(0.0), FloatPoint::new(0.0, 0.0));
+        assert_eq!(quadratic.evaluate(0.5), FloatPoint::new(10.0, 10.0));
+        assert_eq!(quadratic.evaluate(1.0), FloatPoint::new(20.0, 0.0));
+        assert_eq!(
+            quadratic.bounding_box(),
+            Rectangle::new(Point::new(0, 10), Point::new(20, 0))
+        );
+        let polyline = quadratic.to_polyline(4);
+        assert_eq!(polyline.len(), 5);
+        assert_eq!(polyline[2], FloatPoint::new(10.0, 10.0));
+
+        // straight line as cubic curve
+        let straight = BezierCurve::Cubic(
+            Point::new(0, 0),
+            Point::new(3, 4),
+            Point::new(6, 8),
+            Point::new(9, 12),
+        );
+        assert!((straight.arc_length(10) - 15.0).abs() < 1e-4);
+
+        let cubic = BezierCurve::Cubic(
+            Point::new(0, 0),
+            Point::new(0, 40),
+            Point::new(40, 40),
+            Point::new(40, 0),
+        );
+        assert_eq!(cubic.evaluate(0.5), FloatPoint::new(20.0, 30.0));
+        assert_eq!(
+            cubic.bounding_box(),
+            Rectangle::new(Point::new(0, 30), Point::new(40, 0))
+        );
+        let (first, second) = cubic.subdivide(0.5);
+        assert_eq!(
+            first,
+            BezierCurve::Cubic(
+                Point::new(0, 0),
+                Point::new(0, 20),
+                Point::new(10, 30),
+                Point::new(20, 30)
+            )
+        );
+        assert_eq!(
+            second,
+            BezierCurve::Cubic(
+                Point::new(20, 30),
+                Point::new(30, 30),
+                Point::new(40, 20),
+                Point::new(40, 0)
+            )
+        );
+        // arc length of both halves is equal to arc length of curve
+        let arc_length = cubic.arc_length(1_000);
+        assert!((first.arc_length(500) + second.arc_length(500) - arc_length).abs() < 1e-3);
+    }
+}
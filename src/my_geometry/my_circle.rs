@@ -1,7 +1,7 @@
 use super::{
     my_diamond::Diamond,
     my_line::{Line, LineSegment},
-    my_point::{Cylindrical, Point},
+    my_point::{FloatPoint, Point},
     my_rectangle::Rectangle,
     FormOrdering,
 };
@@ -78,9 +78,87 @@ impl Circle {
             radius: (self.radius as f32 * factor) as i64,
         }
     }
-    pub fn point_of_angle(&self, angle: f32) -> Point {
-        let poc = Point::from(Cylindrical::new(self.radius as f32, angle));
-        self.center.add(poc)
+    pub fn point_of_angle(&self, angle: f32) -> FloatPoint {
+        // angle in degree
+        let radians = (angle as f64).to_radians();
+        let poc = FloatPoint::new(
+            self.radius as f64 * radians.cos(),
+            self.radius as f64 * radians.sin(),
+        );
+        FloatPoint::from(self.center).add(poc)
+    }
+    pub fn iter_arc_points(
+        &self,
+        start_angle: f64,
+        end_angle: f64,
+    ) -> impl Iterator<Item = Point> + '_ {
+        // angles in radians, arc runs counterclockwise from start_angle to end_angle
+        // points of circumference are calculated with midpoint circle algorithm
+        // points are sorted counterclockwise starting at start_angle
+        let tau = std::f64::consts::TAU;
+        let span = Circle::arc_span(start_angle, end_angle);
+        let angle = move |p: &Point| {
+            ((p.y - self.center.y) as f64)
+                .atan2((p.x - self.center.x) as f64)
+                .rem_euclid(tau)
+        };
+        let mut points = self.midpoint_circle_points();
+        let first = points.partition_point(|p| angle(p) < start_angle.rem_euclid(tau) - 1e-9);
+        points.rotate_left(first);
+        points
+            .into_iter()
+            .filter(move |p| (angle(p) - start_angle).rem_euclid(tau) <= span + 1e-9)
+    }
+    pub fn arc_length(&self, start_angle: f64, end_angle: f64) -> f64 {
+        // angles in radians, arc runs counterclockwise from start_angle to end_angle
+        self.radius as f64 * Circle::arc_span(start_angle, end_angle)
+    }
+    pub fn sector_area(&self, start_angle: f64, end_angle: f64) -> f64 {
+        // angles in radians, sector runs counterclockwise from start_angle to end_angle
+        0.5 * (self.radius.pow(2) as f64) * Circle::arc_span(start_angle, end_angle)
+    }
+    fn arc_span(start_angle: f64, end_angle: f64) -> f64 {
+        // counterclockwise angle from start_angle to end_angle; full circle, if span >= 2π
+        let tau = std::f64::consts::TAU;
+        if end_angle - start_angle >= tau {
+            tau
+        } else {
+            (end_angle - start_angle).rem_euclid(tau)
+        }
+    }
+    fn midpoint_circle_points(&self) -> Vec<Point> {
+        // points of first octant are mirrored to all octants; points are sorted counterclockwise
+        // starting at angle 0
+        let mut points: Vec<(i64, i64)> = Vec::with_capacity(8 * self.radius as usize + 8);
+        let (mut x, mut y) = (self.radius, 0);
+        let mut decision = 1 - self.radius;
+        while x >= y {
+            for (dx, dy) in [(x, y), (y, x)] {
+                for (sx, sy) in [(1, 1), (-1, 1), (-1, -1), (1, -1)] {
+                    points.push((sx * dx, sy * dy));
+                }
+            }
+            y += 1;
+            if decision < 0 {
+                decision += 2 * y + 1;
+            } else {
+                x -= 1;
+                decision += 2 * (y - x) + 1;
+            }
+        }
+        let angle = |(dx, dy): &(i64, i64)| {
+            (*dy as f64)
+                .atan2(*dx as f64)
+                .rem_euclid(std::f64::consts::TAU)
+        };
+        // mirrored points on axes and diagonals are duplicates
+        points.sort_unstable();
+        points.dedup();
+        points.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+        points
+            .into_iter()
+            .map(|(dx, dy)| Point::new(self.center.x + dx, self.center.y + dy))
+            .collect()
     }
     pub fn y_of_x(&self, x: i64) -> Vec<Point> {
         // formulas
@@ -256,6 +334,54 @@ mod tests {
         assert!(circle > inside);
     }
 
+    #[test]
+    fn test_point_of_angle() {
+        let circle = Circle::new(Point::new(10, 10), 2);
+        let point = circle.point_of_angle(45.0);
+        assert!((point.x - (10.0 + 2.0_f64.sqrt())).abs() < 1e-6);
+        assert!((point.y - (10.0 + 2.0_f64.sqrt())).abs() < 1e-6);
+        assert_eq!(
+            Point::try_from(circle.point_of_angle(180.0)),
+            Ok(Point::new(8, 10))
+        );
+    }
+
+    #[test]
+    fn test_arc() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+        let circle = Circle::new(Point::new(10, 10), 5);
+        let full_circle: Vec<Point> = circle.iter_arc_points(0.0, 2.0 * PI).collect();
+        assert_eq!(full_circle.len(), 28);
+        assert_eq!(full_circle[0], Point::new(15, 10));
+        assert!(full_circle.contains(&Point::new(13, 14)));
+        assert!(full_circle.contains(&Point::new(5, 10)));
+
+        let quarter: Vec<Point> = circle.iter_arc_points(0.0, FRAC_PI_2).collect();
+        assert_eq!(quarter.len(), 8);
+        assert_eq!(quarter[0], Point::new(15, 10));
+        assert_eq!(quarter[7], Point::new(10, 15));
+        assert!(quarter.iter().all(|p| p.x >= 10 && p.y >= 10));
+
+        // arc across angle 0
+        let right_half: Vec<Point> = circle.iter_arc_points(3.0 * FRAC_PI_2, FRAC_PI_2).collect();
+        assert_eq!(right_half.len(), 15);
+        assert!(right_half.iter().all(|p| p.x >= 10));
+        assert_eq!(right_half[0], Point::new(10, 5));
+        assert_eq!(right_half[7], Point::new(15, 10));
+        assert_eq!(right_half[14], Point::new(10, 15));
+
+        // output starts at start_angle
+        let second_quarter: Vec<Point> = circle.iter_arc_points(FRAC_PI_2, PI).collect();
+        assert_eq!(second_quarter.len(), 8);
+        assert_eq!(second_quarter[0], Point::new(10, 15));
+        assert_eq!(second_quarter[7], Point::new(5, 10));
+
+        assert!((circle.arc_length(0.0, PI) - 5.0 * PI).abs() < 1e-9);
+        assert!((circle.arc_length(3.0 * FRAC_PI_2, FRAC_PI_2) - 5.0 * PI).abs() < 1e-9);
+        assert!((circle.arc_length(0.0, 2.0 * PI) - 10.0 * PI).abs() < 1e-9);
+        assert!((circle.sector_area(0.0, FRAC_PI_2) - 25.0 * PI / 4.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_circle_intersection() {
         let c1 = Circle::new(Point::default(), 1_000);
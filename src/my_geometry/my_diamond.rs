@@ -123,6 +123,42 @@ impl Diamond {
         }
         x
     }
+    pub fn iter_border_points(&self) -> impl Iterator<Item = Point> + '_ {
+        // same points as iter_border()
+        self.iter_border()
+    }
+    pub fn iter_interior_points(&self) -> impl Iterator<Item = Point> + '_ {
+        // all points with manhatten distance < radius
+        (self.center.x - self.radius + 1..self.center.x + self.radius).flat_map(move |x| {
+            let delta_y = self.radius - (self.center.x - x).abs();
+            (self.center.y - delta_y + 1..self.center.y + delta_y).map(move |y| Point::new(x, y))
+        })
+    }
+    pub fn iter_border(&self) -> impl Iterator<Item = Point> + '_ {
+        // all 4 * radius points of circumference, starting at top corner going clockwise
+        (0..4 * self.radius).map(move |i| {
+            let step = i % self.radius;
+            let (dx, dy) = match i / self.radius {
+                // top -> right
+                0 => (step, self.radius - step),
+                // right -> bottom
+                1 => (self.radius - step, -step),
+                // bottom -> left
+                2 => (-step, step - self.radius),
+                // left -> top
+                _ => (step - self.radius, step),
+            };
+            self.center.add(Point::new(dx, dy))
+        })
+    }
+    pub fn iter_interior(&self) -> impl Iterator<Item = Point> + '_ {
+        // all points with manhatten distance <= radius, including boundary (circumference);
+        // in contrast to iter_interior_points(), which excludes boundary
+        (self.center.x - self.radius..=self.center.x + self.radius).flat_map(move |x| {
+            let delta_y = self.radius - (self.center.x - x).abs();
+            (self.center.y - delta_y..=self.center.y + delta_y).map(move |y| Point::new(x, y))
+        })
+    }
     pub fn corners(&self) -> [Point; 4] {
         [
             // top
@@ -255,6 +291,51 @@ mod tests {
         assert!(diamond > inside);
     }
     #[test]
+    fn test_diamond_border_and_interior_points() {
+        let diamond = Diamond::new(Point::new(3, -2), 3);
+        let border: Vec<Point> = diamond.iter_border_points().collect();
+        assert_eq!(border.len(), 12);
+        assert!(border.iter().all(|p| diamond == *p));
+        for corner in diamond.corners().iter() {
+            assert_eq!(border.iter().filter(|p| *p == corner).count(), 1);
+        }
+        let interior: Vec<Point> = diamond.iter_interior_points().collect();
+        // 1 + 4 + 8 points with manhatten distance 0, 1 and 2
+        assert_eq!(interior.len(), 13);
+        assert!(interior.iter().all(|p| diamond > *p));
+        let diamond = Diamond::new(Point::default(), 1);
+        assert_eq!(
+            diamond.iter_interior_points().collect::<Vec<Point>>(),
+            [Point::default()]
+        );
+    }
+    #[test]
+    fn test_diamond_iter_border_and_interior() {
+        let diamond = Diamond::new(Point::new(3, -2), 3);
+        let border: Vec<Point> = diamond.iter_border().collect();
+        assert_eq!(border.len(), 12);
+        assert_eq!(border[0], diamond.corners()[0]);
+        assert_eq!(border[3], diamond.corners()[1]);
+        assert_eq!(border[6], diamond.corners()[2]);
+        assert_eq!(border[9], diamond.corners()[3]);
+        assert_eq!(border[1], Point::new(4, 0));
+        assert!(border.iter().all(|p| diamond == *p));
+        // consecutive border points are diagonal neighbors
+        for (index, point) in border.iter().enumerate() {
+            let next = border[(index + 1) % border.len()];
+            assert_eq!(point.delta_x(next), 1);
+            assert_eq!(point.delta_y(next), 1);
+        }
+        assert_eq!(diamond.iter_border_points().collect::<Vec<Point>>(), border);
+
+        let interior: Vec<Point> = diamond.iter_interior().collect();
+        assert_eq!(interior.len(), 2 * 3 * (3 + 1) + 1);
+        assert!(interior.iter().all(|p| diamond >= *p));
+        assert!(border.iter().all(|p| interior.contains(p)));
+        let diamond = Diamond::new(Point::default(), 1);
+        assert_eq!(diamond.iter_interior().count(), 5);
+    }
+    #[test]
     fn test_diamond_intersection() {
         let d1 = Diamond::new(Point::default(), 5);
         let d2 = Diamond::new(Point::new(2, -6), 5);
@@ -1,4 +1,4 @@
-use super::my_point::Point;
+use super::my_point::{orientation, FloatPoint, Orientation, Point};
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, Eq)]
@@ -148,6 +148,32 @@ impl Line {
             };
         Some(Point::new(x, y))
     }
+    pub fn perpendicular_distance_float(&self, p: Point) -> f64 {
+        // |a*x + b*y + c| / sqrt(a² + b²)
+        (self.a * p.x + self.b * p.y + self.c).abs() as f64 / self.normal_len()
+    }
+    pub fn foot_of_perpendicular(&self, p: Point) -> FloatPoint {
+        // move p along normal vector (a, b) onto line
+        // foot = p - (a*x + b*y + c) / (a² + b²) * (a, b)
+        let factor =
+            (self.a * p.x + self.b * p.y + self.c) as f64 / (self.a.pow(2) + self.b.pow(2)) as f64;
+        FloatPoint::new(
+            p.x as f64 - factor * self.a as f64,
+            p.y as f64 - factor * self.b as f64,
+        )
+    }
+    pub fn parallel_at_distance(&self, d: f64) -> (Line, Line) {
+        // parallel lines: a*x + b*y + c -/+ d * sqrt(a² + b²) = 0
+        // since line parameters are integer, c of parallel lines is rounded
+        let offset = d * self.normal_len();
+        (
+            Line::new(self.a, self.b, (self.c as f64 - offset).round() as i64),
+            Line::new(self.a, self.b, (self.c as f64 + offset).round() as i64),
+        )
+    }
+    fn normal_len(&self) -> f64 {
+        ((self.a.pow(2) + self.b.pow(2)) as f64).sqrt()
+    }
     pub fn line_segment_intersection(&self, segment: &LineSegment) -> Option<Point> {
         segment.segment_line_intersection(self)
     }
@@ -167,7 +193,11 @@ impl PartialOrd for LineSegment {
 
 impl PartialEq<Point> for LineSegment {
     fn eq(&self, other: &Point) -> bool {
-        self.line() == *other && self.min_x() <= other.x && other.x <= self.max_x()
+        self.line() == *other
+            && self.min_x() <= other.x
+            && other.x <= self.max_x()
+            && self.min_y() <= other.y
+            && other.y <= self.max_y()
     }
 }
 
@@ -204,6 +234,13 @@ impl LineSegment {
         self.line().is_parallel(&other.line())
     }
     pub fn segment_intersection(&self, other: &Self) -> Option<Point> {
+        if orientation(self.a, self.b, other.a) == Orientation::Collinear
+            && orientation(self.a, self.b, other.b) == Orientation::Collinear
+        {
+            // segments on same line: only one intersection point, if segments touch at end points
+            let so = self.segment_overlapping(other);
+            return if so.len() == 1 { Some(so[0]) } else { None };
+        }
         if let Some(si) = self.line().line_intersection(&other.line()) {
             if self == &si && other == &si {
                 return Some(si);
@@ -219,6 +256,24 @@ impl LineSegment {
         }
         None
     }
+    pub fn nearest_point_on_segment(&self, p: Point) -> Point {
+        Point::try_from(self.nearest_point_on_segment_float(p.into())).unwrap()
+    }
+    pub fn nearest_point_on_segment_float(&self, p: FloatPoint) -> FloatPoint {
+        // project p on line of segment: a + t * (b - a)
+        // t = (p - a) * (b - a) / |b - a|²
+        // clamp t to [0.0, 1.0] to stay on segment
+        let a = FloatPoint::from(self.a);
+        let b = FloatPoint::from(self.b);
+        let ab = b.subtract(a);
+        let ap = p.subtract(a);
+        let t = (ap.x * ab.x + ap.y * ab.y) / (ab.x.powi(2) + ab.y.powi(2));
+        a.lerp(b, t.clamp(0.0, 1.0))
+    }
+    pub fn distance_to_point(&self, p: Point) -> f32 {
+        self.nearest_point_on_segment_float(p.into())
+            .distance(p.into()) as f32
+    }
     pub fn segment_overlapping(&self, other: &Self) -> Vec<Point> {
         let mut so: Vec<Point> = Vec::with_capacity(2);
         for ep in self.end_points().iter().filter(|p| other == *p) {
@@ -232,3 +287,94 @@ impl LineSegment {
         so
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_perpendicular() {
+        // y = x - 2 -> x - y - 2 = 0
+        let line = Line::new(1, -1, -2);
+        let point = Point::new(0, 2);
+        let distance = line.perpendicular_distance_float(point);
+        assert!((distance - 8.0_f64.sqrt()).abs() < 1e-9);
+        assert_eq!(line.foot_of_perpendicular(point), FloatPoint::new(2.0, 0.0));
+        assert_eq!(line.perpendicular_distance_float(Point::new(5, 3)), 0.0);
+
+        // horizontal line y = 3
+        let line = Line::new(0, 1, -3);
+        let (first, second) = line.parallel_at_distance(2.0);
+        assert_eq!(first.get_line_parameter(), (0, 1, -5));
+        assert_eq!(second.get_line_parameter(), (0, 1, -1));
+        for parallel in [first, second] {
+            assert!(parallel.is_parallel(&line));
+            assert_eq!(parallel.perpendicular_distance_float(Point::new(7, 3)), 2.0);
+        }
+    }
+
+    #[test]
+    fn test_segment_intersection() {
+        let segment = LineSegment::new(Point::new(0, 0), Point::new(4, 4));
+        let crossing = LineSegment::new(Point::new(0, 4), Point::new(4, 0));
+        assert_eq!(
+            segment.segment_intersection(&crossing),
+            Some(Point::new(2, 2))
+        );
+        // collinear segments touching at end point
+        let touching = LineSegment::new(Point::new(4, 4), Point::new(6, 6));
+        assert_eq!(
+            segment.segment_intersection(&touching),
+            Some(Point::new(4, 4))
+        );
+        // collinear overlapping segments have no single intersection point
+        let overlapping = LineSegment::new(Point::new(2, 2), Point::new(6, 6));
+        assert_eq!(segment.segment_intersection(&overlapping), None);
+        assert_eq!(
+            segment.segment_overlapping(&overlapping),
+            vec![Point::new(4, 4), Point::new(2, 2)]
+        );
+        // collinear segments with gap
+        let gap = LineSegment::new(Point::new(5, 5), Point::new(6, 6));
+        assert_eq!(segment.segment_intersection(&gap), None);
+        // vertical collinear segments
+        let vertical = LineSegment::new(Point::new(0, 0), Point::new(0, 2));
+        let vertical_touching = LineSegment::new(Point::new(0, 2), Point::new(0, 5));
+        assert_eq!(
+            vertical.segment_intersection(&vertical_touching),
+            Some(Point::new(0, 2))
+        );
+        assert!(vertical != Point::new(0, 3));
+    }
+
+    #[test]
+    fn test_nearest_point_on_segment() {
+        let segment = LineSegment::new(Point::new(0, 0), Point::new(10, 0));
+        assert_eq!(
+            segment.nearest_point_on_segment(Point::new(4, 7)),
+            Point::new(4, 0)
+        );
+        assert_eq!(segment.distance_to_point(Point::new(4, 7)), 7.0);
+        // clamped to end points
+        assert_eq!(
+            segment.nearest_point_on_segment(Point::new(-3, 4)),
+            Point::new(0, 0)
+        );
+        assert_eq!(segment.distance_to_point(Point::new(-3, 4)), 5.0);
+        assert_eq!(
+            segment.nearest_point_on_segment(Point::new(15, -2)),
+            Point::new(10, 0)
+        );
+        // diagonal segment with projection between integer points
+        let segment = LineSegment::new(Point::new(0, 0), Point::new(4, 4));
+        assert_eq!(
+            segment.nearest_point_on_segment_float(FloatPoint::new(0.0, 3.0)),
+            FloatPoint::new(1.5, 1.5)
+        );
+        assert_eq!(
+            segment.nearest_point_on_segment(Point::new(0, 3)),
+            Point::new(2, 2)
+        );
+    }
+}
@@ -105,6 +105,13 @@ impl Point {
     pub fn delta(&self, target: Point) -> i64 {
         self.delta_x(target) + self.delta_y(target)
     }
+    pub fn dot_product(&self, target: Point) -> i64 {
+        self.x * target.x + self.y * target.y
+    }
+    pub fn cross_product(&self, target: Point) -> i64 {
+        // z component of cross product of self and target as 3D vectors with z = 0
+        self.x * target.y - self.y * target.x
+    }
     pub fn quadrant(&self) -> Quadrant {
         match (self.x, self.y) {
             (0, 0) => Quadrant::Origin,
@@ -146,6 +153,78 @@ impl Point {
     }
 }
 
+// FloatPoint is a two dimensional point with real valued coordinates, e.g. for
+// interpolation or results of trigonometric functions.
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct FloatPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Display for FloatPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(f64, f64)> for FloatPoint {
+    fn from(value: (f64, f64)) -> Self {
+        FloatPoint::new(value.0, value.1)
+    }
+}
+
+impl From<Point> for FloatPoint {
+    fn from(value: Point) -> Self {
+        FloatPoint::new(value.x as f64, value.y as f64)
+    }
+}
+
+impl TryFrom<FloatPoint> for Point {
+    type Error = &'static str;
+    fn try_from(value: FloatPoint) -> Result<Self, Self::Error> {
+        // round to nearest integer point
+        let x = value.x.round();
+        let y = value.y.round();
+        let range = i64::MIN as f64..i64::MAX as f64;
+        if !range.contains(&x) || !range.contains(&y) {
+            return Err("coordinates of FloatPoint are out of range of Point");
+        }
+        Ok(Point::new(x as i64, y as i64))
+    }
+}
+
+impl FloatPoint {
+    pub fn new(x: f64, y: f64) -> Self {
+        FloatPoint { x, y }
+    }
+    pub fn add(&self, offset: FloatPoint) -> FloatPoint {
+        FloatPoint {
+            x: self.x + offset.x,
+            y: self.y + offset.y,
+        }
+    }
+    pub fn subtract(&self, offset: FloatPoint) -> FloatPoint {
+        FloatPoint {
+            x: self.x - offset.x,
+            y: self.y - offset.y,
+        }
+    }
+    pub fn distance(&self, other: FloatPoint) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+    pub fn angle_to(&self, other: FloatPoint) -> f64 {
+        // angle of vector from self to other in radians in range -PI..=PI
+        (other.y - self.y).atan2(other.x - self.x)
+    }
+    pub fn lerp(&self, other: FloatPoint, t: f64) -> FloatPoint {
+        // linear interpolation: t == 0 -> self, t == 1 -> other
+        FloatPoint {
+            x: self.x + (other.x - self.x) * t,
+            y: self.y + (other.y - self.y) * t,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Copy, Clone, Default)]
 pub struct Cylindrical {
     r: f32,
@@ -230,6 +309,27 @@ impl Cylindrical {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    Counterclockwise,
+    Collinear,
+}
+
+pub fn signed_area_2x(a: Point, b: Point, c: Point) -> i64 {
+    // twice the signed area of triangle a, b, c
+    // positive, if a -> b -> c is a counterclockwise turn
+    b.subtract(a).cross_product(c.subtract(a))
+}
+
+pub fn orientation(a: Point, b: Point, c: Point) -> Orientation {
+    match signed_area_2x(a, b, c).cmp(&0) {
+        std::cmp::Ordering::Greater => Orientation::Counterclockwise,
+        std::cmp::Ordering::Less => Orientation::Clockwise,
+        std::cmp::Ordering::Equal => Orientation::Collinear,
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Default, Hash)]
 pub struct Point3D {
     pub x: i64,
@@ -296,6 +396,43 @@ mod tests {
         assert!(abs_difference <= 0.01);
     }
 
+    #[test]
+    fn test_float_point() {
+        let start = FloatPoint::new(1.0, 1.0);
+        let end = FloatPoint::from(Point::new(4, 5));
+        assert_eq!(start.distance(end), 5.0);
+        assert_eq!(start.lerp(end, 0.5), FloatPoint::new(2.5, 3.0));
+        assert_eq!(start.lerp(end, 1.0), end);
+        let angle = FloatPoint::default().angle_to(FloatPoint::new(0.0, 2.0));
+        assert!((angle - std::f64::consts::FRAC_PI_2).abs() < f64::EPSILON);
+        assert_eq!(
+            Point::try_from(FloatPoint::new(2.5, -1.4)),
+            Ok(Point::new(3, -1))
+        );
+        assert!(Point::try_from(FloatPoint::new(1e20, 0.0)).is_err());
+        assert!(Point::try_from(FloatPoint::new(f64::NAN, 0.0)).is_err());
+    }
+
+    #[test]
+    fn test_orientation() {
+        let a = Point::new(0, 0);
+        let b = Point::new(4, 0);
+        assert_eq!(signed_area_2x(a, b, Point::new(0, 3)), 12);
+        assert_eq!(signed_area_2x(a, b, Point::new(0, -3)), -12);
+        assert_eq!(
+            orientation(a, b, Point::new(1, 1)),
+            Orientation::Counterclockwise
+        );
+        assert_eq!(orientation(a, b, Point::new(1, -1)), Orientation::Clockwise);
+        assert_eq!(orientation(a, b, Point::new(-7, 0)), Orientation::Collinear);
+        // large coordinates do not overflow
+        let large = i32::MAX as i64;
+        assert_eq!(
+            signed_area_2x(a, Point::new(large, 0), Point::new(0, large)),
+            large * large
+        );
+    }
+
     #[test]
     fn test_quadrant() {
         assert_eq!(Point::new(0, 0).quadrant(), Quadrant::Origin);
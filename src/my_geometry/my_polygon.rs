@@ -0,0 +1,270 @@
+use super::{
+    my_line::LineSegment,
+    my_point::{orientation, signed_area_2x, Orientation, Point},
+};
+use std::cmp::Ordering;
+
+// Polygon: defined by list of vertices, which are connected in given order
+// last vertex is connected to first vertex
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl PartialEq<Point> for Polygon {
+    // equal if Point is on one side
+    // if vertex, point is on 2 sides
+    fn eq(&self, other: &Point) -> bool {
+        self.sides().iter().any(|s| Polygon::is_on_side(s, other))
+    }
+}
+
+impl PartialOrd<Point> for Polygon {
+    // Greater: Point inside Polygon
+    // Equal: Point is on circumference of Polygon
+    // Less: Point is outside of Polygon
+    fn partial_cmp(&self, other: &Point) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.ray_casting(other) {
+            Some(Ordering::Greater)
+        } else {
+            Some(Ordering::Less)
+        }
+    }
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Result<Self, &'static str> {
+        if vertices.len() < 3 {
+            return Err("polygon requires at least 3 vertices");
+        }
+        if (0..vertices.len()).any(|i| vertices[i] == vertices[(i + 1) % vertices.len()]) {
+            return Err("consecutive vertices of polygon must differ");
+        }
+        let v0 = vertices[0];
+        let v1 = vertices[1];
+        if vertices[2..]
+            .iter()
+            .all(|v| orientation(v0, v1, *v) == Orientation::Collinear)
+        {
+            return Err("vertices of polygon must not be collinear");
+        }
+        Ok(Self { vertices })
+    }
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+    pub fn sides(&self) -> Vec<LineSegment> {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(a, b)| LineSegment::new(*a, *b))
+            .collect()
+    }
+    pub fn area(&self) -> f64 {
+        // shoelace formula
+        let double_area: i64 = self
+            .vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum();
+        double_area.abs() as f64 / 2.0
+    }
+    pub fn perimeter(&self) -> f32 {
+        self.sides().iter().map(|s| s.len()).sum()
+    }
+    pub fn contains_point(&self, p: Point) -> bool {
+        // true if point is inside polygon or on circumference of polygon
+        self >= &p
+    }
+    fn is_on_side(side: &LineSegment, p: &Point) -> bool {
+        let [a, b] = side.end_points();
+        orientation(a, b, *p) == Orientation::Collinear
+            && side.min_x() <= p.x
+            && p.x <= side.max_x()
+            && side.min_y() <= p.y
+            && p.y <= side.max_y()
+    }
+    fn ray_casting(&self, p: &Point) -> bool {
+        // cast ray from p in positive x direction and count crossings with sides
+        // half open interval of y excludes double counting of vertices
+        let mut inside = false;
+        for side in self.sides().iter() {
+            let [a, b] = side.end_points();
+            if (a.y > p.y) != (b.y > p.y) {
+                // x of crossing point: a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y)
+                let x_crossing =
+                    a.x as f64 + (p.y - a.y) as f64 * (b.x - a.x) as f64 / (b.y - a.y) as f64;
+                if (p.x as f64) < x_crossing {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+    // Graham scan: returns vertices of convex hull in counterclockwise order
+    // collinear points on hull are dropped, only end points remain
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut sorted_points = points.to_vec();
+    sorted_points.sort_by_key(|p| (p.y, p.x));
+    sorted_points.dedup();
+    // pivot is lowest point; if more than one lowest point, use most left one
+    let pivot = sorted_points.remove(0);
+    sorted_points.sort_by(|a, b| {
+        // point with smaller polar angle relative to pivot is first
+        // if same polar angle, nearer point is first
+        signed_area_2x(pivot, *b, *a)
+            .cmp(&0)
+            .then_with(|| pivot.delta(*a).cmp(&pivot.delta(*b)))
+    });
+    let mut hull: Vec<Point> = vec![pivot];
+    for point in sorted_points {
+        while hull.len() > 1 {
+            let top = hull[hull.len() - 1];
+            let below_top = hull[hull.len() - 2];
+            if orientation(below_top, top, point) == Orientation::Counterclockwise {
+                break;
+            }
+            hull.pop();
+        }
+        hull.push(point);
+    }
+    hull
+}
+
+pub fn is_convex(polygon: &[Point]) -> bool {
+    // true if all turns along polygon have the same direction
+    // collinear consecutive vertices are ignored
+    if polygon.len() < 3 {
+        return false;
+    }
+    let mut turn_direction = Orientation::Collinear;
+    for (index, vertex) in polygon.iter().enumerate() {
+        let next = polygon[(index + 1) % polygon.len()];
+        let after_next = polygon[(index + 2) % polygon.len()];
+        match orientation(*vertex, next, after_next) {
+            Orientation::Collinear => (),
+            turn if turn_direction == Orientation::Collinear => turn_direction = turn,
+            turn if turn != turn_direction => return false,
+            _ => (),
+        }
+    }
+    turn_direction != Orientation::Collinear
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_polygon() {
+        assert!(Polygon::new(vec![Point::new(0, 0), Point::new(1, 1)]).is_err());
+        assert!(Polygon::new(vec![Point::new(0, 0), Point::new(1, 1), Point::new(2, 2)]).is_err());
+        // L shaped polygon
+        let polygon = Polygon::new(vec![
+            Point::new(0, 0),
+            Point::new(4, 0),
+            Point::new(4, 2),
+            Point::new(2, 2),
+            Point::new(2, 4),
+            Point::new(0, 4),
+        ])
+        .unwrap();
+        assert_eq!(polygon.area(), 12.0);
+        assert_eq!(polygon.perimeter(), 16.0);
+
+        let inside = Point::new(1, 3);
+        assert_eq!(polygon.partial_cmp(&inside), Some(Ordering::Greater));
+        assert!(polygon > inside);
+        assert!(polygon.contains_point(inside));
+        // ray of this point passes vertex (2, 2)
+        let inside_vertex_ray = Point::new(1, 2);
+        assert!(polygon > inside_vertex_ray);
+
+        let on_side = Point::new(3, 2);
+        assert_eq!(polygon.partial_cmp(&on_side), Some(Ordering::Equal));
+        assert!(polygon == on_side);
+        assert!(polygon.contains_point(on_side));
+        assert!(polygon == Point::new(2, 4));
+
+        let outside = Point::new(3, 3);
+        assert_eq!(polygon.partial_cmp(&outside), Some(Ordering::Less));
+        assert!(polygon < outside);
+        assert!(!polygon.contains_point(outside));
+        // outside on extension of vertical side
+        assert!(polygon < Point::new(2, 5));
+        assert!(polygon < Point::new(-1, 2));
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let few_points = [Point::new(0, 0), Point::new(1, 1)];
+        assert_eq!(convex_hull(&few_points), few_points.to_vec());
+        let collinear = [
+            Point::new(2, 2),
+            Point::new(0, 0),
+            Point::new(3, 3),
+            Point::new(1, 1),
+        ];
+        assert_eq!(
+            convex_hull(&collinear),
+            vec![Point::new(0, 0), Point::new(3, 3)]
+        );
+
+        let mut rng = thread_rng();
+        let corners = [
+            Point::new(-100, -100),
+            Point::new(100, -100),
+            Point::new(100, 100),
+            Point::new(-100, 100),
+        ];
+        for _ in 0..20 {
+            // random point cloud inside of square including corners and points on sides of square
+            let mut points: Vec<Point> = (0..200)
+                .map(|_| Point::new(rng.gen_range(-99..=99), rng.gen_range(-99..=99)))
+                .collect();
+            points.extend_from_slice(&corners);
+            points.push(Point::new(rng.gen_range(-99..=99), 100));
+            points.push(Point::new(-100, rng.gen_range(-99..=99)));
+            points.shuffle(&mut rng);
+            let hull = convex_hull(&points);
+            assert_eq!(hull, corners.to_vec());
+            assert!(is_convex(&hull));
+            let polygon = Polygon::new(hull).unwrap();
+            assert!(points.iter().all(|p| polygon.contains_point(*p)));
+        }
+
+        // triangle hull of random points inside of triangle
+        let triangle = [Point::new(0, 0), Point::new(60, 0), Point::new(0, 60)];
+        let mut points: Vec<Point> = (0..200)
+            .map(|_| {
+                let x = rng.gen_range(1..59);
+                Point::new(x, rng.gen_range(1..60 - x))
+            })
+            .collect();
+        points.extend_from_slice(&triangle);
+        points.shuffle(&mut rng);
+        assert_eq!(convex_hull(&points), triangle.to_vec());
+
+        // L shaped polygon is not convex
+        let l_shape = [
+            Point::new(0, 0),
+            Point::new(4, 0),
+            Point::new(4, 2),
+            Point::new(2, 2),
+            Point::new(2, 4),
+            Point::new(0, 4),
+        ];
+        assert!(!is_convex(&l_shape));
+        assert_eq!(convex_hull(&l_shape).len(), 5);
+    }
+}
@@ -0,0 +1,251 @@
+use super::{
+    my_circle::Circle,
+    my_line::{Line, LineSegment},
+    my_point::{orientation, signed_area_2x, Orientation, Point},
+    FormOrdering,
+};
+use std::cmp::Ordering;
+
+// Triangle: defined by three non collinear points
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Triangle {
+    a: Point,
+    b: Point,
+    c: Point,
+}
+
+impl PartialEq<Point> for Triangle {
+    // equal if Point is on one side
+    // if corner, point is on 2 sides
+    fn eq(&self, other: &Point) -> bool {
+        let [alpha, beta, gamma] = self.barycentric_weights(*other);
+        alpha >= 0 && beta >= 0 && gamma >= 0 && (alpha == 0 || beta == 0 || gamma == 0)
+    }
+}
+
+impl PartialOrd<Point> for Triangle {
+    // Greater: Point inside Triangle
+    // Equal: Point is on circumference of Triangle
+    // Less: Point is outside of Triangle
+    fn partial_cmp(&self, other: &Point) -> Option<Ordering> {
+        let [alpha, beta, gamma] = self.barycentric_weights(*other);
+        if alpha < 0 || beta < 0 || gamma < 0 {
+            Some(Ordering::Less)
+        } else if alpha == 0 || beta == 0 || gamma == 0 {
+            Some(Ordering::Equal)
+        } else {
+            Some(Ordering::Greater)
+        }
+    }
+}
+
+impl Triangle {
+    pub fn new(a: Point, b: Point, c: Point) -> Result<Self, &'static str> {
+        if orientation(a, b, c) == Orientation::Collinear {
+            return Err("vertices of triangle must not be collinear");
+        }
+        Ok(Self { a, b, c })
+    }
+    pub fn corners(&self) -> [Point; 3] {
+        [self.a, self.b, self.c]
+    }
+    pub fn sides(&self) -> [LineSegment; 3] {
+        [
+            LineSegment::new(self.a, self.b),
+            LineSegment::new(self.b, self.c),
+            LineSegment::new(self.c, self.a),
+        ]
+    }
+    fn double_signed_area(&self) -> i64 {
+        // positive, if a, b, c are ordered counterclockwise
+        signed_area_2x(self.a, self.b, self.c)
+    }
+    fn barycentric_weights(&self, p: Point) -> [i64; 3] {
+        // barycentric coordinates of p scaled by double area of triangle
+        // sign is normalized to positive, if p is inside of triangle
+        let sign = self.double_signed_area().signum();
+        [
+            sign * signed_area_2x(self.b, self.c, p),
+            sign * signed_area_2x(self.c, self.a, p),
+            sign * signed_area_2x(self.a, self.b, p),
+        ]
+    }
+    pub fn area(&self) -> f64 {
+        self.double_signed_area().abs() as f64 / 2.0
+    }
+    pub fn centroid(&self) -> Point {
+        let sum = self.a.add(self.b).add(self.c);
+        Point::new(
+            (sum.x as f64 / 3.0).round() as i64,
+            (sum.y as f64 / 3.0).round() as i64,
+        )
+    }
+    pub fn circumscribed_circle(&self) -> Circle {
+        // center is intersection of perpendicular bisectors of sides
+        // perpendicular bisector of side p-q: all points with equal distance to p and q
+        // (x - p_x)² + (y - p_y)² = (x - q_x)² + (y - q_y)²
+        // x * 2 * (q_x - p_x) + y * 2 * (q_y - p_y) + p_x² + p_y² - q_x² - q_y² = 0
+        let bisector = |p: Point, q: Point| {
+            Line::new(
+                2 * (q.x - p.x),
+                2 * (q.y - p.y),
+                p.x.pow(2) + p.y.pow(2) - q.x.pow(2) - q.y.pow(2),
+            )
+        };
+        let center = bisector(self.a, self.b)
+            .line_intersection(&bisector(self.b, self.c))
+            .unwrap();
+        let radius = center.distance(self.a).round() as i64;
+        Circle::new(center, radius)
+    }
+    pub fn incenter(&self) -> Point {
+        // average of corners weighted by length of opposite side
+        let len_a = self.b.distance(self.c) as f64;
+        let len_b = self.c.distance(self.a) as f64;
+        let len_c = self.a.distance(self.b) as f64;
+        let perimeter = len_a + len_b + len_c;
+        let x = (len_a * self.a.x as f64 + len_b * self.b.x as f64 + len_c * self.c.x as f64)
+            / perimeter;
+        let y = (len_a * self.a.y as f64 + len_b * self.b.y as f64 + len_c * self.c.y as f64)
+            / perimeter;
+        Point::new(x.round() as i64, y.round() as i64)
+    }
+    pub fn is_right_angled(&self) -> bool {
+        let corners = self.corners();
+        (0..3).any(|i| {
+            let corner = corners[i];
+            let to_next = corners[(i + 1) % 3].subtract(corner);
+            let to_previous = corners[(i + 2) % 3].subtract(corner);
+            to_next.dot_product(to_previous) == 0
+        })
+    }
+    pub fn contains_point(&self, p: Point) -> bool {
+        // true if point is inside triangle or on circumference of triangle
+        self >= &p
+    }
+    fn sides_are_crossing(&self, other: &Self) -> bool {
+        // true if any side of self crosses any side of other in a point, which is not an end point of both sides
+        self.sides().iter().any(|sside| {
+            let [sa, sb] = sside.end_points();
+            other.sides().iter().any(|oside| {
+                let [oa, ob] = oside.end_points();
+                signed_area_2x(sa, sb, oa).signum() * signed_area_2x(sa, sb, ob).signum() < 0
+                    && signed_area_2x(oa, ob, sa).signum() * signed_area_2x(oa, ob, sb).signum() < 0
+            })
+        })
+    }
+    pub fn triangle_cmp(&self, other: &Self) -> FormOrdering {
+        let self_corners = self.corners();
+        let other_corners = other.corners();
+        if other_corners.iter().all(|c| self_corners.contains(c)) {
+            return FormOrdering::Identical;
+        }
+        let other_in_self: Vec<Ordering> = other_corners
+            .iter()
+            .filter_map(|c| self.partial_cmp(c))
+            .collect();
+        let self_in_other: Vec<Ordering> = self_corners
+            .iter()
+            .filter_map(|c| other.partial_cmp(c))
+            .collect();
+        for corners_in_triangle in [&other_in_self, &self_in_other] {
+            if corners_in_triangle.iter().all(|o| *o != Ordering::Less) {
+                return if corners_in_triangle.contains(&Ordering::Equal) {
+                    FormOrdering::InsideTouching
+                } else {
+                    FormOrdering::Inside
+                };
+            }
+        }
+        if other_in_self.contains(&Ordering::Greater)
+            || self_in_other.contains(&Ordering::Greater)
+            || self.sides_are_crossing(other)
+        {
+            FormOrdering::Overlapping
+        } else if other_in_self.contains(&Ordering::Equal)
+            || self_in_other.contains(&Ordering::Equal)
+        {
+            FormOrdering::Touching
+        } else {
+            FormOrdering::NonOverlapping
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_triangle() {
+        assert!(Triangle::new(Point::new(0, 0), Point::new(1, 1), Point::new(3, 3)).is_err());
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(6, 0), Point::new(0, 8)).unwrap();
+        assert_eq!(triangle.area(), 24.0);
+        assert_eq!(triangle.centroid(), Point::new(2, 3));
+        assert!(triangle.is_right_angled());
+        // inradius of right triangle: (6 + 8 - 10) / 2 = 2
+        assert_eq!(triangle.incenter(), Point::new(2, 2));
+        let circle = triangle.circumscribed_circle();
+        assert_eq!(circle.get_center(), Point::new(3, 4));
+        assert_eq!(circle.get_radius(), 5);
+
+        let inside = Point::new(1, 1);
+        assert_eq!(triangle.partial_cmp(&inside), Some(Ordering::Greater));
+        assert!(triangle.contains_point(inside));
+        let on_side = Point::new(3, 4);
+        assert!(triangle == on_side);
+        assert!(triangle.contains_point(on_side));
+        let outside = Point::new(4, 4);
+        assert!(triangle < outside);
+        assert!(!triangle.contains_point(outside));
+
+        let not_right_angled =
+            Triangle::new(Point::new(0, 0), Point::new(4, 0), Point::new(1, 3)).unwrap();
+        assert!(!not_right_angled.is_right_angled());
+    }
+
+    #[test]
+    fn test_triangle_cmp() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(6, 0), Point::new(0, 6)).unwrap();
+        let same_corners =
+            Triangle::new(Point::new(0, 6), Point::new(0, 0), Point::new(6, 0)).unwrap();
+        assert!(matches!(
+            triangle.triangle_cmp(&same_corners),
+            FormOrdering::Identical
+        ));
+        let inside = Triangle::new(Point::new(1, 1), Point::new(3, 1), Point::new(1, 3)).unwrap();
+        assert!(matches!(
+            triangle.triangle_cmp(&inside),
+            FormOrdering::Inside
+        ));
+        assert!(matches!(
+            inside.triangle_cmp(&triangle),
+            FormOrdering::Inside
+        ));
+        let inside_touching =
+            Triangle::new(Point::new(0, 0), Point::new(3, 0), Point::new(1, 1)).unwrap();
+        assert!(matches!(
+            triangle.triangle_cmp(&inside_touching),
+            FormOrdering::InsideTouching
+        ));
+        let touching = Triangle::new(Point::new(6, 0), Point::new(8, 0), Point::new(7, 2)).unwrap();
+        assert!(matches!(
+            triangle.triangle_cmp(&touching),
+            FormOrdering::Touching
+        ));
+        // star of david: no corner inside of other triangle
+        let star_a = Triangle::new(Point::new(0, 3), Point::new(6, 3), Point::new(3, -3)).unwrap();
+        let star_b = Triangle::new(Point::new(0, -1), Point::new(6, -1), Point::new(3, 5)).unwrap();
+        assert!(matches!(
+            star_a.triangle_cmp(&star_b),
+            FormOrdering::Overlapping
+        ));
+        let far_away =
+            Triangle::new(Point::new(10, 10), Point::new(12, 10), Point::new(10, 12)).unwrap();
+        assert!(matches!(
+            triangle.triangle_cmp(&far_away),
+            FormOrdering::NonOverlapping
+        ));
+    }
+}
@@ -1,3 +1,29 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::ops::Add;
+
+use crate::my_union_find::UnionFind;
+
+// matrices of all pairs shortest paths, indexed by position of nodes in graph
+pub type CostMatrix<E> = Vec<Vec<Option<E>>>;
+pub type PredecessorMatrix = Vec<Vec<Option<usize>>>;
+// costs and predecessors of nodes reachable from start node of single source shortest paths
+type ShortestPathTree<E> = (HashMap<usize, E>, HashMap<usize, usize>);
+
+// error of shortest_path(), if a negative cycle is reachable from start node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeCycleError;
+
+impl Display for NegativeCycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "negative cycle is reachable from start node")
+    }
+}
+
 #[derive(PartialEq, Clone)]
 pub struct GraphNode<N> {
     pub id: usize,
@@ -480,9 +506,14 @@ impl<N: PartialEq + Clone, E: PartialEq + Clone + Ord> Graph<N, E> {
         &self,
     ) -> impl Iterator<Item = (&GraphEdge<E>, &GraphNode<N>, &GraphNode<N>)> {
         // edge, edge start node, edge end node
-        self.edges
-            .iter()
-            .map(move |e| (e, &self.nodes[e.start], &self.nodes[e.end]))
+        // ids of nodes are not equal to position in nodes, if nodes were removed
+        self.edges.iter().map(move |e| {
+            (
+                e,
+                self.get_node_by_id(e.start).unwrap(),
+                self.get_node_by_id(e.end).unwrap(),
+            )
+        })
     }
     pub fn iter_level_order_traversal(
         &self,
@@ -508,4 +539,1686 @@ impl<N: PartialEq + Clone, E: PartialEq + Clone + Ord> Graph<N, E> {
     ) -> impl Iterator<Item = &GraphNode<N>> {
         DepthFirstSearchTraversal::new(self, start_node, DFSEdgeChoice::MaxValue)
     }
+    fn iter_outgoing_edges(
+        &self,
+        node_id: usize,
+    ) -> impl Iterator<Item = (&GraphEdge<E>, usize)> + '_ {
+        // edges, which can be traversed from node_id, and id of node at other end of edge
+        // Simplex edges only from start to end, Duplex edges in both directions
+        self.edges.iter().filter_map(move |e| {
+            if e.start == node_id {
+                Some((e, e.end))
+            } else if e.end == node_id && e.direction == GraphEdgeDirection::Duplex {
+                Some((e, e.start))
+            } else {
+                None
+            }
+        })
+    }
+    pub fn k_hop_neighborhood(&self, start_id: usize, k: usize) -> HashSet<usize> {
+        // ids of all nodes reachable from start_id in at most k steps without start_id
+        self.k_hop_neighborhood_with_distances(start_id, k)
+            .into_keys()
+            .collect()
+    }
+    pub fn k_hop_neighborhood_with_distances(
+        &self,
+        start_id: usize,
+        k: usize,
+    ) -> HashMap<usize, usize> {
+        // BFS: key is id of node reachable from start_id in at most k steps, value is number of steps
+        // start_id is not included
+        let mut distances: HashMap<usize, usize> = HashMap::new();
+        if self.get_node_by_id(start_id).is_err() {
+            return distances;
+        }
+        let mut visited: HashSet<usize> = HashSet::from([start_id]);
+        let mut queue: VecDeque<(usize, usize)> = VecDeque::from([(start_id, 0)]);
+        while let Some((node_id, distance)) = queue.pop_front() {
+            if distance == k {
+                continue;
+            }
+            for (_, next_id) in self.iter_outgoing_edges(node_id) {
+                if visited.insert(next_id) {
+                    distances.insert(next_id, distance + 1);
+                    queue.push_back((next_id, distance + 1));
+                }
+            }
+        }
+        distances
+    }
+    pub fn in_degree(&self, node_id: usize) -> usize {
+        // number of edges, which can be traversed to node_id; Duplex edges count in both directions
+        self.edges
+            .iter()
+            .filter(|e| {
+                e.end == node_id
+                    || (e.start == node_id && e.direction == GraphEdgeDirection::Duplex)
+            })
+            .count()
+    }
+    pub fn out_degree(&self, node_id: usize) -> usize {
+        // number of edges, which can be traversed from node_id
+        self.iter_outgoing_edges(node_id).count()
+    }
+    pub fn degree(&self, node_id: usize) -> usize {
+        // number of edge ends at node_id independent of direction; looping edge counts twice
+        self.edges
+            .iter()
+            .map(|e| (e.start == node_id) as usize + (e.end == node_id) as usize)
+            .sum()
+    }
+    pub fn degree_distribution(&self) -> HashMap<usize, usize> {
+        // key: degree, value: number of nodes with this degree
+        let mut distribution: HashMap<usize, usize> = HashMap::new();
+        for node in self.nodes.iter() {
+            *distribution.entry(self.degree(node.id)).or_insert(0) += 1;
+        }
+        distribution
+    }
+    pub fn degree_sequence(&self) -> Vec<(usize, usize)> {
+        // (in_degree, out_degree) of all nodes sorted by node id
+        let mut node_ids: Vec<usize> = self.nodes.iter().map(|n| n.id).collect();
+        node_ids.sort();
+        node_ids
+            .into_iter()
+            .map(|id| (self.in_degree(id), self.out_degree(id)))
+            .collect()
+    }
+    pub fn serialize_to_dot<F, G>(&self, node_label: F, edge_label: G) -> String
+    where
+        F: Fn(&GraphNode<N>) -> String,
+        G: Fn(&GraphEdge<E>) -> String,
+    {
+        // Graphviz DOT format; nodes are identified by node id
+        // if graph contains only Duplex edges, it is serialized as undirected graph,
+        // otherwise as digraph with Duplex edges as bidirectional arcs
+        let escape = |label: String| label.replace('\\', "\\\\").replace('"', "\\\"");
+        let directed = self
+            .edges
+            .iter()
+            .any(|e| e.direction == GraphEdgeDirection::Simplex);
+        let (graph_type, connector) = if directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        let mut dot = format!("{} {{\n", graph_type);
+        for node in self.nodes.iter() {
+            dot += &format!(
+                "    {} [label=\"{}\"];\n",
+                node.id,
+                escape(node_label(node))
+            );
+        }
+        for edge in self.edges.iter() {
+            let bidirectional = if directed && edge.direction == GraphEdgeDirection::Duplex {
+                ", dir=both"
+            } else {
+                ""
+            };
+            dot += &format!(
+                "    {} {} {} [label=\"{}\"{}];\n",
+                edge.start,
+                connector,
+                edge.end,
+                escape(edge_label(edge)),
+                bidirectional
+            );
+        }
+        dot += "}\n";
+        dot
+    }
+    fn adjacency_list(&self) -> Vec<Vec<(usize, usize)>> {
+        // for each position of node in nodes: (position of edge in edges, position of node at other end)
+        // same traversal rules as iter_outgoing_edges
+        let node_index: HashMap<usize, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, n)| (n.id, index))
+            .collect();
+        let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); self.nodes.len()];
+        for (edge_index, edge) in self.edges.iter().enumerate() {
+            let start = node_index[&edge.start];
+            let end = node_index[&edge.end];
+            adjacency[start].push((edge_index, end));
+            if edge.direction == GraphEdgeDirection::Duplex && start != end {
+                adjacency[end].push((edge_index, start));
+            }
+        }
+        adjacency
+    }
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        // Tarjan's algorithm: components are returned in reverse topological order of condensed graph
+        // Simplex edges are directed, Duplex edges connect in both directions
+        // node ids of each component are in order of nodes in graph
+        let adjacency = self.adjacency_list();
+        let mut indices: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut low_links: Vec<usize> = vec![0; self.nodes.len()];
+        let mut on_stack: Vec<bool> = vec![false; self.nodes.len()];
+        let mut stack: Vec<usize> = Vec::with_capacity(self.nodes.len());
+        let mut next_index = 0;
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        for root in 0..self.nodes.len() {
+            if indices[root].is_some() {
+                continue;
+            }
+            indices[root] = Some(next_index);
+            low_links[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            // call stack of depth first search: node and position of next edge in adjacency list
+            let mut call_stack: Vec<(usize, usize)> = vec![(root, 0)];
+            while let Some((node, edge_position)) = call_stack.last_mut() {
+                let node = *node;
+                if let Some((_, next)) = adjacency[node].get(*edge_position) {
+                    *edge_position += 1;
+                    match indices[*next] {
+                        None => {
+                            indices[*next] = Some(next_index);
+                            low_links[*next] = next_index;
+                            next_index += 1;
+                            stack.push(*next);
+                            on_stack[*next] = true;
+                            call_stack.push((*next, 0));
+                        }
+                        Some(index) if on_stack[*next] => {
+                            low_links[node] = low_links[node].min(index);
+                        }
+                        _ => (),
+                    }
+                    continue;
+                }
+                call_stack.pop();
+                if Some(low_links[node]) == indices[node] {
+                    let mut component: Vec<usize> = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.sort();
+                    components.push(component.into_iter().map(|i| self.nodes[i].id).collect());
+                }
+                if let Some((parent, _)) = call_stack.last() {
+                    low_links[*parent] = low_links[*parent].min(low_links[node]);
+                }
+            }
+        }
+        components
+    }
+    pub fn is_strongly_connected(&self) -> bool {
+        // true, if every node can be reached from every other node
+        self.strongly_connected_components().len() <= 1
+    }
+    fn low_link_search(&self) -> (Vec<usize>, Vec<usize>) {
+        // Tarjan's depth first search with low links over Duplex edges
+        // returns node ids of articulation points and edge ids of bridges
+        let adjacency = self.adjacency_list();
+        let mut discovery: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut low_links: Vec<usize> = vec![0; self.nodes.len()];
+        let mut is_articulation_point: Vec<bool> = vec![false; self.nodes.len()];
+        let mut bridges: Vec<usize> = Vec::new();
+        let mut time = 0;
+        for root in 0..self.nodes.len() {
+            if discovery[root].is_some() {
+                continue;
+            }
+            discovery[root] = Some(time);
+            low_links[root] = time;
+            time += 1;
+            let mut root_children = 0;
+            // call stack: node, position of next edge in adjacency list and edge to parent node
+            // parent is identified by edge, since parallel edges to parent are no bridges
+            let mut call_stack: Vec<(usize, usize, Option<usize>)> = vec![(root, 0, None)];
+            while let Some((node, edge_position, parent_edge)) = call_stack.last_mut() {
+                let node = *node;
+                if let Some((edge_index, next)) = adjacency[node].get(*edge_position) {
+                    *edge_position += 1;
+                    if self.edges[*edge_index].direction != GraphEdgeDirection::Duplex
+                        || Some(*edge_index) == *parent_edge
+                        || *next == node
+                    {
+                        continue;
+                    }
+                    match discovery[*next] {
+                        None => {
+                            discovery[*next] = Some(time);
+                            low_links[*next] = time;
+                            time += 1;
+                            call_stack.push((*next, 0, Some(*edge_index)));
+                        }
+                        Some(next_discovery) => {
+                            low_links[node] = low_links[node].min(next_discovery);
+                        }
+                    }
+                    continue;
+                }
+                let parent_edge = *parent_edge;
+                call_stack.pop();
+                if let Some((parent, _, _)) = call_stack.last() {
+                    let parent = *parent;
+                    low_links[parent] = low_links[parent].min(low_links[node]);
+                    let parent_discovery = discovery[parent].unwrap();
+                    if low_links[node] > parent_discovery {
+                        bridges.push(self.edges[parent_edge.unwrap()].id);
+                    }
+                    if parent == root {
+                        root_children += 1;
+                    } else if low_links[node] >= parent_discovery {
+                        is_articulation_point[parent] = true;
+                    }
+                }
+            }
+            is_articulation_point[root] = root_children > 1;
+        }
+        let articulation_points: Vec<usize> = self
+            .nodes
+            .iter()
+            .zip(is_articulation_point)
+            .filter(|(_, is_articulation_point)| *is_articulation_point)
+            .map(|(n, _)| n.id)
+            .collect();
+        bridges.sort();
+        (articulation_points, bridges)
+    }
+    pub fn articulation_points(&self) -> Vec<usize> {
+        // node ids, whose removal increases number of connected components
+        // only Duplex edges are considered
+        self.low_link_search().0
+    }
+    pub fn bridges(&self) -> Vec<usize> {
+        // edge ids, whose removal increases number of connected components
+        // only Duplex edges are considered
+        self.low_link_search().1
+    }
+    fn iter_simplex_edges(&self) -> impl Iterator<Item = &GraphEdge<E>> {
+        self.edges
+            .iter()
+            .filter(|e| e.direction == GraphEdgeDirection::Simplex)
+    }
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Vec<usize>> {
+        // Kahn's algorithm: returns node ids in topological order
+        // only Simplex edges define an order, Duplex edges are ignored
+        // if graph contains a cycle, returns ids of nodes, which form a cycle (in order of nodes in graph)
+        let mut in_degree: HashMap<usize, usize> = self.nodes.iter().map(|n| (n.id, 0)).collect();
+        for edge in self.iter_simplex_edges() {
+            *in_degree.get_mut(&edge.end).unwrap() += 1;
+        }
+        let mut queue: VecDeque<usize> = self
+            .nodes
+            .iter()
+            .map(|n| n.id)
+            .filter(|id| in_degree[id] == 0)
+            .collect();
+        let mut sorted: Vec<usize> = Vec::with_capacity(self.nodes.len());
+        while let Some(node_id) = queue.pop_front() {
+            sorted.push(node_id);
+            for edge in self.iter_simplex_edges().filter(|e| e.start == node_id) {
+                let degree = in_degree.get_mut(&edge.end).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(edge.end);
+                }
+            }
+        }
+        if sorted.len() == self.nodes.len() {
+            return Ok(sorted);
+        }
+        // remaining nodes are part of a cycle or reachable from a cycle
+        // remove nodes, which do not lead back into remaining nodes
+        let mut remaining: Vec<usize> = self
+            .nodes
+            .iter()
+            .map(|n| n.id)
+            .filter(|id| in_degree[id] > 0)
+            .collect();
+        loop {
+            let count = remaining.len();
+            let current = remaining.clone();
+            remaining.retain(|id| {
+                self.iter_simplex_edges()
+                    .any(|e| e.start == *id && current.contains(&e.end))
+            });
+            if remaining.len() == count {
+                return Err(remaining);
+            }
+        }
+    }
+    pub fn has_cycle(&self) -> bool {
+        // depth first search over Simplex edges, which stops at first edge back to a node on current path
+        // Duplex edges are ignored
+        let mut finished: Vec<usize> = Vec::with_capacity(self.nodes.len());
+        for node in self.nodes.iter() {
+            if finished.contains(&node.id) {
+                continue;
+            }
+            // stack of nodes on current path and index of next edge to check
+            let mut path: Vec<(usize, usize)> = vec![(node.id, 0)];
+            while let Some((node_id, edge_index)) = path.last_mut() {
+                let next_edge = self.edges[*edge_index..].iter().position(|e| {
+                    e.direction == GraphEdgeDirection::Simplex && e.start == *node_id
+                });
+                match next_edge {
+                    Some(position) => {
+                        let next_id = self.edges[*edge_index + position].end;
+                        *edge_index += position + 1;
+                        if path.iter().any(|(id, _)| *id == next_id) {
+                            return true;
+                        }
+                        if !finished.contains(&next_id) {
+                            path.push((next_id, 0));
+                        }
+                    }
+                    None => {
+                        finished.push(*node_id);
+                        path.pop();
+                    }
+                }
+            }
+        }
+        false
+    }
+    fn spanning_tree(&self, maximum: bool) -> Graph<N, E> {
+        // Kruskal's algorithm: add Duplex edges sorted by value, if they connect two separate trees
+        // Simplex edges are ignored; if graph is not connected, result is a spanning forest
+        let node_index: HashMap<usize, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, n)| (n.id, index))
+            .collect();
+        let mut union_find = UnionFind::new(self.nodes.len());
+        let mut sorted_edges: Vec<&GraphEdge<E>> = self
+            .edges
+            .iter()
+            .filter(|e| e.direction == GraphEdgeDirection::Duplex)
+            .collect();
+        sorted_edges.sort_by(|a, b| a.value.cmp(&b.value));
+        if maximum {
+            sorted_edges.reverse();
+        }
+        let mut tree_edges: Vec<usize> = Vec::with_capacity(self.nodes.len());
+        for edge in sorted_edges {
+            if union_find.union(node_index[&edge.start], node_index[&edge.end]) {
+                tree_edges.push(edge.id);
+            }
+        }
+        let mut tree = self.clone();
+        tree.edges.retain(|e| tree_edges.contains(&e.id));
+        tree
+    }
+    pub fn minimum_spanning_tree(&self) -> Graph<N, E> {
+        // returns graph with all nodes and only edges of minimum spanning tree
+        self.spanning_tree(false)
+    }
+    pub fn maximum_spanning_tree(&self) -> Graph<N, E> {
+        // returns graph with all nodes and only edges of maximum spanning tree
+        self.spanning_tree(true)
+    }
+    pub fn subgraph(&self, node_ids: &[usize]) -> Graph<N, E> {
+        // returns graph with given nodes and all edges between them; ids of nodes and edges are kept
+        let mut subgraph = self.clone();
+        subgraph.nodes.retain(|n| node_ids.contains(&n.id));
+        subgraph
+            .edges
+            .retain(|e| node_ids.contains(&e.start) && node_ids.contains(&e.end));
+        subgraph
+    }
+    pub fn induced_subgraph(&self, predicate: impl Fn(&GraphNode<N>) -> bool) -> Graph<N, E> {
+        // returns subgraph of all nodes, which fulfill predicate
+        let node_ids: Vec<usize> = self
+            .nodes
+            .iter()
+            .filter(|n| predicate(n))
+            .map(|n| n.id)
+            .collect();
+        self.subgraph(&node_ids)
+    }
+    pub fn complement(&self) -> Graph<N, E>
+    where
+        E: Default,
+    {
+        // returns graph with all nodes and a Duplex edge with default value between each pair of
+        // different nodes, which are not connected by an edge in any direction
+        let mut complement = self.clone();
+        complement.edges.clear();
+        complement.edge_count = 0;
+        for (index, start) in self.nodes.iter().enumerate() {
+            for end in self.nodes.iter().skip(index + 1) {
+                if !self.edges.iter().any(|e| {
+                    (e.start == start.id && e.end == end.id)
+                        || (e.start == end.id && e.end == start.id)
+                }) {
+                    complement
+                        .add_edge(start.id, end.id, E::default(), GraphEdgeDirection::Duplex)
+                        .unwrap();
+                }
+            }
+        }
+        complement
+    }
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartite_coloring().is_some()
+    }
+    pub fn bipartite_coloring(&self) -> Option<HashMap<usize, bool>> {
+        // BFS coloring of each connected component; None, if an odd cycle exists
+        // only Duplex edges are considered, since bipartiteness is undefined for directed edges
+        let mut colors: HashMap<usize, bool> = HashMap::with_capacity(self.nodes.len());
+        for node in self.nodes.iter() {
+            if colors.contains_key(&node.id) {
+                continue;
+            }
+            colors.insert(node.id, true);
+            let mut queue: VecDeque<usize> = VecDeque::from([node.id]);
+            while let Some(current) = queue.pop_front() {
+                let color = colors[&current];
+                for edge in self
+                    .edges
+                    .iter()
+                    .filter(|e| e.direction == GraphEdgeDirection::Duplex)
+                {
+                    let next = if edge.start == current {
+                        edge.end
+                    } else if edge.end == current {
+                        edge.start
+                    } else {
+                        continue;
+                    };
+                    match colors.get(&next) {
+                        Some(next_color) if *next_color == color => return None,
+                        Some(_) => (),
+                        None => {
+                            colors.insert(next, !color);
+                            queue.push_back(next);
+                        }
+                    }
+                }
+            }
+        }
+        Some(colors)
+    }
+    pub fn pagerank(&self, damping: f64, iterations: usize, tolerance: f64) -> HashMap<usize, f64> {
+        // power iteration: PR(v) = (1 - d) / N + d * sum(PR(u) / out_degree(u)) of all u linking to v
+        // Simplex edges are links from start to end, Duplex edges are links in both directions
+        // score of nodes without outgoing links is distributed to all nodes, therefore sum of scores is 1
+        let size = self.nodes.len();
+        if size == 0 {
+            return HashMap::new();
+        }
+        let adjacency = self.adjacency_list();
+        let mut scores = vec![1.0 / size as f64; size];
+        for _ in 0..iterations {
+            let dangling_score: f64 = adjacency
+                .iter()
+                .zip(scores.iter())
+                .filter(|(links, _)| links.is_empty())
+                .map(|(_, score)| score)
+                .sum();
+            let mut new_scores =
+                vec![(1.0 - damping) / size as f64 + damping * dangling_score / size as f64; size];
+            for (links, score) in adjacency.iter().zip(scores.iter()) {
+                for (_, end) in links.iter() {
+                    new_scores[*end] += damping * score / links.len() as f64;
+                }
+            }
+            let change: f64 = new_scores
+                .iter()
+                .zip(scores.iter())
+                .map(|(new, old)| (new - old).abs())
+                .sum();
+            scores = new_scores;
+            if change < tolerance {
+                break;
+            }
+        }
+        self.nodes
+            .iter()
+            .zip(scores)
+            .map(|(n, score)| (n.id, score))
+            .collect()
+    }
+    fn laplacian_matrix(&self) -> Vec<Vec<f64>> {
+        // unweighted laplacian of graph; edges are treated as undirected, loops are ignored
+        // rows and columns are indexed by position of node in nodes
+        let n = self.nodes.len();
+        let mut laplacian = vec![vec![0.0; n]; n];
+        for edge in self.edges.iter().filter(|e| e.start != e.end) {
+            let start = self.nodes.iter().position(|n| n.id == edge.start).unwrap();
+            let end = self.nodes.iter().position(|n| n.id == edge.end).unwrap();
+            if laplacian[start][end] == 0.0 {
+                laplacian[start][end] = -1.0;
+                laplacian[end][start] = -1.0;
+                laplacian[start][start] += 1.0;
+                laplacian[end][end] += 1.0;
+            }
+        }
+        laplacian
+    }
+    pub fn fiedler_vector(&self) -> Option<Vec<f64>> {
+        // eigenvector of second smallest eigenvalue of laplacian matrix
+        // values are indexed by position of node in iter_nodes()
+        // returns None, if graph has less than two nodes or is disconnected
+        let n = self.nodes.len();
+        if n < 2 {
+            return None;
+        }
+        let laplacian = self.laplacian_matrix();
+        // check connectivity (edges are treated as undirected)
+        let mut visited = vec![false; n];
+        let mut stack = vec![0];
+        visited[0] = true;
+        while let Some(current) = stack.pop() {
+            for (neighbor, value) in laplacian[current].iter().enumerate() {
+                if *value < 0.0 && !visited[neighbor] {
+                    visited[neighbor] = true;
+                    stack.push(neighbor);
+                }
+            }
+        }
+        if visited.iter().any(|v| !v) {
+            return None;
+        }
+        // power iteration on shift * I - L: largest eigenvalue of L is smaller or equal 2 * max degree.
+        // Eigenvector of smallest eigenvalue of L is constant vector, which is removed in each iteration.
+        // Therefore power iteration converges to eigenvector of second smallest eigenvalue of L.
+        let shift = 2.0 * (0..n).map(|i| laplacian[i][i]).fold(0.0, f64::max) + 1.0;
+        let remove_mean_and_normalize = |v: &mut Vec<f64>| -> bool {
+            let mean = v.iter().sum::<f64>() / n as f64;
+            v.iter_mut().for_each(|x| *x -= mean);
+            let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm < f64::EPSILON {
+                return false;
+            }
+            v.iter_mut().for_each(|x| *x /= norm);
+            true
+        };
+        // deterministic start vector, which is not symmetric to node order
+        let mut fiedler: Vec<f64> = (0..n).map(|i| ((i * 7919 + 13) % 101) as f64).collect();
+        if !remove_mean_and_normalize(&mut fiedler) {
+            return None;
+        }
+        for _ in 0..10_000 {
+            let mut next: Vec<f64> = (0..n)
+                .map(|i| {
+                    shift * fiedler[i] - (0..n).map(|j| laplacian[i][j] * fiedler[j]).sum::<f64>()
+                })
+                .collect();
+            if !remove_mean_and_normalize(&mut next) {
+                return None;
+            }
+            let delta = next
+                .iter()
+                .zip(fiedler.iter())
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0, f64::max);
+            fiedler = next;
+            if delta < 1e-12 {
+                break;
+            }
+        }
+        // sign of eigenvector is arbitrary: first non zero value is set to negative
+        if let Some(first) = fiedler.iter().find(|x| x.abs() > 1e-9) {
+            if *first > 0.0 {
+                fiedler.iter_mut().for_each(|x| *x = -*x);
+            }
+        }
+        Some(fiedler)
+    }
+    pub fn spectral_partition(&self) -> (Vec<usize>, Vec<usize>) {
+        // two-way partition of node ids by thresholding fiedler vector at its median
+        // if fiedler vector does not exist, all nodes are returned in first partition
+        let fiedler = match self.fiedler_vector() {
+            Some(fiedler) => fiedler,
+            None => return (self.nodes.iter().map(|n| n.id).collect(), Vec::new()),
+        };
+        let mut sorted_nodes: Vec<(f64, usize)> = fiedler
+            .into_iter()
+            .zip(self.nodes.iter().map(|n| n.id))
+            .collect();
+        sorted_nodes.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let median_index = sorted_nodes.len() / 2;
+        let second: Vec<usize> = sorted_nodes
+            .drain(median_index..)
+            .map(|(_, id)| id)
+            .collect();
+        let first: Vec<usize> = sorted_nodes.into_iter().map(|(_, id)| id).collect();
+        (first, second)
+    }
+}
+
+impl<N: PartialEq + Clone, E: PartialEq + Clone + Ord + Add<Output = E> + Default> Graph<N, E> {
+    fn dijkstra_search(
+        &self,
+        start_id: usize,
+        end_id: Option<usize>,
+    ) -> (HashMap<usize, E>, HashMap<usize, usize>) {
+        // returns costs and predecessors of settled nodes; search stops, if end_id is settled
+        // edge values must not be negative
+        let mut costs: HashMap<usize, E> = HashMap::new();
+        let mut predecessors: HashMap<usize, usize> = HashMap::new();
+        if self.get_node_by_id(start_id).is_err() {
+            return (costs, predecessors);
+        }
+        // (cost, node id, predecessor id)
+        let mut queue: BinaryHeap<(Reverse<E>, usize, Option<usize>)> = BinaryHeap::new();
+        queue.push((Reverse(E::default()), start_id, None));
+        while let Some((Reverse(cost), node_id, predecessor)) = queue.pop() {
+            if costs.contains_key(&node_id) {
+                // node is already settled with lower cost
+                continue;
+            }
+            costs.insert(node_id, cost.clone());
+            if let Some(predecessor) = predecessor {
+                predecessors.insert(node_id, predecessor);
+            }
+            if end_id == Some(node_id) {
+                break;
+            }
+            for (edge, next_id) in self.iter_outgoing_edges(node_id) {
+                if !costs.contains_key(&next_id) {
+                    queue.push((
+                        Reverse(cost.clone() + edge.value.clone()),
+                        next_id,
+                        Some(node_id),
+                    ));
+                }
+            }
+        }
+        (costs, predecessors)
+    }
+    fn trace_path(
+        mut costs: HashMap<usize, E>,
+        predecessors: HashMap<usize, usize>,
+        end_id: usize,
+    ) -> Option<(Vec<usize>, E)> {
+        // node ids of path from start to end_id (including both) and cost of path
+        let cost = costs.remove(&end_id)?;
+        let mut path = vec![end_id];
+        while let Some(predecessor) = predecessors.get(path.last().unwrap()) {
+            path.push(*predecessor);
+        }
+        path.reverse();
+        Some((path, cost))
+    }
+    pub fn dijkstra(&self, start_id: usize, end_id: usize) -> Option<(Vec<usize>, E)> {
+        // returns node ids of path from start_id to end_id (including both) and cost of path
+        let (costs, predecessors) = self.dijkstra_search(start_id, Some(end_id));
+        Self::trace_path(costs, predecessors, end_id)
+    }
+    pub fn dijkstra_all_from(&self, start_id: usize) -> HashMap<usize, E> {
+        // cost of shortest path from start_id to all reachable nodes
+        self.dijkstra_search(start_id, None).0
+    }
+    fn iter_arcs(&self) -> impl Iterator<Item = (usize, usize, &E)> + '_ {
+        // (start id, end id, value) of every traversable direction of edges
+        self.edges.iter().flat_map(|e| {
+            let backward =
+                (e.direction == GraphEdgeDirection::Duplex).then_some((e.end, e.start, &e.value));
+            std::iter::once((e.start, e.end, &e.value)).chain(backward)
+        })
+    }
+    fn relax_arcs(
+        &self,
+        costs: &mut HashMap<usize, E>,
+        predecessors: &mut HashMap<usize, usize>,
+    ) -> bool {
+        // one pass of Bellman-Ford over all arcs; returns true, if any cost decreased
+        let mut relaxed = false;
+        for (start, end, value) in self.iter_arcs() {
+            let Some(start_cost) = costs.get(&start) else {
+                continue;
+            };
+            let cost = start_cost.clone() + value.clone();
+            if costs.get(&end).is_none_or(|c| &cost < c) {
+                costs.insert(end, cost);
+                predecessors.insert(end, start);
+                relaxed = true;
+            }
+        }
+        relaxed
+    }
+    fn bellman_ford_search(&self, start_id: usize) -> Result<ShortestPathTree<E>, &'static str> {
+        // returns costs and predecessors of all nodes reachable from start_id
+        // edge values may be negative; Duplex edge with negative value is a negative cycle
+        let mut costs: HashMap<usize, E> = HashMap::new();
+        let mut predecessors: HashMap<usize, usize> = HashMap::new();
+        if self.get_node_by_id(start_id).is_err() {
+            return Ok((costs, predecessors));
+        }
+        costs.insert(start_id, E::default());
+        // shortest paths have at most number of nodes - 1 edges
+        for _ in 1..self.nodes.len() {
+            if !self.relax_arcs(&mut costs, &mut predecessors) {
+                return Ok((costs, predecessors));
+            }
+        }
+        if self.relax_arcs(&mut costs, &mut predecessors) {
+            return Err("negative cycle is reachable from start node");
+        }
+        Ok((costs, predecessors))
+    }
+    pub fn bellman_ford(&self, start_id: usize) -> Result<HashMap<usize, E>, &'static str> {
+        // cost of shortest path from start_id to all reachable nodes; edge values may be negative
+        Ok(self.bellman_ford_search(start_id)?.0)
+    }
+    pub fn detect_negative_cycle(&self) -> bool {
+        // Bellman-Ford from virtual node, which is connected to all nodes with value zero
+        let mut costs: HashMap<usize, E> =
+            self.nodes.iter().map(|n| (n.id, E::default())).collect();
+        let mut predecessors: HashMap<usize, usize> = HashMap::new();
+        for _ in 0..self.nodes.len() {
+            if !self.relax_arcs(&mut costs, &mut predecessors) {
+                return false;
+            }
+        }
+        self.relax_arcs(&mut costs, &mut predecessors)
+    }
+    pub fn shortest_path(
+        &self,
+        start_id: usize,
+        end_id: usize,
+    ) -> Result<Option<(Vec<usize>, E)>, NegativeCycleError> {
+        // dijkstra, if all edge values are not negative, otherwise Bellman-Ford
+        if self.edges.iter().all(|e| e.value >= E::default()) {
+            return Ok(self.dijkstra(start_id, end_id));
+        }
+        let (costs, predecessors) = self
+            .bellman_ford_search(start_id)
+            .map_err(|_| NegativeCycleError)?;
+        Ok(Self::trace_path(costs, predecessors, end_id))
+    }
+    pub fn all_pairs_shortest_paths(&self) -> CostMatrix<E> {
+        self.all_pairs_shortest_paths_with_paths().0
+    }
+    pub fn all_pairs_shortest_paths_with_paths(&self) -> (CostMatrix<E>, PredecessorMatrix) {
+        // Floyd-Warshall: matrices are indexed by position of nodes in graph (insertion order)
+        // costs[i][j]: cost of shortest path from node i to node j, None if not reachable
+        // predecessors[i][j]: position of node before node j on shortest path from node i to node j
+        // edge values must not be negative
+        let size = self.nodes.len();
+        let mut costs: CostMatrix<E> = vec![vec![None; size]; size];
+        let mut predecessors: PredecessorMatrix = vec![vec![None; size]; size];
+        for (start, edges) in self.adjacency_list().into_iter().enumerate() {
+            costs[start][start] = Some(E::default());
+            for (edge_index, end) in edges {
+                let value = &self.edges[edge_index].value;
+                if start != end && costs[start][end].as_ref().is_none_or(|c| value < c) {
+                    costs[start][end] = Some(value.clone());
+                    predecessors[start][end] = Some(start);
+                }
+            }
+        }
+        for via in 0..size {
+            for start in 0..size {
+                let Some(start_via) = costs[start][via].clone() else {
+                    continue;
+                };
+                for end in 0..size {
+                    let Some(via_end) = costs[via][end].clone() else {
+                        continue;
+                    };
+                    let cost = start_via.clone() + via_end;
+                    if costs[start][end].as_ref().is_none_or(|c| &cost < c) {
+                        costs[start][end] = Some(cost);
+                        predecessors[start][end] = predecessors[via][end];
+                    }
+                }
+            }
+        }
+        (costs, predecessors)
+    }
+}
+
+impl<N: PartialEq + Clone + Display, E: PartialEq + Clone + Ord + Display> Graph<N, E> {
+    pub fn serialize_to_dot_default(&self) -> String {
+        // labels of nodes and edges are their displayed item and value
+        self.serialize_to_dot(|n| n.item.to_string(), |e| e.value.to_string())
+    }
+}
+
+// flow network on top of Graph: each directed edge is stored as a Simplex forward edge and a
+// Simplex backward edge. Edge values hold residual capacities, which are updated by max_flow().
+pub struct FlowNetwork<N> {
+    graph: Graph<N, i64>,
+    // forward edge id -> (backward edge id, capacity)
+    forward_edges: HashMap<usize, (usize, i64)>,
+    // backward edge id -> forward edge id
+    backward_edges: HashMap<usize, usize>,
+}
+
+impl<N: PartialEq + Clone> FlowNetwork<N> {
+    pub fn new(expected_number_of_nodes: usize, expected_number_of_edges: usize) -> Self {
+        FlowNetwork {
+            graph: Graph::new(
+                expected_number_of_nodes,
+                2 * expected_number_of_edges,
+                false,
+            ),
+            forward_edges: HashMap::with_capacity(expected_number_of_edges),
+            backward_edges: HashMap::with_capacity(expected_number_of_edges),
+        }
+    }
+    pub fn graph(&self) -> &Graph<N, i64> {
+        &self.graph
+    }
+    pub fn add_node(&mut self, item: N) -> usize {
+        self.graph.add_node(item)
+    }
+    pub fn add_directed_edge(
+        &mut self,
+        from: usize,
+        to: usize,
+        capacity: i64,
+    ) -> Result<usize, &str> {
+        // returns id of forward edge
+        if capacity < 0 {
+            return Err("capacity must not be negative");
+        }
+        if from == to {
+            return Err("looping edge is not allowed in flow network");
+        }
+        if self.graph.get_node_by_id(from).is_err() || self.graph.get_node_by_id(to).is_err() {
+            return Err("node id does not exist");
+        }
+        let forward = self
+            .graph
+            .add_edge(from, to, capacity, GraphEdgeDirection::Simplex)
+            .unwrap();
+        let backward = self
+            .graph
+            .add_edge(to, from, 0, GraphEdgeDirection::Simplex)
+            .unwrap();
+        self.forward_edges.insert(forward, (backward, capacity));
+        self.backward_edges.insert(backward, forward);
+        Ok(forward)
+    }
+    pub fn get_flow(&self, forward_edge: usize) -> Result<i64, &str> {
+        // flow on forward edge after last call of max_flow()
+        let (_, capacity) = self
+            .forward_edges
+            .get(&forward_edge)
+            .ok_or("edge is not a forward edge of flow network")?;
+        Ok(capacity - self.graph.get_edge_by_id(forward_edge)?.value)
+    }
+    fn reset_residual_capacities(&mut self) {
+        for (forward, (backward, capacity)) in self.forward_edges.iter() {
+            *self.graph.get_edge_value_mut_by_id(*forward).unwrap() = *capacity;
+            *self.graph.get_edge_value_mut_by_id(*backward).unwrap() = 0;
+        }
+    }
+    fn find_augmenting_path(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+        // breadth first search in residual graph, returns edge ids of shortest path
+        let mut parent_edge: HashMap<usize, usize> = HashMap::new();
+        let mut queue = VecDeque::from([source]);
+        while let Some(current) = queue.pop_front() {
+            if current == sink {
+                let mut path = Vec::new();
+                let mut node = sink;
+                while node != source {
+                    let edge = self.graph.get_edge_by_id(parent_edge[&node]).unwrap();
+                    path.push(edge.id);
+                    node = edge.start;
+                }
+                return Some(path);
+            }
+            for edge in self
+                .graph
+                .edges
+                .iter()
+                .filter(|e| e.start == current && e.value > 0)
+            {
+                if edge.end != source && !parent_edge.contains_key(&edge.end) {
+                    parent_edge.insert(edge.end, edge.id);
+                    queue.push_back(edge.end);
+                }
+            }
+        }
+        None
+    }
+    fn reverse_edge(&self, edge: usize) -> usize {
+        match self.forward_edges.get(&edge) {
+            Some((backward, _)) => *backward,
+            None => self.backward_edges[&edge],
+        }
+    }
+    pub fn max_flow(&mut self, source: usize, sink: usize) -> i64 {
+        // Edmonds-Karp: augment flow along shortest paths of residual graph
+        self.reset_residual_capacities();
+        if source == sink
+            || self.graph.get_node_by_id(source).is_err()
+            || self.graph.get_node_by_id(sink).is_err()
+        {
+            return 0;
+        }
+        let mut flow = 0;
+        while let Some(path) = self.find_augmenting_path(source, sink) {
+            let path_flow = path
+                .iter()
+                .map(|id| self.graph.get_edge_by_id(*id).unwrap().value)
+                .min()
+                .unwrap();
+            for id in path {
+                *self.graph.get_edge_value_mut_by_id(id).unwrap() -= path_flow;
+                let reverse = self.reverse_edge(id);
+                *self.graph.get_edge_value_mut_by_id(reverse).unwrap() += path_flow;
+            }
+            flow += path_flow;
+        }
+        flow
+    }
+    pub fn min_cut(&mut self, source: usize, sink: usize) -> Vec<usize> {
+        // returns ids of forward edges, which separate nodes reachable from source in residual
+        // graph after max flow from all other nodes
+        self.max_flow(source, sink);
+        let mut reachable = vec![source];
+        let mut index = 0;
+        while index < reachable.len() {
+            let current = reachable[index];
+            for edge in self
+                .graph
+                .edges
+                .iter()
+                .filter(|e| e.start == current && e.value > 0)
+            {
+                if !reachable.contains(&edge.end) {
+                    reachable.push(edge.end);
+                }
+            }
+            index += 1;
+        }
+        let mut cut: Vec<usize> = self
+            .forward_edges
+            .keys()
+            .copied()
+            .filter(|id| {
+                let edge = self.graph.get_edge_by_id(*id).unwrap();
+                reachable.contains(&edge.start) && !reachable.contains(&edge.end)
+            })
+            .collect();
+        cut.sort();
+        cut
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spectral_partition() {
+        // two triangles connected by one edge
+        let mut graph: Graph<char, u8> = Graph::new(6, 7, true);
+        let ids: Vec<usize> = "abcdef".chars().map(|c| graph.add_node(c)).collect();
+        for (start, end) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+            graph
+                .add_edge(ids[start], ids[end], 1, GraphEdgeDirection::Duplex)
+                .unwrap();
+        }
+        let fiedler = graph.fiedler_vector().unwrap();
+        assert_eq!(fiedler.len(), 6);
+        assert!(fiedler.iter().sum::<f64>().abs() < 1e-9);
+        let (mut first, mut second) = graph.spectral_partition();
+        first.sort();
+        second.sort();
+        assert_eq!(first, vec![ids[0], ids[1], ids[2]]);
+        assert_eq!(second, vec![ids[3], ids[4], ids[5]]);
+
+        // disconnected graph has no fiedler vector
+        graph.add_node('g');
+        assert!(graph.fiedler_vector().is_none());
+        let (first, second) = graph.spectral_partition();
+        assert_eq!(first.len(), 7);
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let mut graph: Graph<char, u32> = Graph::new(6, 8, true);
+        let ids: Vec<usize> = "abcdef".chars().map(|c| graph.add_node(c)).collect();
+        let (a, b, c, d, e, f) = (ids[0], ids[1], ids[2], ids[3], ids[4], ids[5]);
+        graph.add_edge(a, b, 7, GraphEdgeDirection::Duplex).unwrap();
+        graph.add_edge(a, c, 9, GraphEdgeDirection::Duplex).unwrap();
+        graph
+            .add_edge(a, f, 14, GraphEdgeDirection::Duplex)
+            .unwrap();
+        graph
+            .add_edge(b, c, 10, GraphEdgeDirection::Duplex)
+            .unwrap();
+        graph
+            .add_edge(b, d, 15, GraphEdgeDirection::Duplex)
+            .unwrap();
+        graph
+            .add_edge(c, d, 11, GraphEdgeDirection::Duplex)
+            .unwrap();
+        graph.add_edge(c, f, 2, GraphEdgeDirection::Duplex).unwrap();
+        // e can only be left, not entered
+        graph
+            .add_edge(e, d, 6, GraphEdgeDirection::Simplex)
+            .unwrap();
+
+        assert_eq!(graph.dijkstra(a, f), Some((vec![a, c, f], 11)));
+        assert_eq!(graph.dijkstra(f, b), Some((vec![f, c, b], 12)));
+        assert_eq!(graph.dijkstra(a, a), Some((vec![a], 0)));
+        assert_eq!(graph.dijkstra(a, e), None);
+        assert_eq!(graph.dijkstra(e, a), Some((vec![e, d, c, a], 26)));
+
+        let costs = graph.dijkstra_all_from(a);
+        assert_eq!(costs.len(), 5);
+        assert_eq!(costs[&d], 20);
+        assert_eq!(costs[&b], 7);
+        assert!(!costs.contains_key(&e));
+        assert!(graph.dijkstra_all_from(42).is_empty());
+    }
+
+    #[test]
+    fn test_shortest_path_negative_values() {
+        let mut graph: Graph<char, i32> = Graph::new(6, 7, true);
+        let ids: Vec<usize> = "abcdef".chars().map(|c| graph.add_node(c)).collect();
+        let (a, b, c, d, e, f) = (ids[0], ids[1], ids[2], ids[3], ids[4], ids[5]);
+        graph
+            .add_edge(a, b, 4, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(a, c, 2, GraphEdgeDirection::Simplex)
+            .unwrap();
+        let negative_edge = graph
+            .add_edge(b, c, -3, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(c, d, 2, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph.add_edge(d, e, 1, GraphEdgeDirection::Duplex).unwrap();
+        // without negative values shortest_path() is equal to dijkstra()
+        let mut positive = graph.clone();
+        *positive.get_edge_value_mut_by_id(negative_edge).unwrap() = 3;
+        assert_eq!(positive.shortest_path(a, e), Ok(positive.dijkstra(a, e)));
+
+        assert!(!graph.detect_negative_cycle());
+        assert_eq!(graph.shortest_path(a, d), Ok(Some((vec![a, b, c, d], 3))));
+        assert_eq!(graph.shortest_path(d, a), Ok(None));
+        assert_eq!(graph.shortest_path(a, a), Ok(Some((vec![a], 0))));
+        let costs = graph.bellman_ford(a).unwrap();
+        assert_eq!(costs.len(), 5);
+        assert_eq!(costs[&c], 1);
+        assert_eq!(costs[&e], 4);
+        assert!(graph.bellman_ford(42).unwrap().is_empty());
+
+        // cycle c -> d -> b -> c with sum -1
+        graph
+            .add_edge(d, b, 0, GraphEdgeDirection::Simplex)
+            .unwrap();
+        assert!(graph.detect_negative_cycle());
+        assert_eq!(graph.shortest_path(a, e), Err(NegativeCycleError));
+        assert!(graph.bellman_ford(a).is_err());
+        // cycle is not reachable from isolated node f
+        assert_eq!(graph.shortest_path(f, a), Ok(None));
+        assert_eq!(graph.bellman_ford(f).unwrap().len(), 1);
+
+        // Duplex edge with negative value is a negative cycle
+        let mut graph: Graph<char, i32> = Graph::new(2, 1, true);
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        graph
+            .add_edge(a, b, -1, GraphEdgeDirection::Duplex)
+            .unwrap();
+        assert!(graph.detect_negative_cycle());
+        assert_eq!(graph.shortest_path(b, a), Err(NegativeCycleError));
+    }
+
+    #[test]
+    fn test_subgraph() {
+        let mut graph: Graph<u32, u32> = Graph::new(5, 6, true);
+        let ids: Vec<usize> = (1..=5).map(|i| graph.add_node(i)).collect();
+        graph
+            .add_edge(ids[0], ids[1], 1, GraphEdgeDirection::Duplex)
+            .unwrap();
+        graph
+            .add_edge(ids[1], ids[2], 2, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(ids[2], ids[3], 3, GraphEdgeDirection::Duplex)
+            .unwrap();
+        let edge_3_5 = graph
+            .add_edge(ids[2], ids[4], 4, GraphEdgeDirection::Duplex)
+            .unwrap();
+        graph
+            .add_edge(ids[3], ids[0], 5, GraphEdgeDirection::Simplex)
+            .unwrap();
+
+        let subgraph = graph.subgraph(&[ids[1], ids[2], ids[4]]);
+        let node_ids: Vec<usize> = subgraph.iter_nodes().map(|n| n.id).collect();
+        assert_eq!(node_ids, vec![ids[1], ids[2], ids[4]]);
+        assert_eq!(subgraph.get_node_by_id(ids[4]).unwrap().item, 5);
+        assert_eq!(subgraph.iter_edges().count(), 2);
+        assert_eq!(subgraph.get_edge_by_id(edge_3_5).unwrap().value, 4);
+        assert!(subgraph.get_node_by_id(ids[0]).is_err());
+
+        // odd items: 1, 3, 5
+        let mut odd = graph.induced_subgraph(|n| n.item % 2 == 1);
+        assert_eq!(odd.iter_nodes().count(), 3);
+        assert_eq!(odd.iter_edges().count(), 1);
+        // force_unambiguous is kept and new ids do not collide with ids of original graph
+        assert_eq!(odd.add_node(3), ids[2]);
+        assert_eq!(odd.add_node(6), 5);
+
+        let complement = graph.complement();
+        assert_eq!(complement.iter_nodes().count(), 5);
+        // 10 pairs of nodes, 5 of them connected in graph
+        assert_eq!(complement.iter_edges().count(), 5);
+        assert!(complement
+            .get_edge(ids[0], ids[2], GraphEdgeDirection::Duplex)
+            .is_ok());
+        assert!(complement
+            .get_edge(ids[0], ids[3], GraphEdgeDirection::Duplex)
+            .is_err());
+        assert!(complement
+            .iter_edges()
+            .all(|(e, ..)| e.value == 0 && e.direction == GraphEdgeDirection::Duplex));
+    }
+
+    #[test]
+    fn test_bipartite_coloring() {
+        // complete bipartite graph K3,3
+        let mut graph: Graph<u32, u32> = Graph::new(6, 9, true);
+        let left: Vec<usize> = (0..3).map(|i| graph.add_node(i)).collect();
+        let right: Vec<usize> = (3..6).map(|i| graph.add_node(i)).collect();
+        for l in left.iter() {
+            for r in right.iter() {
+                graph
+                    .add_edge(*l, *r, 1, GraphEdgeDirection::Duplex)
+                    .unwrap();
+            }
+        }
+        assert!(graph.is_bipartite());
+        let coloring = graph.bipartite_coloring().unwrap();
+        assert_eq!(coloring.len(), 6);
+        assert!(left.iter().all(|l| coloring[l] == coloring[&left[0]]));
+        assert!(right.iter().all(|r| coloring[r] != coloring[&left[0]]));
+
+        // Simplex edges are ignored
+        graph
+            .add_edge(left[0], left[1], 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        assert!(graph.is_bipartite());
+        // isolated node gets a color
+        let isolated = graph.add_node(6);
+        assert!(graph.bipartite_coloring().unwrap().contains_key(&isolated));
+        graph
+            .add_edge(left[0], left[1], 1, GraphEdgeDirection::Duplex)
+            .unwrap();
+        assert!(!graph.is_bipartite());
+
+        // triangle K3
+        let mut graph: Graph<u32, u32> = Graph::new(3, 3, true);
+        let ids: Vec<usize> = (0..3).map(|i| graph.add_node(i)).collect();
+        for (start, end) in [(0, 1), (1, 2), (2, 0)] {
+            graph
+                .add_edge(ids[start], ids[end], 1, GraphEdgeDirection::Duplex)
+                .unwrap();
+        }
+        assert!(!graph.is_bipartite());
+        assert_eq!(graph.bipartite_coloring(), None);
+    }
+
+    #[test]
+    fn test_k_hop_neighborhood() {
+        // chain 0 - 1 - 2 - 3 with shortcut 0 -> 2 and Simplex edge 3 -> 4
+        let mut graph: Graph<u32, u32> = Graph::new(6, 5, true);
+        let ids: Vec<usize> = (0..6).map(|i| graph.add_node(i)).collect();
+        for (start, end) in [(0, 1), (1, 2), (2, 3)] {
+            graph
+                .add_edge(ids[start], ids[end], 1, GraphEdgeDirection::Duplex)
+                .unwrap();
+        }
+        graph
+            .add_edge(ids[0], ids[2], 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(ids[3], ids[4], 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+
+        assert!(graph.k_hop_neighborhood(ids[0], 0).is_empty());
+        assert_eq!(
+            graph.k_hop_neighborhood(ids[0], 1),
+            HashSet::from([ids[1], ids[2]])
+        );
+        let distances = graph.k_hop_neighborhood_with_distances(ids[0], 3);
+        assert_eq!(distances.len(), 4);
+        assert_eq!(distances[&ids[2]], 1);
+        assert_eq!(distances[&ids[3]], 2);
+        assert_eq!(distances[&ids[4]], 3);
+        // isolated node 5 is never reached
+        assert_eq!(graph.k_hop_neighborhood(ids[0], 10).len(), 4);
+        // Simplex edges cannot be traversed backwards
+        assert!(graph.k_hop_neighborhood(ids[4], 10).is_empty());
+        assert_eq!(
+            graph.k_hop_neighborhood(ids[2], 1),
+            HashSet::from([ids[1], ids[3]])
+        );
+        assert!(graph.k_hop_neighborhood(42, 1).is_empty());
+    }
+
+    #[test]
+    fn test_pagerank() {
+        // cycle of Duplex edges: all nodes are equally important
+        let mut graph: Graph<u32, u32> = Graph::new(3, 3, true);
+        let ids: Vec<usize> = (0..3).map(|i| graph.add_node(i)).collect();
+        for (start, end) in [(0, 1), (1, 2), (2, 0)] {
+            graph
+                .add_edge(ids[start], ids[end], 1, GraphEdgeDirection::Duplex)
+                .unwrap();
+        }
+        let scores = graph.pagerank(0.85, 100, 1e-10);
+        assert!(scores.values().all(|s| (s - 1.0 / 3.0).abs() < 1e-9));
+
+        // all spokes link to hub, hub links to spoke 1
+        let mut graph: Graph<u32, u32> = Graph::new(5, 5, true);
+        let hub = graph.add_node(0);
+        let spokes: Vec<usize> = (1..5).map(|i| graph.add_node(i)).collect();
+        for spoke in spokes.iter() {
+            graph
+                .add_edge(*spoke, hub, 1, GraphEdgeDirection::Simplex)
+                .unwrap();
+        }
+        graph
+            .add_edge(hub, spokes[0], 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        let scores = graph.pagerank(0.85, 100, 1e-10);
+        assert_eq!(scores.len(), 5);
+        assert!((scores.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(spokes.iter().all(|s| scores[&hub] > scores[s]));
+        assert!(scores[&spokes[0]] > scores[&spokes[1]]);
+        assert!((scores[&spokes[1]] - scores[&spokes[2]]).abs() < 1e-9);
+        // without links scores are uniform
+        let scores = graph.pagerank(0.0, 100, 1e-10);
+        assert!(scores.values().all(|s| (s - 0.2).abs() < 1e-9));
+
+        // node without outgoing links distributes its score to all nodes
+        let mut graph: Graph<u32, u32> = Graph::new(2, 1, true);
+        let a = graph.add_node(0);
+        let b = graph.add_node(1);
+        graph
+            .add_edge(a, b, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        let scores = graph.pagerank(0.85, 100, 1e-10);
+        assert!((scores[&a] + scores[&b] - 1.0).abs() < 1e-9);
+        assert!(scores[&b] > scores[&a]);
+        assert!(Graph::<u32, u32>::new(0, 0, true)
+            .pagerank(0.85, 10, 1e-6)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut graph: Graph<&str, u32> = Graph::new(6, 7, true);
+        let shirt = graph.add_node("shirt");
+        let tie = graph.add_node("tie");
+        let jacket = graph.add_node("jacket");
+        let trousers = graph.add_node("trousers");
+        let shoes = graph.add_node("shoes");
+        let socks = graph.add_node("socks");
+        graph
+            .add_edge(shirt, tie, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(tie, jacket, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(trousers, shoes, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(socks, shoes, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(trousers, jacket, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        // Duplex edges do not define an order
+        graph
+            .add_edge(shoes, shirt, 1, GraphEdgeDirection::Duplex)
+            .unwrap();
+        assert!(!graph.has_cycle());
+        let sorted = graph.topological_sort().unwrap();
+        assert_eq!(sorted, vec![shirt, trousers, socks, tie, shoes, jacket]);
+        for edge in graph.iter_simplex_edges() {
+            let start = sorted.iter().position(|id| *id == edge.start).unwrap();
+            let end = sorted.iter().position(|id| *id == edge.end).unwrap();
+            assert!(start < end);
+        }
+
+        // cycle shirt -> tie -> jacket -> shirt; socks and shoes are only reachable from cycle
+        graph
+            .add_edge(jacket, shirt, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(jacket, socks, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        assert!(graph.has_cycle());
+        assert_eq!(graph.topological_sort(), Err(vec![shirt, tie, jacket]));
+
+        let mut two_nodes: Graph<u8, u32> = Graph::new(2, 2, true);
+        let first = two_nodes.add_node(0);
+        let second = two_nodes.add_node(1);
+        two_nodes
+            .add_edge(first, second, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        assert!(!two_nodes.has_cycle());
+        two_nodes
+            .add_edge(second, first, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        assert!(two_nodes.has_cycle());
+        assert_eq!(two_nodes.topological_sort(), Err(vec![first, second]));
+    }
+
+    #[test]
+    fn test_spanning_tree() {
+        // complete graph K5 with edge value i + j between node i and node j
+        // minimum spanning tree connects all nodes to node 0: 1 + 2 + 3 + 4 = 10
+        // maximum spanning tree connects all nodes to node 4: 4 + 5 + 6 + 7 = 22
+        let mut graph: Graph<u32, u32> = Graph::new(5, 10, true);
+        let ids: Vec<usize> = (0..5).map(|i| graph.add_node(i)).collect();
+        for i in 0..5 {
+            for j in i + 1..5 {
+                graph
+                    .add_edge(ids[i], ids[j], (i + j) as u32, GraphEdgeDirection::Duplex)
+                    .unwrap();
+            }
+        }
+        // Simplex edges are ignored
+        graph
+            .add_edge(ids[1], ids[2], 0, GraphEdgeDirection::Simplex)
+            .unwrap();
+
+        let minimum = graph.minimum_spanning_tree();
+        assert_eq!(minimum.iter_nodes().count(), 5);
+        assert_eq!(minimum.iter_edges().count(), 4);
+        assert_eq!(
+            minimum.iter_edges().map(|(e, _, _)| e.value).sum::<u32>(),
+            10
+        );
+        assert!(minimum
+            .iter_edges()
+            .all(|(e, _, _)| e.start == ids[0] && e.direction == GraphEdgeDirection::Duplex));
+
+        let maximum = graph.maximum_spanning_tree();
+        assert_eq!(maximum.iter_nodes().count(), 5);
+        assert_eq!(maximum.iter_edges().count(), 4);
+        assert_eq!(
+            maximum.iter_edges().map(|(e, _, _)| e.value).sum::<u32>(),
+            22
+        );
+        assert!(maximum.iter_edges().all(|(e, _, _)| e.end == ids[4]));
+
+        // not connected graph results in spanning forest
+        let single = graph.add_node(5);
+        let forest = graph.minimum_spanning_tree();
+        assert_eq!(forest.iter_edges().count(), 4);
+        assert!(forest.get_node_by_id(single).is_ok());
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        // a <-> b -> c -> d -> c, d -> e
+        let mut graph: Graph<char, u32> = Graph::new(5, 6, true);
+        let ids: Vec<usize> = "abcde".chars().map(|c| graph.add_node(c)).collect();
+        let (a, b, c, d, e) = (ids[0], ids[1], ids[2], ids[3], ids[4]);
+        graph.add_edge(a, b, 1, GraphEdgeDirection::Duplex).unwrap();
+        graph
+            .add_edge(b, c, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(c, d, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(d, c, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(d, e, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec![e], vec![c, d], vec![a, b]]
+        );
+        assert!(!graph.is_strongly_connected());
+
+        // e -> a closes the loop
+        graph
+            .add_edge(e, a, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        assert_eq!(graph.strongly_connected_components(), vec![ids.clone()]);
+        assert!(graph.is_strongly_connected());
+
+        // components of condensed graph are in reverse topological order
+        let mut chain: Graph<usize, u32> = Graph::new(100, 99, true);
+        let ids: Vec<usize> = (0..100).map(|i| chain.add_node(i)).collect();
+        for window in ids.windows(2) {
+            chain
+                .add_edge(window[0], window[1], 1, GraphEdgeDirection::Simplex)
+                .unwrap();
+        }
+        let components = chain.strongly_connected_components();
+        assert_eq!(components.len(), 100);
+        assert!(components
+            .iter()
+            .zip(ids.iter().rev())
+            .all(|(component, id)| component == &vec![*id]));
+    }
+
+    #[test]
+    fn test_articulation_points_and_bridges() {
+        // triangle a-b-c, bridge c-d, triangle d-e-f, bridge f-g
+        // double edge g-h is no bridge, Simplex edge h-a is ignored
+        let mut graph: Graph<char, u32> = Graph::new(8, 10, true);
+        let ids: Vec<usize> = "abcdefgh".chars().map(|c| graph.add_node(c)).collect();
+        let (a, b, c, d, e, f, g, h) = (
+            ids[0], ids[1], ids[2], ids[3], ids[4], ids[5], ids[6], ids[7],
+        );
+        graph.add_edge(a, b, 1, GraphEdgeDirection::Duplex).unwrap();
+        graph.add_edge(b, c, 1, GraphEdgeDirection::Duplex).unwrap();
+        graph.add_edge(c, a, 1, GraphEdgeDirection::Duplex).unwrap();
+        let c_d = graph.add_edge(c, d, 1, GraphEdgeDirection::Duplex).unwrap();
+        graph.add_edge(d, e, 1, GraphEdgeDirection::Duplex).unwrap();
+        graph.add_edge(e, f, 1, GraphEdgeDirection::Duplex).unwrap();
+        graph.add_edge(f, d, 1, GraphEdgeDirection::Duplex).unwrap();
+        let f_g = graph.add_edge(f, g, 1, GraphEdgeDirection::Duplex).unwrap();
+        graph.add_edge(g, h, 1, GraphEdgeDirection::Duplex).unwrap();
+        graph.add_edge(h, g, 1, GraphEdgeDirection::Duplex).unwrap();
+        graph
+            .add_edge(h, a, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        assert_eq!(graph.articulation_points(), vec![c, d, f, g]);
+        assert_eq!(graph.bridges(), vec![c_d, f_g]);
+
+        // root of depth first search with two subtrees is articulation point
+        let mut star: Graph<u8, u32> = Graph::new(4, 3, true);
+        let center = star.add_node(0);
+        for i in 1..4 {
+            let leaf = star.add_node(i);
+            star.add_edge(center, leaf, 1, GraphEdgeDirection::Duplex)
+                .unwrap();
+        }
+        assert_eq!(star.articulation_points(), vec![center]);
+        assert_eq!(star.bridges().len(), 3);
+
+        // cycle has neither articulation points nor bridges
+        let mut cycle: Graph<u8, u32> = Graph::new(4, 4, true);
+        let ids: Vec<usize> = (0..4).map(|i| cycle.add_node(i)).collect();
+        for i in 0..4 {
+            cycle
+                .add_edge(ids[i], ids[(i + 1) % 4], 1, GraphEdgeDirection::Duplex)
+                .unwrap();
+        }
+        assert!(cycle.articulation_points().is_empty());
+        assert!(cycle.bridges().is_empty());
+    }
+
+    #[test]
+    fn test_all_pairs_shortest_paths() {
+        let mut graph: Graph<char, u32> = Graph::new(4, 5, true);
+        let ids: Vec<usize> = "abcd".chars().map(|c| graph.add_node(c)).collect();
+        graph
+            .add_edge(ids[0], ids[1], 5, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(ids[0], ids[2], 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(ids[2], ids[1], 2, GraphEdgeDirection::Duplex)
+            .unwrap();
+        graph
+            .add_edge(ids[1], ids[3], 4, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(ids[1], ids[3], 3, GraphEdgeDirection::Simplex)
+            .unwrap();
+        let (costs, predecessors) = graph.all_pairs_shortest_paths_with_paths();
+        assert_eq!(
+            costs,
+            vec![
+                vec![Some(0), Some(3), Some(1), Some(6)],
+                vec![None, Some(0), Some(2), Some(3)],
+                vec![None, Some(2), Some(0), Some(5)],
+                vec![None, None, None, Some(0)],
+            ]
+        );
+        assert_eq!(costs, graph.all_pairs_shortest_paths());
+        // path a -> d: a -> c -> b -> d
+        let mut path = vec![3];
+        while let Some(predecessor) = predecessors[0][*path.last().unwrap()] {
+            path.push(predecessor);
+        }
+        path.reverse();
+        assert_eq!(path, vec![0, 2, 1, 3]);
+        assert_eq!(predecessors[3][0], None);
+
+        // all pairs shortest paths match dijkstra
+        for (start, start_costs) in costs.iter().enumerate() {
+            let dijkstra_costs = graph.dijkstra_all_from(ids[start]);
+            for (end, cost) in start_costs.iter().enumerate() {
+                assert_eq!(cost.as_ref(), dijkstra_costs.get(&ids[end]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_degree() {
+        let mut graph: Graph<char, u32> = Graph::new(4, 5, true);
+        let ids: Vec<usize> = "abcd".chars().map(|c| graph.add_node(c)).collect();
+        let (a, b, c, d) = (ids[0], ids[1], ids[2], ids[3]);
+        graph
+            .add_edge(a, b, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(a, c, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph
+            .add_edge(c, b, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        graph.add_edge(b, d, 1, GraphEdgeDirection::Duplex).unwrap();
+        graph.add_edge(d, d, 1, GraphEdgeDirection::Duplex).unwrap();
+        assert_eq!(graph.in_degree(a), 0);
+        assert_eq!(graph.out_degree(a), 2);
+        assert_eq!(graph.degree(a), 2);
+        assert_eq!(graph.in_degree(b), 3);
+        assert_eq!(graph.out_degree(b), 1);
+        assert_eq!(graph.degree(b), 3);
+        assert_eq!(graph.in_degree(d), 2);
+        assert_eq!(graph.out_degree(d), 2);
+        assert_eq!(graph.degree(d), 3);
+        assert_eq!(graph.degree(42), 0);
+        assert_eq!(
+            graph.degree_sequence(),
+            vec![(0, 2), (3, 1), (1, 1), (2, 2)]
+        );
+        let distribution = graph.degree_distribution();
+        assert_eq!(distribution.len(), 2);
+        assert_eq!(distribution[&2], 2);
+        assert_eq!(distribution[&3], 2);
+
+        // removed node is not part of degree sequence
+        graph.remove_node_by_id(a).unwrap();
+        let e = graph.add_node('e');
+        graph
+            .add_edge(e, c, 1, GraphEdgeDirection::Simplex)
+            .unwrap();
+        assert_eq!(
+            graph.degree_sequence(),
+            vec![(2, 1), (1, 1), (2, 2), (0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_serialize_to_dot() {
+        let mut graph: Graph<&str, u32> = Graph::new(3, 2, true);
+        let a = graph.add_node("a");
+        let b = graph.add_node("b \"quoted\"");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b, 3, GraphEdgeDirection::Duplex).unwrap();
+        graph.add_edge(b, c, 5, GraphEdgeDirection::Duplex).unwrap();
+        assert_eq!(
+            graph.serialize_to_dot_default(),
+            "graph {\n    \
+             0 [label=\"a\"];\n    \
+             1 [label=\"b \\\"quoted\\\"\"];\n    \
+             2 [label=\"c\"];\n    \
+             0 -- 1 [label=\"3\"];\n    \
+             1 -- 2 [label=\"5\"];\n\
+             }\n"
+        );
+
+        graph
+            .add_edge(c, a, 7, GraphEdgeDirection::Simplex)
+            .unwrap();
+        assert_eq!(
+            graph.serialize_to_dot(|n| format!("node {}", n.id), |e| format!("edge {}", e.id)),
+            "digraph {\n    \
+             0 [label=\"node 0\"];\n    \
+             1 [label=\"node 1\"];\n    \
+             2 [label=\"node 2\"];\n    \
+             0 -> 1 [label=\"edge 0\", dir=both];\n    \
+             1 -> 2 [label=\"edge 1\", dir=both];\n    \
+             2 -> 0 [label=\"edge 2\"];\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_flow_network() {
+        let mut network: FlowNetwork<char> = FlowNetwork::new(6, 9);
+        let ids: Vec<usize> = "stabcd".chars().map(|c| network.add_node(c)).collect();
+        let (s, t, a, b, c, d) = (ids[0], ids[1], ids[2], ids[3], ids[4], ids[5]);
+        network.add_directed_edge(s, a, 10).unwrap();
+        network.add_directed_edge(s, c, 10).unwrap();
+        network.add_directed_edge(a, b, 4).unwrap();
+        network.add_directed_edge(a, c, 2).unwrap();
+        network.add_directed_edge(a, d, 8).unwrap();
+        network.add_directed_edge(c, d, 9).unwrap();
+        let b_t = network.add_directed_edge(b, t, 10).unwrap();
+        network.add_directed_edge(d, b, 6).unwrap();
+        let d_t = network.add_directed_edge(d, t, 10).unwrap();
+        assert!(network.add_directed_edge(a, a, 1).is_err());
+        assert!(network.add_directed_edge(a, b, -1).is_err());
+
+        assert_eq!(network.max_flow(s, t), 19);
+        assert_eq!(
+            network.get_flow(b_t).unwrap() + network.get_flow(d_t).unwrap(),
+            19
+        );
+        // max flow can be calculated multiple times
+        assert_eq!(network.max_flow(s, t), 19);
+        assert_eq!(network.max_flow(t, s), 0);
+        assert_eq!(network.max_flow(s, s), 0);
+
+        let cut = network.min_cut(s, t);
+        let cut_capacity: i64 = cut.iter().map(|id| network.get_flow(*id).unwrap()).sum();
+        assert_eq!(cut_capacity, 19);
+        // all edges of min cut are saturated
+        assert!(cut
+            .iter()
+            .all(|id| network.graph().get_edge_by_id(*id).unwrap().value == 0));
+    }
 }
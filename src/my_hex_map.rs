@@ -110,6 +110,101 @@ impl<'a, T: Copy + Clone + Default, const N: usize> Iterator for HexDistanceIter
     }
 }
 
+// HexMap: hexagonal grid with radius R around center hex (0, 0), using axial coordinates (q, r)
+// hex (q, r) is part of map, if |q| <= R && |r| <= R && |q + r| <= R -> map has 3 * R² + 3 * R + 1 hexes
+// orientation of hex is pointy-top: q increases to the right, r increases down right
+// since number of hexes cannot be computed from const R on stable rust, hexes are stored row by row in Vec
+#[derive(Clone, PartialEq, Debug)]
+pub struct HexMap<T, const R: usize> {
+    items: Vec<T>,
+}
+
+impl<T: Copy + Clone + Default, const R: usize> Default for HexMap<T, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Clone + Default, const R: usize> HexMap<T, R> {
+    const RADIUS: i32 = R as i32;
+    pub fn new() -> Self {
+        Self::init(T::default())
+    }
+    pub fn init(init_element: T) -> Self {
+        Self {
+            items: vec![init_element; 3 * R * R + 3 * R + 1],
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    pub fn is_in_map(q: i32, r: i32) -> bool {
+        q.abs() <= Self::RADIUS && r.abs() <= Self::RADIUS && (q + r).abs() <= Self::RADIUS
+    }
+    fn q_range(r: i32) -> std::ops::RangeInclusive<i32> {
+        // range of q in row r
+        (-Self::RADIUS).max(-r - Self::RADIUS)..=Self::RADIUS.min(-r + Self::RADIUS)
+    }
+    fn index(q: i32, r: i32) -> Option<usize> {
+        if !Self::is_in_map(q, r) {
+            return None;
+        }
+        // row k = r + R has length 2 * R + 1 - |k - R|; offset is sum of length of all previous rows
+        let k = (r + Self::RADIUS) as usize;
+        let row_offset = if k <= R {
+            k * (2 * R + 1 + k) / 2
+        } else {
+            let m = k - R;
+            R * (3 * R + 1) / 2 + m * (2 * R + 1) - m * (m - 1) / 2
+        };
+        Some(row_offset + (q - Self::q_range(r).start()) as usize)
+    }
+    pub fn get(&self, q: i32, r: i32) -> Option<&T> {
+        Self::index(q, r).map(|index| &self.items[index])
+    }
+    pub fn get_mut(&mut self, q: i32, r: i32) -> Option<&mut T> {
+        Self::index(q, r).map(|index| &mut self.items[index])
+    }
+    pub fn set(&mut self, q: i32, r: i32, value: T) {
+        match Self::index(q, r) {
+            Some(index) => self.items[index] = value,
+            None => panic!("line {}, hex ({}, {}) is not in map", line!(), q, r),
+        }
+    }
+    pub fn hex_distance(a: (i32, i32), b: (i32, i32)) -> usize {
+        // axial coordinates are cube coordinates (q, r, s) with s = -q - r
+        let dq = a.0 - b.0;
+        let dr = a.1 - b.1;
+        ((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as usize
+    }
+    pub fn hex_neighbors(q: i32, r: i32) -> [(i32, i32); 6] {
+        // same order as HexNeigh: starting on the right side counterclockwise around hex
+        // neighbors may be outside of map
+        [
+            (q + 1, r),
+            (q + 1, r - 1),
+            (q, r - 1),
+            (q - 1, r),
+            (q - 1, r + 1),
+            (q, r + 1),
+        ]
+    }
+    pub fn iter_neighbors(&self, q: i32, r: i32) -> impl Iterator<Item = ((i32, i32), &T)> {
+        Self::hex_neighbors(q, r)
+            .into_iter()
+            .filter_map(move |(nq, nr)| self.get(nq, nr).map(|v| ((nq, nr), v)))
+    }
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        // row by row from r = -R to r = R, each row with increasing q
+        (-Self::RADIUS..=Self::RADIUS)
+            .flat_map(|r| Self::q_range(r).map(move |q| (q, r)))
+            .zip(self.items.iter())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,4 +287,44 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_axial_hex_map() {
+        let mut hex_map: HexMap<u8, 3> = HexMap::new();
+        assert_eq!(hex_map.len(), 37);
+        assert_eq!(HexMap::<u8, 0>::new().len(), 1);
+        // index of hexes is unique and iter() visits hexes in order of storage
+        for (index, ((q, r), _)) in hex_map.iter().enumerate() {
+            assert_eq!(HexMap::<u8, 3>::index(q, r), Some(index));
+        }
+        assert_eq!(hex_map.iter().count(), 37);
+        assert!(hex_map.get(3, 1).is_none());
+        assert!(hex_map.get(-3, 4).is_none());
+        assert!(hex_map.get(-3, 3).is_some());
+
+        hex_map.set(2, -1, 5);
+        assert_eq!(hex_map.get(2, -1), Some(&5));
+        *hex_map.get_mut(0, 0).unwrap() = 1;
+        assert_eq!(hex_map.iter().filter(|(_, v)| **v > 0).count(), 2);
+
+        assert_eq!(HexMap::<u8, 3>::hex_distance((0, 0), (0, 0)), 0);
+        assert_eq!(HexMap::<u8, 3>::hex_distance((0, 0), (2, -1)), 2);
+        assert_eq!(HexMap::<u8, 3>::hex_distance((-3, 0), (3, -3)), 6);
+        assert_eq!(HexMap::<u8, 3>::hex_distance((3, -3), (-3, 3)), 6);
+        let neighbors = HexMap::<u8, 3>::hex_neighbors(0, 0);
+        assert!(neighbors
+            .iter()
+            .all(|n| HexMap::<u8, 3>::hex_distance((0, 0), *n) == 1));
+        assert_eq!(hex_map.iter_neighbors(0, 0).count(), 6);
+        // corner of map has 3 neighbors in map
+        assert_eq!(hex_map.iter_neighbors(3, 0).count(), 3);
+        assert_eq!(hex_map.iter_neighbors(3, -1).count(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_axial_hex_map_set_out_of_map() {
+        let mut hex_map: HexMap<u8, 1> = HexMap::new();
+        hex_map.set(1, 1, 1);
+    }
 }
@@ -33,10 +33,10 @@ impl<T: Copy + Clone + Default, const X: usize, const Y: usize, const N: usize>
     MyMap1D<T, X, Y, N>
 {
     fn coordinates_to_index(coordinates: MapPoint<X, Y>) -> usize {
-        coordinates.x() + coordinates.y() * X
+        coordinates.to_index()
     }
     fn index_to_coordinates(index: usize) -> MapPoint<X, Y> {
-        MapPoint::new(index % X, index / X)
+        MapPoint::from_index(index)
     }
     pub fn new() -> Self {
         if X == 0 {
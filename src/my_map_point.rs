@@ -50,6 +50,7 @@ impl<const X: usize, const Y: usize> MapPoint<X, Y> {
     pub const NE: MapPoint<X, Y> = MapPoint { x: X - 1, y: 0 };
     pub const SW: MapPoint<X, Y> = MapPoint { x: 0, y: Y - 1 };
     pub const SE: MapPoint<X, Y> = MapPoint { x: X - 1, y: Y - 1 };
+    pub const MAP_SIZE: usize = X * Y;
     pub const fn new_const(x: usize, y: usize) -> MapPoint<X, Y> {
         MapPoint { x, y }
     }
@@ -66,6 +67,33 @@ impl<const X: usize, const Y: usize> MapPoint<X, Y> {
         }
         result
     }
+    pub fn try_from_signed(x: i32, y: i32) -> Option<MapPoint<X, Y>> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        let result = MapPoint {
+            x: x as usize,
+            y: y as usize,
+        };
+        if result.is_in_map() {
+            Some(result)
+        } else {
+            None
+        }
+    }
+    pub fn to_index(&self) -> usize {
+        // row-major index
+        self.y * X + self.x
+    }
+    pub fn from_index(index: usize) -> Self {
+        if index >= Self::MAP_SIZE {
+            panic!("line {}, index is out of range", line!());
+        }
+        MapPoint {
+            x: index % X,
+            y: index / X,
+        }
+    }
     pub fn x(&self) -> usize {
         self.x
     }
@@ -115,17 +143,9 @@ impl<const X: usize, const Y: usize> MapPoint<X, Y> {
         }
     }
     pub fn available_cardinal_directions(&self) -> Vec<Compass> {
-        match self.map_position() {
-            Compass::Center => vec![Compass::N, Compass::E, Compass::S, Compass::W],
-            Compass::N => vec![Compass::E, Compass::S, Compass::W],
-            Compass::E => vec![Compass::N, Compass::S, Compass::W],
-            Compass::S => vec![Compass::N, Compass::E, Compass::W],
-            Compass::W => vec![Compass::N, Compass::E, Compass::S],
-            Compass::NE => vec![Compass::S, Compass::W],
-            Compass::SE => vec![Compass::N, Compass::W],
-            Compass::SW => vec![Compass::N, Compass::E],
-            Compass::NW => vec![Compass::E, Compass::S],
-        }
+        Compass::iter_cardinal_directions()
+            .filter(|c| self.neighbor(*c).is_some())
+            .collect()
     }
     pub fn forward_x(&self) -> Option<MapPoint<X, Y>> {
         // increments x, if x reaches row end, move to start of next row; if x reaches end of map, return None
@@ -181,24 +201,15 @@ impl<const X: usize, const Y: usize> MapPoint<X, Y> {
         }
         Some(result)
     }
-    pub fn offset_pp(&self, offset: (usize, usize)) -> Option<MapPoint<X, Y>> {
-        let result = MapPoint {
-            x: self.x + offset.0,
-            y: self.y + offset.1,
-        };
-        if result.is_in_map() {
-            Some(result)
-        } else {
-            None
-        }
-    }
-    pub fn offset_mm(&self, offset: (usize, usize)) -> Option<MapPoint<X, Y>> {
-        if offset.0 > self.x || offset.1 > self.y {
+    pub fn checked_offset(&self, dx: i32, dy: i32) -> Option<MapPoint<X, Y>> {
+        let x = self.x as i64 + dx as i64;
+        let y = self.y as i64 + dy as i64;
+        if x < 0 || y < 0 {
             return None;
         }
         let result = MapPoint {
-            x: self.x - offset.0,
-            y: self.y - offset.1,
+            x: x as usize,
+            y: y as usize,
         };
         if result.is_in_map() {
             Some(result)
@@ -224,17 +235,41 @@ impl<const X: usize, const Y: usize> MapPoint<X, Y> {
             y: Y - 1 - self.y,
         }
     }
+    pub fn rotate_90_cw(&self) -> MapPoint<Y, X> {
+        // position in map rotated clockwise by 90°; width and height of map are swapped
+        MapPoint {
+            x: Y - 1 - self.y,
+            y: self.x,
+        }
+    }
+    pub fn rotate_180(&self) -> MapPoint<X, Y> {
+        self.invert_x_and_y()
+    }
+    pub fn rotate_270_cw(&self) -> MapPoint<Y, X> {
+        // position in map rotated clockwise by 270°; width and height of map are swapped
+        MapPoint {
+            x: self.y,
+            y: X - 1 - self.x,
+        }
+    }
+    pub fn reflect_diagonal(&self) -> MapPoint<Y, X> {
+        // transpose: reflect at diagonal from NW to SE
+        MapPoint {
+            x: self.y,
+            y: self.x,
+        }
+    }
     pub fn neighbor(&self, orientation: Compass) -> Option<MapPoint<X, Y>> {
         match orientation {
             Compass::Center => Some(*self),
-            Compass::N => self.offset_mm((0, 1)),
-            Compass::NE => self.offset_mm((0, 1)).and_then(|n| n.offset_pp((1, 0))),
-            Compass::E => self.offset_pp((1, 0)),
-            Compass::SE => self.offset_pp((1, 1)),
-            Compass::S => self.offset_pp((0, 1)),
-            Compass::SW => self.offset_pp((0, 1)).and_then(|s| s.offset_mm((1, 0))),
-            Compass::W => self.offset_mm((1, 0)),
-            Compass::NW => self.offset_mm((1, 1)),
+            Compass::N => self.checked_offset(0, -1),
+            Compass::NE => self.checked_offset(1, -1),
+            Compass::E => self.checked_offset(1, 0),
+            Compass::SE => self.checked_offset(1, 1),
+            Compass::S => self.checked_offset(0, 1),
+            Compass::SW => self.checked_offset(-1, 1),
+            Compass::W => self.checked_offset(-1, 0),
+            Compass::NW => self.checked_offset(-1, -1),
         }
     }
     pub fn orientation_of_neighbor(&self, neighbor: MapPoint<X, Y>) -> Option<Compass> {
@@ -270,6 +305,38 @@ impl<const X: usize, const Y: usize> MapPoint<X, Y> {
     pub fn iter_edge(&self, counterclockwise: bool) -> impl Iterator<Item = MapPoint<X, Y>> {
         EdgeIter::new(*self, counterclockwise)
     }
+    pub fn iter_bresenham_line(
+        &self,
+        target: MapPoint<X, Y>,
+    ) -> impl Iterator<Item = MapPoint<X, Y>> {
+        BresenhamIter::new(*self, target)
+    }
+    pub fn iter_ring(&self, radius: usize) -> impl Iterator<Item = MapPoint<X, Y>> {
+        // all map points with manhatten distance radius, starting at north tip going clockwise
+        let center = *self;
+        let r = radius as i32;
+        // ring of radius 0 is center itself
+        let ring_len = (4 * r).max(1);
+        (0..ring_len).filter_map(move |i| {
+            let step = i % r.max(1);
+            let (dx, dy) = match i / r.max(1) {
+                // north -> east
+                0 => (step, step - r),
+                // east -> south
+                1 => (r - step, step),
+                // south -> west
+                2 => (-step, r - step),
+                // west -> north
+                _ => (step - r, -step),
+            };
+            center.checked_offset(dx, dy)
+        })
+    }
+    pub fn iter_disk(&self, radius: usize) -> impl Iterator<Item = MapPoint<X, Y>> {
+        // all map points with manhatten distance <= radius, ring by ring starting with self
+        let center = *self;
+        (0..=radius).flat_map(move |r| center.iter_ring(r))
+    }
 }
 
 struct NeighborIter<const X: usize, const Y: usize> {
@@ -519,6 +586,66 @@ impl<const X: usize, const Y: usize> Iterator for EdgeIter<X, Y> {
     }
 }
 
+struct BresenhamIter<const X: usize, const Y: usize> {
+    current_x: i64,
+    current_y: i64,
+    target: MapPoint<X, Y>,
+    // absolute delta of x and negative absolute delta of y
+    delta_x: i64,
+    delta_y: i64,
+    step_x: i64,
+    step_y: i64,
+    error: i64,
+    finished: bool,
+}
+
+impl<const X: usize, const Y: usize> BresenhamIter<X, Y> {
+    fn new(start_point: MapPoint<X, Y>, target: MapPoint<X, Y>) -> Self {
+        let delta_x = start_point.distance_x(target) as i64;
+        let delta_y = -(start_point.distance_y(target) as i64);
+        BresenhamIter {
+            current_x: start_point.x as i64,
+            current_y: start_point.y as i64,
+            target,
+            delta_x,
+            delta_y,
+            step_x: if start_point.x < target.x { 1 } else { -1 },
+            step_y: if start_point.y < target.y { 1 } else { -1 },
+            error: delta_x + delta_y,
+            finished: false,
+        }
+    }
+}
+
+impl<const X: usize, const Y: usize> Iterator for BresenhamIter<X, Y> {
+    type Item = MapPoint<X, Y>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        // all points of line are inside of rectangle between start and target, therefore in map
+        let result = MapPoint {
+            x: self.current_x as usize,
+            y: self.current_y as usize,
+        };
+        if result == self.target {
+            self.finished = true;
+        } else {
+            let double_error = 2 * self.error;
+            if double_error >= self.delta_y {
+                self.error += self.delta_y;
+                self.current_x += self.step_x;
+            }
+            if double_error <= self.delta_x {
+                self.error += self.delta_x;
+                self.current_y += self.step_y;
+            }
+        }
+        Some(result)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -600,6 +727,133 @@ mod tests {
         );
     }
 
+    #[test]
+    fn signed_offset_test() {
+        const X: usize = 20;
+        const Y: usize = 10;
+        assert_eq!(
+            MapPoint::<X, Y>::try_from_signed(19, 9),
+            Some(MapPoint::<X, Y>::SE)
+        );
+        assert_eq!(MapPoint::<X, Y>::try_from_signed(-1, 5), None);
+        assert_eq!(MapPoint::<X, Y>::try_from_signed(3, -2), None);
+        assert_eq!(MapPoint::<X, Y>::try_from_signed(20, 5), None);
+        assert_eq!(MapPoint::<X, Y>::try_from_signed(3, 10), None);
+        let point = MapPoint::<X, Y>::new(5, 5);
+        assert_eq!(point.checked_offset(-5, 4), Some(MapPoint::<X, Y>::SW));
+        assert_eq!(point.checked_offset(14, -5), Some(MapPoint::<X, Y>::NE));
+        assert_eq!(point.checked_offset(-6, 0), None);
+        assert_eq!(point.checked_offset(0, 5), None);
+        assert_eq!(point.checked_offset(i32::MIN, i32::MAX), None);
+    }
+
+    #[test]
+    fn bresenham_line_test() {
+        const X: usize = 20;
+        const Y: usize = 10;
+        let start = MapPoint::<X, Y>::new(1, 1);
+        let line: Vec<(usize, usize)> = start
+            .iter_bresenham_line(MapPoint::new(7, 4))
+            .map(|p| p.into())
+            .collect();
+        assert_eq!(
+            line,
+            vec![(1, 1), (2, 2), (3, 2), (4, 3), (5, 3), (6, 4), (7, 4)]
+        );
+        // reverse direction and steep line
+        let line: Vec<(usize, usize)> = MapPoint::<X, Y>::new(3, 9)
+            .iter_bresenham_line(MapPoint::new(1, 0))
+            .map(|p| p.into())
+            .collect();
+        assert_eq!(line.len(), 10);
+        assert_eq!(line[0], (3, 9));
+        assert_eq!(line[9], (1, 0));
+        assert!(line.windows(2).all(|w| w[0].1 == w[1].1 + 1));
+        // single point
+        assert_eq!(start.iter_bresenham_line(start).count(), 1);
+        // each point is within 0.5 of ideal line
+        let end = MapPoint::<X, Y>::SE;
+        for point in MapPoint::<X, Y>::NW.iter_bresenham_line(end) {
+            let ideal_y = point.x() as f64 * (Y - 1) as f64 / (X - 1) as f64;
+            assert!((point.y() as f64 - ideal_y).abs() <= 0.5);
+        }
+    }
+
+    #[test]
+    fn ring_and_disk_test() {
+        const X: usize = 20;
+        const Y: usize = 10;
+        let center = MapPoint::<X, Y>::new(5, 5);
+        assert_eq!(center.iter_ring(0).collect::<Vec<_>>(), vec![center]);
+        let ring: Vec<(usize, usize)> = center.iter_ring(1).map(|p| p.into()).collect();
+        assert_eq!(ring, vec![(5, 4), (6, 5), (5, 6), (4, 5)]);
+        let ring: Vec<MapPoint<X, Y>> = center.iter_ring(3).collect();
+        assert_eq!(ring.len(), 12);
+        assert_eq!(ring[0], MapPoint::new(5, 2));
+        assert_eq!(ring[1], MapPoint::new(6, 3));
+        assert!(ring.iter().all(|p| center.distance(*p) == 3));
+        // ring partly outside of map
+        let corner = MapPoint::<X, Y>::NW;
+        let ring: Vec<(usize, usize)> = corner.iter_ring(2).map(|p| p.into()).collect();
+        assert_eq!(ring, vec![(2, 0), (1, 1), (0, 2)]);
+        let disk: Vec<MapPoint<X, Y>> = center.iter_disk(3).collect();
+        assert_eq!(disk.len(), 2 * 3 * (3 + 1) + 1);
+        assert!(disk
+            .windows(2)
+            .all(|w| center.distance(w[0]) <= center.distance(w[1])));
+        assert_eq!(corner.iter_disk(2).count(), 6);
+    }
+
+    #[test]
+    fn index_test() {
+        const X: usize = 20;
+        const Y: usize = 10;
+        assert_eq!(MapPoint::<X, Y>::MAP_SIZE, 200);
+        assert_eq!(MapPoint::<X, Y>::NW.to_index(), 0);
+        assert_eq!(MapPoint::<X, Y>::NE.to_index(), 19);
+        assert_eq!(MapPoint::<X, Y>::new(3, 2).to_index(), 43);
+        assert_eq!(MapPoint::<X, Y>::SE.to_index(), 199);
+        let mut point = Some(MapPoint::<X, Y>::NW);
+        let mut index = 0;
+        while let Some(p) = point {
+            assert_eq!(p.to_index(), index);
+            assert_eq!(MapPoint::<X, Y>::from_index(index), p);
+            point = p.forward_x();
+            index += 1;
+        }
+        assert_eq!(index, MapPoint::<X, Y>::MAP_SIZE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_range_test() {
+        MapPoint::<20, 10>::from_index(200);
+    }
+
+    #[test]
+    fn rotation_test() {
+        const X: usize = 4;
+        const Y: usize = 3;
+        let point = MapPoint::<X, Y>::new(1, 0);
+        let rotated: MapPoint<Y, X> = point.rotate_90_cw();
+        assert_eq!(rotated, MapPoint::<Y, X>::new(2, 1));
+        assert_eq!(point.rotate_180(), MapPoint::<X, Y>::new(2, 2));
+        assert_eq!(point.rotate_270_cw(), MapPoint::<Y, X>::new(0, 2));
+        assert_eq!(point.reflect_diagonal(), MapPoint::<Y, X>::new(0, 1));
+        // corners rotate to corners
+        assert_eq!(MapPoint::<X, Y>::NW.rotate_90_cw(), MapPoint::<Y, X>::NE);
+        assert_eq!(MapPoint::<X, Y>::NE.rotate_90_cw(), MapPoint::<Y, X>::SE);
+        let mut p = Some(MapPoint::<X, Y>::NW);
+        while let Some(point) = p {
+            assert!(point.rotate_90_cw().is_in_map());
+            assert_eq!(point.rotate_90_cw().rotate_90_cw(), point.rotate_180());
+            assert_eq!(point.rotate_90_cw().rotate_180(), point.rotate_270_cw());
+            assert_eq!(point.rotate_270_cw().rotate_90_cw(), point);
+            assert_eq!(point.reflect_diagonal().reflect_diagonal(), point);
+            p = point.forward_x();
+        }
+    }
+
     #[test]
     fn side_and_corner_test() {
         const X: usize = 20;
@@ -622,5 +876,19 @@ mod tests {
         assert!(a.map_position().is_cardinal());
         let a = MapPoint::<X, Y>::new(18, 8);
         assert!(a.map_position().is_center());
+        assert_eq!(
+            a.available_cardinal_directions(),
+            Compass::cardinals().to_vec()
+        );
+        let a = MapPoint::<X, Y>::new(19, 0);
+        assert_eq!(
+            a.available_cardinal_directions(),
+            vec![Compass::S, Compass::W]
+        );
+        let a = MapPoint::<X, Y>::new(0, 5);
+        assert_eq!(
+            a.available_cardinal_directions(),
+            vec![Compass::N, Compass::E, Compass::S]
+        );
     }
 }
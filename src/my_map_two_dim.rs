@@ -1,8 +1,11 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
 use std::fmt::Display;
 
 use crate::my_array::*;
 use crate::my_compass::*;
 use crate::my_map_point::*;
+use crate::my_union_find::UnionFind;
 
 /* use filter_fn as follows (use "_" for unused variables):
         let filter_fn = Box::new(|
@@ -27,6 +30,13 @@ pub type FilterFn<T, const X: usize, const Y: usize> =
 // let is_cell_free_fn = Box::new(|current_point: MapPoint<X, Y>, current_cell_value: &T| current_point.use_it_somehow() || current_cell_value.use_it_somehow() );
 pub type IsCellFreeFn<T, const X: usize, const Y: usize> = Box<dyn Fn(MapPoint<X, Y>, &T) -> bool>;
 
+// use cost_fn as follows (use "_" for unused variables):
+// let cost_fn = |point_of_next_cell: MapPoint<X, Y>,
+//                value_of_next_cell: &T,
+//                orientation_of_next_cell: Compass,
+//                current_point: MapPoint<X, Y>,
+//                value_of_current_cell: &T| -> Option<usize> { Some(cost) or None, if blocked };
+
 // use MyMap2D if compilation time is suffice, because it is more efficient and has cleaner interface
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct MyMap2D<T, const X: usize, const Y: usize> {
@@ -87,6 +97,27 @@ impl<T: Copy + Clone + Default, const X: usize, const Y: usize> MyMap2D<T, X, Y>
             items: [[init_element; X]; Y],
         }
     }
+    pub fn from_fn(f: impl Fn(MapPoint<X, Y>) -> T) -> Self {
+        // initializes cells in row-major order
+        let mut map = Self::new();
+        for (p, v) in map.iter_mut() {
+            *v = f(p);
+        }
+        map
+    }
+    pub fn map_values<U: Copy + Clone + Default>(
+        &self,
+        f: impl Fn(MapPoint<X, Y>, &T) -> U,
+    ) -> MyMap2D<U, X, Y> {
+        MyMap2D::from_fn(|p| f(p, self.get(p)))
+    }
+    pub fn zip_with<U: Copy + Clone + Default, V: Copy + Clone + Default>(
+        &self,
+        other: &MyMap2D<U, X, Y>,
+        f: impl Fn(MapPoint<X, Y>, &T, &U) -> V,
+    ) -> MyMap2D<V, X, Y> {
+        MyMap2D::from_fn(|p| f(p, self.get(p), other.get(p)))
+    }
     pub fn get(&self, coordinates: MapPoint<X, Y>) -> &T {
         &self.items[coordinates.y()][coordinates.x()]
     }
@@ -161,6 +192,472 @@ impl<T: Copy + Clone + Default, const X: usize, const Y: usize> MyMap2D<T, X, Y>
         }
         free_zones > 1
     }
+    // cell-wise comparison with other map: changed() returns true, if cell values differ
+    pub fn diff_map<F: Fn(&T, &T) -> bool>(
+        &self,
+        other: &MyMap2D<T, X, Y>,
+        changed: F,
+    ) -> MyMap2D<bool, X, Y> {
+        let mut diff: MyMap2D<bool, X, Y> = MyMap2D::init(false);
+        for (p, v) in self.iter() {
+            diff.set(p, changed(v, other.get(p)));
+        }
+        diff
+    }
+    pub fn changed_cells(
+        &self,
+        other: &MyMap2D<T, X, Y>,
+        changed: impl Fn(&T, &T) -> bool,
+    ) -> Vec<MapPoint<X, Y>> {
+        self.iter()
+            .filter(|(p, v)| changed(v, other.get(*p)))
+            .map(|(p, _)| p)
+            .collect()
+    }
+    pub fn iter_visible_from(
+        &self,
+        origin: MapPoint<X, Y>,
+        is_opaque: impl Fn(MapPoint<X, Y>, &T) -> bool,
+    ) -> impl Iterator<Item = MapPoint<X, Y>> {
+        // one bresenham ray is cast from origin to each cell at edge of map. All cells along a ray are
+        // visible up to and including first opaque cell. Rays to neighboring edge cells differ at most
+        // by one cell per step, therefore rays cover all cells of map. Inner cells share rays, which
+        // reduces costs to O((X + Y) * max(X, Y)). Origin is always visible.
+        let opaque = self.map_values(&is_opaque);
+        let mut visible: MyMap2D<bool, X, Y> = MyMap2D::init(false);
+        visible.set(origin, true);
+        for (edge_cell, _) in self.iter().filter(|(p, _)| !p.map_position().is_center()) {
+            for p in origin.iter_bresenham_line(edge_cell).skip(1) {
+                visible.set(p, true);
+                if *opaque.get(p) {
+                    break;
+                }
+            }
+        }
+        (0..X * Y)
+            .map(MapPoint::<X, Y>::from_index)
+            .filter(move |p| *visible.get(*p))
+    }
+    // BFS from seed over cardinal neighbors; visit_fn is called for each cell, which fulfills predicate
+    // BFS stops, if visit_fn returns false
+    fn flood_fill_visit(
+        &self,
+        seed: MapPoint<X, Y>,
+        predicate: impl Fn(MapPoint<X, Y>, &T) -> bool,
+        mut visit_fn: impl FnMut(MapPoint<X, Y>) -> bool,
+    ) {
+        if !predicate(seed, self.get(seed)) {
+            return;
+        }
+        let mut visited: MyMap2D<bool, X, Y> = MyMap2D::init(false);
+        let mut queue: VecDeque<MapPoint<X, Y>> = VecDeque::new();
+        visited.set(seed, true);
+        queue.push_back(seed);
+        while let Some(current_point) = queue.pop_front() {
+            if !visit_fn(current_point) {
+                return;
+            }
+            for (next_point, _, next_value) in self.iter_neighbors(current_point) {
+                if !*visited.get(next_point) && predicate(next_point, next_value) {
+                    visited.set(next_point, true);
+                    queue.push_back(next_point);
+                }
+            }
+        }
+    }
+    pub fn flood_fill(
+        &self,
+        seed: MapPoint<X, Y>,
+        predicate: impl Fn(MapPoint<X, Y>, &T) -> bool,
+    ) -> Vec<MapPoint<X, Y>> {
+        let mut filled: Vec<MapPoint<X, Y>> = Vec::new();
+        self.flood_fill_visit(seed, predicate, |p| {
+            filled.push(p);
+            true
+        });
+        filled
+    }
+    pub fn flood_fill_count(
+        &self,
+        seed: MapPoint<X, Y>,
+        predicate: impl Fn(MapPoint<X, Y>, &T) -> bool,
+    ) -> usize {
+        // number of cells reachable from seed including seed; 0, if seed does not fulfill predicate
+        let mut count = 0;
+        self.flood_fill_visit(seed, predicate, |_| {
+            count += 1;
+            true
+        });
+        count
+    }
+    pub fn count_reachable(
+        &self,
+        start: MapPoint<X, Y>,
+        passable: impl Fn(MapPoint<X, Y>, &T) -> bool,
+    ) -> usize {
+        // number of passable cells reachable from start including start; 0, if start is not passable
+        // visited cells are tracked in a bool map on stack, therefore no hashing is required
+        self.flood_fill_count(start, passable)
+    }
+    pub fn is_connected(
+        &self,
+        a: MapPoint<X, Y>,
+        b: MapPoint<X, Y>,
+        passable: impl Fn(MapPoint<X, Y>, &T) -> bool,
+    ) -> bool {
+        // true, if passable path between a and b exists; BFS stops as soon as b is found
+        let mut connected = false;
+        self.flood_fill_visit(a, passable, |p| {
+            connected = p == b;
+            !connected
+        });
+        connected
+    }
+    pub fn bfs_distances(
+        &self,
+        sources: &[MapPoint<X, Y>],
+        passable: impl Fn(MapPoint<X, Y>, &T) -> bool,
+    ) -> MyMap2D<Option<usize>, X, Y> {
+        // multi source BFS over cardinal neighbors: distance of each cell to nearest source
+        // sources have distance 0 independent of passable; None, if cell is not reachable
+        let mut distances: MyMap2D<Option<usize>, X, Y> = MyMap2D::init(None);
+        let mut queue: VecDeque<MapPoint<X, Y>> = VecDeque::with_capacity(X * Y);
+        for source in sources.iter() {
+            if distances.get(*source).is_none() {
+                distances.set(*source, Some(0));
+                queue.push_back(*source);
+            }
+        }
+        while let Some(current_point) = queue.pop_front() {
+            let next_distance = distances.get(current_point).unwrap() + 1;
+            for (next_point, _, next_value) in self.iter_neighbors(current_point) {
+                if distances.get(next_point).is_none() && passable(next_point, next_value) {
+                    distances.set(next_point, Some(next_distance));
+                    queue.push_back(next_point);
+                }
+            }
+        }
+        distances
+    }
+    pub fn dijkstra(
+        &self,
+        start: MapPoint<X, Y>,
+        end: MapPoint<X, Y>,
+        cost_fn: impl Fn(MapPoint<X, Y>, &T, Compass, MapPoint<X, Y>, &T) -> Option<usize>,
+    ) -> Option<(Vec<MapPoint<X, Y>>, usize)> {
+        // returns path from start to end (including both) and total cost of path
+        self.shortest_path(start, end, cost_fn, |_, _| 0)
+    }
+    pub fn astar(
+        &self,
+        start: MapPoint<X, Y>,
+        end: MapPoint<X, Y>,
+        cost_fn: impl Fn(MapPoint<X, Y>, &T, Compass, MapPoint<X, Y>, &T) -> Option<usize>,
+        heuristic: impl Fn(MapPoint<X, Y>, MapPoint<X, Y>) -> usize,
+    ) -> Option<(Vec<MapPoint<X, Y>>, usize)> {
+        // heuristic(point, end) must not overestimate cost from point to end, otherwise path may not be optimal
+        self.shortest_path(start, end, cost_fn, heuristic)
+    }
+    pub fn astar_manhattan(
+        &self,
+        start: MapPoint<X, Y>,
+        end: MapPoint<X, Y>,
+        cost_fn: impl Fn(MapPoint<X, Y>, &T, Compass, MapPoint<X, Y>, &T) -> Option<usize>,
+    ) -> Option<(Vec<MapPoint<X, Y>>, usize)> {
+        // manhattan distance is only admissible, if each step costs at least 1
+        self.shortest_path(start, end, cost_fn, |a, b| a.distance(b))
+    }
+    fn shortest_path(
+        &self,
+        start: MapPoint<X, Y>,
+        end: MapPoint<X, Y>,
+        cost_fn: impl Fn(MapPoint<X, Y>, &T, Compass, MapPoint<X, Y>, &T) -> Option<usize>,
+        heuristic: impl Fn(MapPoint<X, Y>, MapPoint<X, Y>) -> usize,
+    ) -> Option<(Vec<MapPoint<X, Y>>, usize)> {
+        // A* with f-score = g + h; Dijkstra, if heuristic is always 0
+        let mut costs: MyMap2D<usize, X, Y> = MyMap2D::init(usize::MAX);
+        let mut predecessors: HashMap<MapPoint<X, Y>, MapPoint<X, Y>> = HashMap::new();
+        // MapPoint does not implement Ord, therefore use index of MapPoint in queue
+        let mut queue: BinaryHeap<Reverse<(usize, usize, usize)>> = BinaryHeap::new();
+        costs.set(start, 0);
+        queue.push(Reverse((heuristic(start, end), 0, start.to_index())));
+        while let Some(Reverse((_, cost, index))) = queue.pop() {
+            let current_point = MapPoint::<X, Y>::from_index(index);
+            if current_point == end {
+                let mut path = vec![end];
+                while let Some(predecessor) = predecessors.get(path.last().unwrap()) {
+                    path.push(*predecessor);
+                }
+                path.reverse();
+                return Some((path, cost));
+            }
+            if cost > *costs.get(current_point) {
+                // outdated queue entry
+                continue;
+            }
+            let current_value = self.get(current_point);
+            for (next_point, orientation, next_value) in self.iter_neighbors(current_point) {
+                if let Some(edge_cost) = cost_fn(
+                    next_point,
+                    next_value,
+                    orientation,
+                    current_point,
+                    current_value,
+                ) {
+                    let next_cost = cost + edge_cost;
+                    if next_cost < *costs.get(next_point) {
+                        costs.set(next_point, next_cost);
+                        predecessors.insert(next_point, current_point);
+                        queue.push(Reverse((
+                            next_cost + heuristic(next_point, end),
+                            next_cost,
+                            next_point.to_index(),
+                        )));
+                    }
+                }
+            }
+        }
+        None
+    }
+    pub fn connected_components(
+        &self,
+        predicate: impl Fn(MapPoint<X, Y>, &T) -> bool,
+    ) -> MyMap2D<Option<usize>, X, Y> {
+        self.connected_components_with_count(predicate).0
+    }
+    pub fn connected_components_with_count(
+        &self,
+        predicate: impl Fn(MapPoint<X, Y>, &T) -> bool,
+    ) -> (MyMap2D<Option<usize>, X, Y>, usize) {
+        // union-find over cells, which fulfill predicate; cells are connected via cardinal neighbors
+        // component ids are given in row-major order of first cell of component
+        let mut union_find = UnionFind::new(X * Y);
+        let mut is_member: MyMap2D<bool, X, Y> = MyMap2D::init(false);
+        for (p, v) in self.iter() {
+            if !predicate(p, v) {
+                continue;
+            }
+            is_member.set(p, true);
+            // check already visited neighbors
+            for neighbor in [p.neighbor(Compass::N), p.neighbor(Compass::W)]
+                .into_iter()
+                .flatten()
+                .filter(|n| *is_member.get(*n))
+            {
+                union_find.union(p.to_index(), neighbor.to_index());
+            }
+        }
+        let mut components: MyMap2D<Option<usize>, X, Y> = MyMap2D::init(None);
+        let mut root_ids: HashMap<usize, usize> = HashMap::new();
+        for (p, _) in self.iter().filter(|(p, _)| *is_member.get(*p)) {
+            let root = union_find.find(p.to_index());
+            let next_id = root_ids.len();
+            components.set(p, Some(*root_ids.entry(root).or_insert(next_id)));
+        }
+        (components, root_ids.len())
+    }
+    pub fn count_components(&self, predicate: impl Fn(MapPoint<X, Y>, &T) -> bool) -> usize {
+        self.connected_components_with_count(predicate).1
+    }
+    pub fn largest_component_size(&self, predicate: impl Fn(MapPoint<X, Y>, &T) -> bool) -> usize {
+        let (components, count) = self.connected_components_with_count(predicate);
+        let mut sizes = vec![0; count];
+        for id in components.iter().filter_map(|(_, id)| *id) {
+            sizes[id] += 1;
+        }
+        sizes.into_iter().max().unwrap_or(0)
+    }
+    fn region_members(region: &[MapPoint<X, Y>]) -> MyMap2D<bool, X, Y> {
+        let mut members: MyMap2D<bool, X, Y> = MyMap2D::init(false);
+        for p in region.iter() {
+            members.set(*p, true);
+        }
+        members
+    }
+    pub fn region_perimeter(&self, region: &[MapPoint<X, Y>]) -> usize {
+        // number of cell sides, which border on a cell outside of region or on boundary of map
+        let members = Self::region_members(region);
+        members
+            .iter()
+            .filter(|(_, is_member)| **is_member)
+            .map(|(p, _)| 4 - members.iter_neighbors(p).filter(|(.., n)| **n).count())
+            .sum()
+    }
+    pub fn region_area(region: &[MapPoint<X, Y>]) -> usize {
+        // number of cells of region; region must not contain duplicates
+        debug_assert_eq!(
+            MyMap2D::<bool, X, Y>::region_members(region)
+                .iter()
+                .filter(|(_, is_member)| **is_member)
+                .count(),
+            region.len(),
+            "region contains duplicates"
+        );
+        region.len()
+    }
+    pub fn iter_region_border<'a>(
+        &'a self,
+        region: &'a [MapPoint<X, Y>],
+    ) -> impl Iterator<Item = MapPoint<X, Y>> + 'a {
+        // cells of region with at least one cardinal neighbor, which is not in region or outside of map;
+        // consistent with region_perimeter() boundary of map counts as non region
+        let members = Self::region_members(region);
+        region
+            .iter()
+            .copied()
+            .filter(move |p| members.iter_neighbors(*p).filter(|(.., n)| **n).count() < 4)
+    }
+    pub fn transpose(&self) -> MyMap2D<T, Y, X> {
+        let mut transposed: MyMap2D<T, Y, X> = MyMap2D::new();
+        for (p, v) in self.iter() {
+            transposed.set(p.reflect_diagonal(), *v);
+        }
+        transposed
+    }
+    pub fn rotate_90_cw(&self) -> MyMap2D<T, Y, X> {
+        let mut rotated: MyMap2D<T, Y, X> = MyMap2D::new();
+        for (p, v) in self.iter() {
+            rotated.set(p.rotate_90_cw(), *v);
+        }
+        rotated
+    }
+    pub fn rotate_180(&self) -> MyMap2D<T, X, Y> {
+        let mut rotated: MyMap2D<T, X, Y> = MyMap2D::new();
+        for (p, v) in self.iter() {
+            rotated.set(p.rotate_180(), *v);
+        }
+        rotated
+    }
+    pub fn rotate_270_cw(&self) -> MyMap2D<T, Y, X> {
+        let mut rotated: MyMap2D<T, Y, X> = MyMap2D::new();
+        for (p, v) in self.iter() {
+            rotated.set(p.rotate_270_cw(), *v);
+        }
+        rotated
+    }
+    pub fn flip_horizontal(&self) -> Self {
+        // mirror at vertical axis: left and right are swapped
+        let mut flipped = *self;
+        for row in flipped.items.iter_mut() {
+            row.reverse();
+        }
+        flipped
+    }
+    pub fn flip_vertical(&self) -> Self {
+        // mirror at horizontal axis: top and bottom are swapped
+        let mut flipped = *self;
+        flipped.items.reverse();
+        flipped
+    }
+    pub fn step_cellular_automaton(
+        &self,
+        kernel: impl Fn(MapPoint<X, Y>, &T, &[(MapPoint<X, Y>, Compass, &T)]) -> T,
+    ) -> MyMap2D<T, X, Y> {
+        // next generation is computed into new map; kernel gets cardinal neighbors
+        let mut neighbors: Vec<(MapPoint<X, Y>, Compass, &T)> = Vec::with_capacity(4);
+        let mut next_generation: MyMap2D<T, X, Y> = MyMap2D::new();
+        for (p, v) in self.iter() {
+            neighbors.clear();
+            neighbors.extend(self.iter_neighbors(p));
+            next_generation.set(p, kernel(p, v, &neighbors));
+        }
+        next_generation
+    }
+    pub fn step_with_corners(
+        &self,
+        kernel: impl Fn(MapPoint<X, Y>, &T, &[(MapPoint<X, Y>, Compass, &T)]) -> T,
+    ) -> MyMap2D<T, X, Y> {
+        // next generation is computed into new map; kernel gets cardinal and ordinal neighbors
+        let mut neighbors: Vec<(MapPoint<X, Y>, Compass, &T)> = Vec::with_capacity(8);
+        let mut next_generation: MyMap2D<T, X, Y> = MyMap2D::new();
+        for (p, v) in self.iter() {
+            neighbors.clear();
+            neighbors.extend(self.iter_neighbors_with_corners(p));
+            next_generation.set(p, kernel(p, v, &neighbors));
+        }
+        next_generation
+    }
+    pub fn morphological_dilate(
+        &self,
+        predicate: impl Fn(&T) -> bool,
+        radius: usize,
+    ) -> MyMap2D<bool, X, Y> {
+        // true, if any cell within manhattan distance radius fulfills predicate
+        self.morphological(predicate, radius, true)
+    }
+    pub fn morphological_erode(
+        &self,
+        predicate: impl Fn(&T) -> bool,
+        radius: usize,
+    ) -> MyMap2D<bool, X, Y> {
+        // true, if all cells within manhattan distance radius fulfill predicate
+        // cells outside of map are ignored
+        self.morphological(predicate, radius, false)
+    }
+    fn morphological(
+        &self,
+        predicate: impl Fn(&T) -> bool,
+        radius: usize,
+        dilate: bool,
+    ) -> MyMap2D<bool, X, Y> {
+        let mask = self.map_values(|_, v| predicate(v));
+        MyMap2D::from_fn(|p| {
+            if radius == 1 {
+                // single scan of center and cardinal neighbors
+                let mut cells = p.iter_neighbors(Compass::N, true, true, false);
+                if dilate {
+                    cells.any(|(n, _)| *mask.get(n))
+                } else {
+                    cells.all(|(n, _)| *mask.get(n))
+                }
+            } else {
+                let mut cells = p.iter_disk(radius);
+                if dilate {
+                    cells.any(|n| *mask.get(n))
+                } else {
+                    cells.all(|n| *mask.get(n))
+                }
+            }
+        })
+    }
+    pub fn iter_kernel<const KX: usize, const KY: usize>(
+        &self,
+        center: MapPoint<X, Y>,
+    ) -> impl Iterator<Item = Option<(MapPoint<X, Y>, &T)>> {
+        // KX * KY cells of kernel around center in row-major order; None, if cell is outside of map
+        // if size of kernel is even, center is right respectively below of middle of kernel
+        (0..KY).flat_map(move |ky| {
+            (0..KX).map(move |kx| {
+                center
+                    .checked_offset(kx as i32 - (KX / 2) as i32, ky as i32 - (KY / 2) as i32)
+                    .map(|p| (p, self.get(p)))
+            })
+        })
+    }
+    pub fn apply_kernel<
+        U: Copy + Clone + Default,
+        const KX: usize,
+        const KY: usize,
+        const N: usize,
+    >(
+        &self,
+        f: impl Fn(MapPoint<X, Y>, [Option<&T>; N]) -> U,
+    ) -> MyMap2D<U, X, Y> {
+        // N has to be KX * KY, since array length cannot be computed from const generics
+        if KX * KY != N {
+            panic!(
+                "line {}, number of kernel elements does not fit to KX and KY",
+                line!()
+            );
+        }
+        MyMap2D::from_fn(|p| {
+            let mut window: [Option<&T>; N] = [None; N];
+            for (cell, kernel_cell) in window.iter_mut().zip(self.iter_kernel::<KX, KY>(p)) {
+                *cell = kernel_cell.map(|(_, v)| v);
+            }
+            f(p, window)
+        })
+    }
     pub fn iter(&self) -> impl Iterator<Item = (MapPoint<X, Y>, &T)> {
         self.items.iter().enumerate().flat_map(|(y, row)| {
             row.iter()
@@ -326,6 +823,19 @@ impl<T: Copy + Clone + Default, const X: usize, const Y: usize> Default for MyMa
     }
 }
 
+impl<const X: usize, const Y: usize> MyMap2D<bool, X, Y> {
+    pub fn step_conways_life(&self) -> MyMap2D<bool, X, Y> {
+        // living cell survives with 2 or 3 living neighbors, dead cell is born with 3 living neighbors
+        self.step_with_corners(|_, alive, neighbors| {
+            match neighbors.iter().filter(|(_, _, n)| **n).count() {
+                2 => *alive,
+                3 => true,
+                _ => false,
+            }
+        })
+    }
+}
+
 struct DistanceIter<'a, T, const X: usize, const Y: usize> {
     data_map: &'a MyMap2D<T, X, Y>,
     // input for filter_fn in stated order:
@@ -394,6 +904,7 @@ impl<'a, T: Copy + Clone + Default, const X: usize, const Y: usize> Iterator
 mod tests {
 
     use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
 
     #[test]
     fn test_cut_off() {
@@ -460,4 +971,428 @@ mod tests {
             eprintln!("");
         }
     }
+
+    #[test]
+    fn test_diff_map() {
+        const X: usize = 5;
+        const Y: usize = 4;
+        let old_map: MyMap2D<u8, X, Y> = MyMap2D::init(1);
+        let mut new_map = old_map;
+        new_map.set((1, 2).into(), 3);
+        new_map.set((4, 0).into(), 2);
+        let diff = old_map.diff_map(&new_map, |a, b| a != b);
+        assert_eq!(diff.iter().filter(|(_, c)| **c).count(), 2);
+        assert!(*diff.get((1, 2).into()));
+        assert!(*diff.get((4, 0).into()));
+        assert_eq!(
+            old_map.changed_cells(&new_map, |a, b| a != b),
+            vec![MapPoint::<X, Y>::new(4, 0), MapPoint::<X, Y>::new(1, 2)]
+        );
+        // custom change detection: only changes bigger than 1 count
+        assert_eq!(
+            old_map.changed_cells(&new_map, |a, b| a.abs_diff(*b) > 1),
+            vec![MapPoint::<X, Y>::new(1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_iter_visible_from() {
+        const X: usize = 7;
+        const Y: usize = 5;
+        let input = ".......\n...#...\n...#...\n...#...\n.......";
+        let map: MyMap2D<char, X, Y> = MyMap2D::from(input);
+        let origin = MapPoint::<X, Y>::new(0, 2);
+        let visible: Vec<MapPoint<X, Y>> =
+            map.iter_visible_from(origin, |_, c| *c == '#').collect();
+        assert!(visible.contains(&origin));
+        // all cells in front of wall and wall itself are visible
+        for (p, _) in map.iter().filter(|(p, _)| p.x() <= 3) {
+            assert!(visible.contains(&p));
+        }
+        // cells in shadow of wall are not visible
+        for x in 4..X {
+            for y in 1..4 {
+                assert!(!visible.contains(&MapPoint::new(x, y)));
+            }
+        }
+        // without opaque cells rays cover all cells from every origin
+        for (origin, _) in map.iter() {
+            assert_eq!(map.iter_visible_from(origin, |_, _| false).count(), X * Y);
+        }
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        const X: usize = 6;
+        const Y: usize = 4;
+        let input = "..#...\n..#.#.\n###.#.\n....#.";
+        let map: MyMap2D<char, X, Y> = MyMap2D::from(input);
+        let is_free = |_: MapPoint<X, Y>, c: &char| *c == '.';
+        let top_left = map.flood_fill(MapPoint::new(0, 0), is_free);
+        assert_eq!(top_left.len(), 4);
+        assert_eq!(top_left[0], MapPoint::new(0, 0));
+        // area right of first wall reaches bottom row and right column
+        let right_area = map.flood_fill(MapPoint::new(3, 0), is_free);
+        assert_eq!(right_area.len(), 12);
+        assert!(right_area.contains(&MapPoint::new(0, 3)));
+        assert!(right_area.contains(&MapPoint::new(5, 3)));
+        assert_eq!(map.flood_fill_count(MapPoint::new(3, 0), is_free), 12);
+        // seed does not fulfill predicate
+        assert!(map.flood_fill(MapPoint::new(2, 0), is_free).is_empty());
+        assert_eq!(map.flood_fill_count(MapPoint::new(2, 0), is_free), 0);
+
+        assert_eq!(map.count_reachable(MapPoint::new(1, 1), is_free), 4);
+        assert_eq!(map.count_reachable(MapPoint::new(5, 0), is_free), 12);
+        assert!(map.is_connected(MapPoint::new(3, 0), MapPoint::new(0, 3), is_free));
+        assert!(map.is_connected(MapPoint::new(0, 0), MapPoint::new(0, 0), is_free));
+        assert!(!map.is_connected(MapPoint::new(0, 0), MapPoint::new(3, 0), is_free));
+        // blocked cells are never connected
+        assert!(!map.is_connected(MapPoint::new(2, 0), MapPoint::new(2, 1), is_free));
+        assert!(!map.is_connected(MapPoint::new(3, 0), MapPoint::new(4, 1), is_free));
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        const X: usize = 5;
+        const Y: usize = 3;
+        let input = "..#..\n..#..\n.....";
+        let map: MyMap2D<char, X, Y> = MyMap2D::from(input);
+        let passable = |_: MapPoint<X, Y>, c: &char| *c == '.';
+        let distances = map.bfs_distances(&[MapPoint::new(0, 0)], passable);
+        assert_eq!(*distances.get(MapPoint::new(0, 0)), Some(0));
+        assert_eq!(*distances.get(MapPoint::new(1, 1)), Some(2));
+        assert_eq!(*distances.get(MapPoint::new(2, 0)), None);
+        // around wall
+        assert_eq!(*distances.get(MapPoint::new(4, 0)), Some(8));
+        // distances of bfs_distances are equal to distances of iter_distance
+        let filter_fn: FilterFn<char, X, Y> = Box::new(|_, c, _, _, _, _| *c == '.');
+        for (p, _, distance) in map.iter_distance(MapPoint::new(0, 0), filter_fn) {
+            assert_eq!(*distances.get(p), Some(distance));
+        }
+
+        // second source on other side of wall
+        let distances = map.bfs_distances(&[MapPoint::new(0, 0), MapPoint::new(4, 0)], passable);
+        assert_eq!(*distances.get(MapPoint::new(4, 0)), Some(0));
+        assert_eq!(*distances.get(MapPoint::new(3, 1)), Some(2));
+        assert_eq!(*distances.get(MapPoint::new(2, 2)), Some(4));
+        assert_eq!(distances.iter().filter(|(_, d)| d.is_none()).count(), 2);
+        assert!(map
+            .bfs_distances(&[], passable)
+            .iter()
+            .all(|(_, d)| d.is_none()));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        const X: usize = 6;
+        const Y: usize = 4;
+        // digits are cost to enter cell, '#' is wall
+        let input = "111111\n1###91\n1#1111\n111#11";
+        let map: MyMap2D<char, X, Y> = MyMap2D::from(input);
+        let cost_fn = |_: MapPoint<X, Y>, c: &char, _: Compass, _: MapPoint<X, Y>, _: &char| {
+            c.to_digit(10).map(|d| d as usize)
+        };
+        let (path, cost) = map
+            .dijkstra(MapPoint::new(0, 3), MapPoint::new(4, 1), cost_fn)
+            .unwrap();
+        // cheapest path leads around wall at (3, 3) and enters (4, 1) from below
+        assert_eq!(cost, 14);
+        assert_eq!(path.len(), 7);
+        assert_eq!(path[5], MapPoint::new(4, 2));
+        assert_eq!(path[0], MapPoint::new(0, 3));
+        assert_eq!(*path.last().unwrap(), MapPoint::new(4, 1));
+        assert!(path.windows(2).all(|w| w[0].distance(w[1]) == 1));
+        assert_eq!(
+            path.iter()
+                .skip(1)
+                .map(|p| map.get(*p).to_digit(10).unwrap() as usize)
+                .sum::<usize>(),
+            cost
+        );
+        // start equals end
+        assert_eq!(
+            map.dijkstra(MapPoint::new(2, 2), MapPoint::new(2, 2), cost_fn),
+            Some((vec![MapPoint::new(2, 2)], 0))
+        );
+        // end is enclosed by walls
+        let input = "1111\n#1#1\n1#11";
+        let map: MyMap2D<char, 4, 3> = MyMap2D::from(input);
+        let cost_fn = |_: MapPoint<4, 3>, c: &char, _: Compass, _: MapPoint<4, 3>, _: &char| {
+            c.to_digit(10).map(|d| d as usize)
+        };
+        assert_eq!(
+            map.dijkstra(MapPoint::new(0, 0), MapPoint::new(0, 2), cost_fn),
+            None
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        const X: usize = 100;
+        const Y: usize = 100;
+        // sparse grid: some random walls; seeded to get same map for every test run
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut map: MyMap2D<bool, X, Y> = MyMap2D::init(true);
+        for _ in 0..(X * Y / 5) {
+            map.set(
+                MapPoint::new(rng.gen_range(0..X), rng.gen_range(0..Y)),
+                false,
+            );
+        }
+        let start = MapPoint::<X, Y>::NW;
+        let end = MapPoint::<X, Y>::SE;
+        map.set(start, true);
+        map.set(end, true);
+        let cost_fn = |_: MapPoint<X, Y>, free: &bool, _: Compass, _: MapPoint<X, Y>, _: &bool| {
+            free.then_some(1)
+        };
+
+        let dijkstra_result = map.dijkstra(start, end, cost_fn);
+        let astar_result = map.astar(start, end, cost_fn, |a, b| a.distance(b));
+        let astar_manhattan_result = map.astar_manhattan(start, end, cost_fn);
+
+        // paths may differ, if more than one shortest path exists
+        let (_, cost) = dijkstra_result.unwrap();
+        let (path, astar_cost) = astar_result.unwrap();
+        assert_eq!(astar_cost, cost);
+        assert_eq!(path.len(), cost + 1);
+        assert!(path.iter().all(|p| *map.get(*p)));
+        assert!(path.windows(2).all(|w| w[0].distance(w[1]) == 1));
+        assert_eq!(astar_manhattan_result.unwrap().1, cost);
+        assert!(cost >= start.distance(end));
+
+        // without walls shortest path has manhattan distance
+        let map: MyMap2D<bool, X, Y> = MyMap2D::init(true);
+        let (path, cost) = map.astar_manhattan(start, end, cost_fn).unwrap();
+        assert_eq!(cost, X + Y - 2);
+        assert_eq!(path.len(), X + Y - 1);
+    }
+
+    #[test]
+    fn test_connected_components() {
+        const X: usize = 6;
+        const Y: usize = 4;
+        // U shaped component is connected at bottom, single cells at corners are separate components
+        let input = "#.#..#\n#.#...\n###.#.\n....#.";
+        let map: MyMap2D<char, X, Y> = MyMap2D::from(input);
+        let is_wall = |_: MapPoint<X, Y>, c: &char| *c == '#';
+        let (components, count) = map.connected_components_with_count(is_wall);
+        assert_eq!(count, 3);
+        assert_eq!(*components.get(MapPoint::new(0, 0)), Some(0));
+        assert_eq!(*components.get(MapPoint::new(2, 0)), Some(0));
+        assert_eq!(*components.get(MapPoint::new(5, 0)), Some(1));
+        assert_eq!(*components.get(MapPoint::new(4, 3)), Some(2));
+        assert_eq!(*components.get(MapPoint::new(1, 0)), None);
+        assert_eq!(map.count_components(is_wall), 3);
+        assert_eq!(map.largest_component_size(is_wall), 7);
+        let is_free = |_: MapPoint<X, Y>, c: &char| *c == '.';
+        assert_eq!(map.count_components(is_free), 2);
+        assert_eq!(map.largest_component_size(is_free), 12);
+        // each component is same as flood fill of one of its cells
+        for (p, id) in components.iter().filter(|(_, id)| id.is_some()) {
+            let flood = map.flood_fill(p, is_wall);
+            assert_eq!(
+                flood.len(),
+                components.iter().filter(|(_, other)| *other == id).count()
+            );
+        }
+        assert_eq!(map.count_components(|_, _| false), 0);
+        assert_eq!(map.largest_component_size(|_, _| false), 0);
+    }
+
+    #[test]
+    fn test_region() {
+        const X: usize = 4;
+        const Y: usize = 4;
+        let map: MyMap2D<u8, X, Y> = MyMap2D::new();
+        // L shaped region in top left corner
+        let region: Vec<MapPoint<X, Y>> = [(0, 0), (1, 0), (0, 1), (0, 2)]
+            .into_iter()
+            .map(MapPoint::from)
+            .collect();
+        assert_eq!(MyMap2D::<u8, X, Y>::region_area(&region), 4);
+        assert_eq!(map.region_perimeter(&region), 10);
+        // (0, 0) has only neighbors in region, but touches boundary of map
+        let border: Vec<MapPoint<X, Y>> = map.iter_region_border(&region).collect();
+        assert_eq!(border, region);
+
+        // 2x2 block in center
+        let block: Vec<MapPoint<X, Y>> = [(1, 1), (2, 1), (1, 2), (2, 2)]
+            .into_iter()
+            .map(MapPoint::from)
+            .collect();
+        assert_eq!(map.region_perimeter(&block), 8);
+        assert_eq!(map.iter_region_border(&block).count(), 4);
+
+        // whole map has perimeter of map boundary and all cells at boundary of map as border cells
+        let all: Vec<MapPoint<X, Y>> = map.iter().map(|(p, _)| p).collect();
+        assert_eq!(map.region_perimeter(&all), 2 * (X + Y));
+        assert_eq!(map.iter_region_border(&all).count(), 2 * (X + Y) - 4);
+        assert!(map
+            .iter_region_border(&all)
+            .all(|p| p.x() == 0 || p.y() == 0 || p.x() == X - 1 || p.y() == Y - 1));
+        assert_eq!(map.region_perimeter(&[]), 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "region contains duplicates")]
+    fn test_region_area_duplicates() {
+        let point = MapPoint::<3, 3>::new(1, 1);
+        MyMap2D::<u8, 3, 3>::region_area(&[point, point]);
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        const X: usize = 3;
+        const Y: usize = 2;
+        let map: MyMap2D<char, X, Y> = MyMap2D::from("abc\ndef");
+        assert_eq!(map.transpose(), MyMap2D::<char, Y, X>::from("ad\nbe\ncf"));
+        assert_eq!(
+            map.rotate_90_cw(),
+            MyMap2D::<char, Y, X>::from("da\neb\nfc")
+        );
+        assert_eq!(map.rotate_180(), MyMap2D::<char, X, Y>::from("fed\ncba"));
+        assert_eq!(
+            map.rotate_270_cw(),
+            MyMap2D::<char, Y, X>::from("cf\nbe\nad")
+        );
+        assert_eq!(
+            map.flip_horizontal(),
+            MyMap2D::<char, X, Y>::from("cba\nfed")
+        );
+        assert_eq!(map.flip_vertical(), MyMap2D::<char, X, Y>::from("def\nabc"));
+        assert_eq!(map.rotate_90_cw().rotate_90_cw(), map.rotate_180());
+        assert_eq!(map.rotate_90_cw().rotate_270_cw(), map);
+        assert_eq!(map.transpose().transpose(), map);
+        assert_eq!(map.flip_horizontal().flip_vertical(), map.rotate_180());
+    }
+
+    #[test]
+    fn test_from_fn_map_values_and_zip_with() {
+        const X: usize = 4;
+        const Y: usize = 3;
+        let index_map: MyMap2D<usize, X, Y> = MyMap2D::from_fn(|p| p.to_index());
+        assert!(index_map.iter().all(|(p, v)| *v == p.to_index()));
+        let even_map = index_map.map_values(|_, v| v % 2 == 0);
+        assert_eq!(even_map.iter().filter(|(_, v)| **v).count(), 6);
+        assert!(*even_map.get(MapPoint::new(2, 1)));
+        let x_map: MyMap2D<usize, X, Y> = MyMap2D::from_fn(|p| p.x());
+        let sum_map = index_map.zip_with(&x_map, |p, i, x| i + x + p.y());
+        assert_eq!(*sum_map.get(MapPoint::new(3, 2)), 11 + 3 + 2);
+        let diff: MyMap2D<bool, X, Y> = index_map.zip_with(&even_map, |_, i, e| (i % 2 == 0) != *e);
+        assert!(diff.iter().all(|(_, v)| !*v));
+    }
+
+    #[test]
+    fn test_cellular_automaton() {
+        const X: usize = 5;
+        const Y: usize = 5;
+        let to_bool = |_: MapPoint<X, Y>, c: &char| *c == '#';
+        // blinker oscillates with period 2
+        let blinker: MyMap2D<char, X, Y> = MyMap2D::from(".....\n..#..\n..#..\n..#..\n.....");
+        let blinker = blinker.map_values(to_bool);
+        let rotated: MyMap2D<char, X, Y> = MyMap2D::from(".....\n.....\n.###.\n.....\n.....");
+        let rotated = rotated.map_values(to_bool);
+        assert_eq!(blinker.step_conways_life(), rotated);
+        assert_eq!(blinker.step_conways_life().step_conways_life(), blinker);
+        // block is stable
+        let block: MyMap2D<char, X, Y> = MyMap2D::from(".....\n.##..\n.##..\n.....\n.....");
+        let block = block.map_values(to_bool);
+        assert_eq!(block.step_conways_life(), block);
+
+        // cardinal kernel: each cell becomes number of cardinal neighbors
+        let map: MyMap2D<usize, X, Y> = MyMap2D::init(0);
+        let neighbor_count = map.step_cellular_automaton(|_, _, neighbors| neighbors.len());
+        assert_eq!(*neighbor_count.get(MapPoint::new(0, 0)), 2);
+        assert_eq!(*neighbor_count.get(MapPoint::new(2, 0)), 3);
+        assert_eq!(*neighbor_count.get(MapPoint::new(2, 2)), 4);
+        let neighbor_count = map.step_with_corners(|_, _, neighbors| neighbors.len());
+        assert_eq!(*neighbor_count.get(MapPoint::new(0, 0)), 3);
+        assert_eq!(*neighbor_count.get(MapPoint::new(2, 2)), 8);
+    }
+
+    #[test]
+    fn test_morphological_dilate_and_erode() {
+        const X: usize = 7;
+        const Y: usize = 7;
+        let mut map: MyMap2D<char, X, Y> = MyMap2D::init('.');
+        map.set(MapPoint::new(3, 3), '#');
+        let is_obstacle = |c: &char| *c == '#';
+        assert_eq!(
+            map.morphological_dilate(is_obstacle, 0),
+            map.map_values(|_, c| is_obstacle(c))
+        );
+        let dilated = map.morphological_dilate(is_obstacle, 1);
+        assert_eq!(dilated.iter().filter(|(_, d)| **d).count(), 5);
+        assert!(*dilated.get(MapPoint::new(3, 2)));
+        assert!(!*dilated.get(MapPoint::new(2, 2)));
+        let dilated = map.morphological_dilate(is_obstacle, 2);
+        assert_eq!(dilated.iter().filter(|(_, d)| **d).count(), 13);
+        assert!(*dilated.get(MapPoint::new(2, 2)));
+        assert!(*dilated.get(MapPoint::new(3, 1)));
+        assert!(!*dilated.get(MapPoint::new(1, 1)));
+
+        // erosion of free cells is inverse of dilation of obstacles
+        let is_free = |c: &char| *c == '.';
+        for radius in 1..=3 {
+            let eroded = map.morphological_erode(is_free, radius);
+            let dilated = map.morphological_dilate(is_obstacle, radius);
+            assert!(eroded.iter().all(|(p, e)| *e != *dilated.get(p)));
+        }
+        // cells outside of map are ignored
+        assert!(*map.morphological_erode(is_free, 2).get(MapPoint::new(0, 0)));
+    }
+
+    #[test]
+    fn test_kernel() {
+        const X: usize = 4;
+        const Y: usize = 3;
+        let map: MyMap2D<usize, X, Y> = MyMap2D::from_fn(|p| p.to_index());
+        let kernel: Vec<Option<usize>> = map
+            .iter_kernel::<3, 3>(MapPoint::new(0, 1))
+            .map(|c| c.map(|(_, v)| *v))
+            .collect();
+        assert_eq!(
+            kernel,
+            vec![
+                None,
+                Some(0),
+                Some(1),
+                None,
+                Some(4),
+                Some(5),
+                None,
+                Some(8),
+                Some(9)
+            ]
+        );
+        assert_eq!(
+            map.iter_kernel::<1, 1>(MapPoint::new(2, 2))
+                .collect::<Vec<_>>(),
+            vec![Some((MapPoint::new(2, 2), &10))]
+        );
+        // box blur with sum of existing cells
+        let sum_map =
+            map.apply_kernel::<usize, 3, 3, 9>(|_, window| window.iter().flatten().copied().sum());
+        assert_eq!(*sum_map.get(MapPoint::new(0, 0)), 1 + 4 + 5);
+        assert_eq!(
+            *sum_map.get(MapPoint::new(1, 1)),
+            (0..3).chain(4..7).chain(8..11).sum::<usize>()
+        );
+        // horizontal edge detection: right minus left neighbor
+        let edge_map = map.apply_kernel::<i64, 3, 1, 3>(|_, window| match window {
+            [Some(l), _, Some(r)] => *r as i64 - *l as i64,
+            _ => 0,
+        });
+        assert_eq!(*edge_map.get(MapPoint::new(1, 2)), 2);
+        assert_eq!(*edge_map.get(MapPoint::new(3, 2)), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kernel_size_mismatch() {
+        let map: MyMap2D<usize, 4, 3> = MyMap2D::new();
+        map.apply_kernel::<usize, 3, 3, 8>(|_, _| 0);
+    }
 }
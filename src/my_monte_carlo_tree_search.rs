@@ -1,15 +1,29 @@
+pub mod alpha_zero;
 pub mod game_engine;
+#[cfg(feature = "serde")]
+mod search_statistics;
+pub mod simulation_policy;
+#[cfg(feature = "postcard")]
+mod tree_persistence;
 
 use rand::prelude::*;
 use rand::seq::IteratorRandom;
 use std::any::Any;
+use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
 use std::time::Duration;
 use std::time::Instant;
 
 use crate::my_tree::*;
+use alpha_zero::AlphaZeroConfig;
+use simulation_policy::SimulationPolicy;
 
 #[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MonteCarloPlayer {
     Me,
     Opp,
@@ -22,9 +36,53 @@ impl MonteCarloPlayer {
             MonteCarloPlayer::Opp => MonteCarloPlayer::Me,
         }
     }
+    // index of player in rewards of MonteCarloNode: me is always player 0
+    pub const fn index(&self) -> usize {
+        match self {
+            MonteCarloPlayer::Me => 0,
+            MonteCarloPlayer::Opp => 1,
+        }
+    }
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            0 => MonteCarloPlayer::Me,
+            1 => MonteCarloPlayer::Opp,
+            _ => panic!(
+                "line {}, player index of two player game is out of range",
+                line!()
+            ),
+        }
+    }
+}
+
+// player of game with N players: index in 0..N, from view of tree search me is player 0
+// with MonteCarloGameMode::RoundRobin players act in sequence 0, 1, ..., N-1, 0, 1, ...
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct MultiPlayer<const N: usize>(usize);
+
+impl<const N: usize> MultiPlayer<N> {
+    pub fn new(index: usize) -> Self {
+        if index >= N {
+            panic!("line {}, player index is out of range", line!());
+        }
+        MultiPlayer(index)
+    }
+    pub fn index(&self) -> usize {
+        self.0
+    }
+    pub fn next_player(&self) -> Self {
+        MultiPlayer((self.0 + 1) % N)
+    }
+}
+
+impl<const N: usize> From<MonteCarloPlayer> for MultiPlayer<N> {
+    fn from(player: MonteCarloPlayer) -> Self {
+        MultiPlayer::new(player.index())
+    }
 }
 
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MonteCarloNodeType {
     GameDataUpdate,
     ActionResult,
@@ -37,6 +95,80 @@ pub enum MonteCarloNodeType {
 pub enum MonteCarloGameMode {
     SameTurnParallel, // both players act parallel on same turn. Actions change game data at the same time
     ByTurns,          // each turn only one player acts, players switch at turn end
+    RoundRobin, // like ByTurns for N players acting in sequence 0, 1, ..., N-1; required for N != 2
+}
+
+#[derive(Copy, Clone, Debug)]
+// decay of weighting_factor over played turns: weighting_factor * decay_factor(played_turns)
+// in late game positions are more deterministic and less exploration is needed
+pub enum ExplorationDecayPolicy {
+    // no decay, weighting_factor is used as is
+    Constant,
+    // linear decay from 1.0 at turn 0 to final_factor at max_number_of_turns
+    Linear { final_factor: f32 },
+    // decay_rate.powi(played_turns), decay_rate should be in (0.0, 1.0]
+    Exponential { decay_rate: f32 },
+    // user defined decay function of played_turns
+    Custom(fn(usize) -> f32),
+}
+
+impl ExplorationDecayPolicy {
+    pub fn decay_factor(&self, played_turns: usize, max_number_of_turns: usize) -> f32 {
+        match self {
+            ExplorationDecayPolicy::Constant => 1.0,
+            ExplorationDecayPolicy::Linear { final_factor } => {
+                if max_number_of_turns == 0 {
+                    return 1.0;
+                }
+                let progress =
+                    (played_turns.min(max_number_of_turns) as f32) / max_number_of_turns as f32;
+                1.0 + (final_factor - 1.0) * progress
+            }
+            ExplorationDecayPolicy::Exponential { decay_rate } => {
+                decay_rate.powi(played_turns as i32)
+            }
+            ExplorationDecayPolicy::Custom(decay_fn) => decay_fn(played_turns),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+// defines, how expand_tree() limits expansion of tree
+pub enum MonteCarloExpansionMode {
+    // expand tree until time out of current turn
+    TimeBounded,
+    // run iterations_per_depth cycles with depth limit 1, 2, ..., max_depth relative to tree_root
+    // time out is ignored, which results in deterministic search depth
+    IterativeDeepening {
+        max_depth: usize,
+        iterations_per_depth: usize,
+    },
+}
+
+// equivalence parameter of RAVE: number of samples, at which UCT and RAVE score are weighted equally
+// beta = sqrt(RAVE_EQUIVALENCE / (3 * samples + RAVE_EQUIVALENCE))
+const RAVE_EQUIVALENCE: f32 = 500.0;
+
+// exploration part of UCT score
+fn uct_exploration_score(weighting_factor: f32, parent_samples: f32, samples: f32) -> f32 {
+    weighting_factor * (parent_samples.log10() / samples).sqrt()
+}
+
+// actions played during playout with index of acting player
+type PlayedActions<A> = Vec<(usize, A)>;
+
+// number of cycles of a worker of expand_tree_parallel(), whose results are propagated together
+const PARALLEL_BATCH_SIZE: usize = 32;
+
+// result of a worker of expand_tree_parallel(): path from tree_root to playout node (see
+// path_from_root()), playout rewards, backtrack_heuristic and played actions of playout
+type WorkerResult<A, const N: usize> = (Vec<usize>, [f32; N], bool, PlayedActions<A>);
+
+// rewards of all players for playout score from view of me: other players share loss of me
+fn score_to_rewards<const N: usize>(playout_score: f32) -> [f32; N] {
+    let mut rewards = [(1.0 - playout_score) / (N - 1) as f32; N];
+    rewards[MonteCarloPlayer::Me.index()] = playout_score;
+    rewards
 }
 
 #[derive(Copy, Clone, PartialEq)]
@@ -58,6 +190,18 @@ pub trait MonteCarloPlayerAction: Copy + Clone + PartialEq + Default + 'static {
         player: MonteCarloPlayer,
         parent_game_turn: usize,
     ) -> Box<dyn Iterator<Item = Self> + '_>;
+    // actions of player with index in 0..N; games with more than two players have to implement it
+    fn iter_player_actions(
+        game_data: &impl MonteCarloGameData,
+        player: usize,
+        parent_game_turn: usize,
+    ) -> Box<dyn Iterator<Item = Self> + '_> {
+        Self::iter_actions(
+            game_data,
+            MonteCarloPlayer::from_index(player),
+            parent_game_turn,
+        )
+    }
 }
 
 // Trait for updating game data after modifications through players. Normally there as some kind of random factor involved, e.g. drawing new ressources of several kind from a "bag".
@@ -94,6 +238,27 @@ pub trait MonteCarloGameData: Copy + Clone + PartialEq + Default + 'static {
     fn calc_heuristic(&self) -> f32;
     fn check_game_ending(&self, game_turn: usize) -> bool;
     fn game_winner(&self, game_turn: usize) -> Option<MonteCarloPlayer>; // None if tie
+    fn apply_player_action(
+        &mut self,
+        player: usize,
+        player_action: &impl MonteCarloPlayerAction,
+    ) -> bool {
+        // action of player with index in 0..N; games with more than two players have to implement
+        // apply_player_action() and game_reward()
+        // true if score event, which results in change of heuristic
+        match MonteCarloPlayer::from_index(player) {
+            MonteCarloPlayer::Me => self.apply_my_action(player_action),
+            MonteCarloPlayer::Opp => self.apply_opp_action(player_action),
+        }
+    }
+    fn game_reward(&self, player: usize, game_turn: usize) -> f32 {
+        // reward of player with index in 0..N at game end in [0.0, 1.0]
+        match self.game_winner(game_turn) {
+            Some(winner) if winner.index() == player => 1.0,
+            Some(_) => 0.0,
+            None => 0.5,
+        }
+    }
     fn check_consistency_of_game_data_during_init_root(
         &mut self,
         current_game_state: &Self,
@@ -115,36 +280,71 @@ pub trait MonteCarloGameData: Copy + Clone + PartialEq + Default + 'static {
     ) -> bool;
 }
 
+// serde supports arrays only up to fixed length, therefore rewards of N players are serialized as sequence
+#[cfg(feature = "serde")]
+mod serde_rewards {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        rewards: &[f32; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(rewards.iter())
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[f32; N], D::Error> {
+        let rewards = Vec::<f32>::deserialize(deserializer)?;
+        rewards
+            .try_into()
+            .map_err(|_| serde::de::Error::custom("invalid number of player rewards"))
+    }
+}
+
 // "G" is a trait object for a game data
+// N is number of players; with N != 2 game mode has to be MonteCarloGameMode::RoundRobin
 #[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MonteCarloNode<
     G: MonteCarloGameData,
     A: MonteCarloPlayerAction,
     U: MonteCarloGameDataUpdate,
+    const N: usize = 2,
 > {
     game_data: G,
     player_action: A,
     game_data_update: U,
     node_type: MonteCarloNodeType,
     next_node: MonteCarloNodeType,
-    player: MonteCarloPlayer,
+    player: usize, // index of player, who acts next
     game_turn: usize,
     heuristic: f32,
     alpha: f32,
     beta: f32,
-    wins: f32,
+    #[cfg_attr(feature = "serde", serde(with = "serde_rewards"))]
+    rewards: [f32; N], // sum of playout rewards of each player
     samples: f32,
+    rave_wins: f32, // All Moves As First: rewards of player, who chose action of node, of playouts, in which action was played later on
+    rave_samples: f32,
     parent_samples: f32,
     exploitation_score: f32, // exploitation_score is needed to choose best action and to choose node to exploit
     exploration_score: f32,  // exploration_score is needed to identify nodes for exploration
+    exploration_weight: f32, // decayed weighting_factor used to calc exploration_score
     heuristic_score: f32,
     total_score: f32,
+    prior: f32,         // prior probability of player action, if AlphaZeroConfig is used
+    network_score: f32, // cached value of AlphaZero network as playout score, NaN if not evaluated
     pruned_node: bool,
-    game_end_node: bool, // leave, at which the game ends
+    game_end_node: bool,         // leave, at which the game ends
+    proven_outcome: Option<f32>, // playout score of game end node or minimax of proven children
 }
 
-impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpdate>
-    MonteCarloNode<G, A, U>
+impl<
+        G: MonteCarloGameData,
+        A: MonteCarloPlayerAction,
+        U: MonteCarloGameDataUpdate,
+        const N: usize,
+    > MonteCarloNode<G, A, U, N>
 {
     fn new() -> Self {
         MonteCarloNode {
@@ -153,20 +353,26 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
             game_data_update: U::default(),
             node_type: MonteCarloNodeType::ActionResult,
             next_node: MonteCarloNodeType::ActionResult,
-            player: MonteCarloPlayer::Me,
+            player: MonteCarloPlayer::Me.index(),
             game_turn: 0,
             heuristic: 0.0,
             alpha: f32::INFINITY,
             beta: f32::NEG_INFINITY,
-            wins: 0.0,
+            rewards: [0.0; N],
             samples: f32::NAN,
+            rave_wins: 0.0,
+            rave_samples: 0.0,
             parent_samples: 0.0,
             exploitation_score: 0.0,
             exploration_score: 0.0,
+            exploration_weight: 0.0,
             heuristic_score: 0.0,
             total_score: 0.0,
+            prior: 0.0,
+            network_score: f32::NAN,
             pruned_node: false,
             game_end_node: false,
+            proven_outcome: None,
         }
     }
     fn new_player_action_child(&self, player_action: A) -> Self {
@@ -186,38 +392,67 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
         new_child.node_type = MonteCarloNodeType::GameDataUpdate;
         new_child
     }
+    fn choosing_player(&self) -> usize {
+        // player, who chose action of node; player of node has already switched to next player
+        (self.player + N - 1) % N
+    }
 
     fn calc_heuristic(&mut self, use_heuristic_score: bool) {
         if use_heuristic_score {
             self.heuristic = self.game_data.calc_heuristic();
-            match self.player {
-                MonteCarloPlayer::Me => self.alpha = self.heuristic,
-                MonteCarloPlayer::Opp => self.beta = self.heuristic,
+            // heuristic is from view of me: all other players minimize it
+            if self.player == MonteCarloPlayer::Me.index() {
+                self.alpha = self.heuristic;
+            } else {
+                self.beta = self.heuristic;
             }
         }
     }
-    fn calc_node_score(&mut self, parent_samples: f32, weighting_factor: f32) {
-        if parent_samples != self.parent_samples {
+    fn calc_node_score(
+        &mut self,
+        parent_samples: f32,
+        weighting_factor: f32,
+        exploration_decay: ExplorationDecayPolicy,
+        played_turns: usize,
+        max_number_of_turns: usize,
+        use_rave: bool,
+    ) {
+        let weighting_factor =
+            weighting_factor * exploration_decay.decay_factor(played_turns, max_number_of_turns);
+        // exploration_score has to be updated, if parent_samples or decayed weighting_factor changed
+        if parent_samples != self.parent_samples || weighting_factor != self.exploration_weight {
             self.update_exploration_score(parent_samples, weighting_factor);
         }
-        self.total_score = match self.player {
-            MonteCarloPlayer::Me => {
-                self.exploitation_score + self.exploration_score - self.heuristic_score
-            }
-            MonteCarloPlayer::Opp => {
-                self.exploitation_score + self.exploration_score + self.heuristic_score
-            }
+        let score = if use_rave {
+            let beta = (RAVE_EQUIVALENCE / (3.0 * self.samples + RAVE_EQUIVALENCE)).sqrt();
+            self.calc_rave_score(beta)
+        } else {
+            self.exploitation_score + self.exploration_score
+        };
+        self.total_score = if self.choosing_player() == MonteCarloPlayer::Me.index() {
+            score + self.heuristic_score
+        } else {
+            score - self.heuristic_score
         };
     }
+    pub fn calc_rave_score(&self, beta: f32) -> f32 {
+        // weighted sum of exploitation_score and AMAF score plus exploration_score
+        // beta in [0.0, 1.0]: 0.0 is pure UCT, 1.0 is pure RAVE
+        if self.rave_samples == 0.0 {
+            return self.exploitation_score + self.exploration_score;
+        }
+        let rave_score = self.rave_wins / self.rave_samples;
+        (1.0 - beta) * self.exploitation_score + beta * rave_score + self.exploration_score
+    }
 
     fn check_game_turn(&mut self, game_mode: MonteCarloGameMode) {
         match game_mode {
             MonteCarloGameMode::SameTurnParallel => {
-                if self.player == MonteCarloPlayer::Opp {
+                if self.player == MonteCarloPlayer::Opp.index() {
                     self.game_turn += 1;
                 }
             }
-            MonteCarloGameMode::ByTurns => self.game_turn += 1,
+            MonteCarloGameMode::ByTurns | MonteCarloGameMode::RoundRobin => self.game_turn += 1,
         }
     }
 
@@ -244,11 +479,11 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
         self.samples = 0.0;
         // score_event depends on player action (e.g. scoring points) or end of game
         let mut score_event = self.apply_player_action();
-        self.player = self.player.next_player();
+        self.player = (self.player + 1) % N;
         self.check_game_turn(game_mode);
         match game_mode {
             MonteCarloGameMode::SameTurnParallel => {
-                if self.player == MonteCarloPlayer::Me {
+                if self.player == MonteCarloPlayer::Me.index() {
                     // first check if game ends
                     if self.check_game_ending(max_number_of_turns) {
                         self.calc_heuristic(use_heuristic_score);
@@ -261,7 +496,7 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
                         );
                 }
             }
-            MonteCarloGameMode::ByTurns => {
+            MonteCarloGameMode::ByTurns | MonteCarloGameMode::RoundRobin => {
                 score_event = self.check_game_ending(max_number_of_turns) || score_event;
             }
         }
@@ -285,64 +520,147 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
     }
 
     fn apply_player_action(&mut self) -> bool {
-        match self.player {
-            MonteCarloPlayer::Me => self.game_data.apply_my_action(&self.player_action),
-            MonteCarloPlayer::Opp => self.game_data.apply_opp_action(&self.player_action),
-        }
+        self.game_data
+            .apply_player_action(self.player, &self.player_action)
     }
 
     fn check_game_ending(&mut self, max_number_of_turns: usize) -> bool {
         self.game_end_node = self.game_turn == max_number_of_turns
             || self.game_data.check_game_ending(self.game_turn);
+        if self.game_end_node {
+            self.proven_outcome = Some(self.calc_playout_rewards()[MonteCarloPlayer::Me.index()]);
+        }
         self.game_end_node
     }
 
-    fn calc_playout_score(&self) -> f32 {
-        match self.game_data.game_winner(self.game_turn) {
-            Some(player) => match player {
-                MonteCarloPlayer::Me => 1.0,
-                MonteCarloPlayer::Opp => 0.0,
-            },
-            None => 0.5,
+    fn calc_playout_rewards(&self) -> [f32; N] {
+        std::array::from_fn(|player| self.game_data.game_reward(player, self.game_turn))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn simulate_playout(
+        &self,
+        game_mode: MonteCarloGameMode,
+        max_number_of_turns: usize,
+        max_simulation_depth: Option<usize>,
+        force_update: bool,
+        use_heuristic_score: bool,
+        simulation_policy: Option<&dyn SimulationPolicy<G, A>>,
+        rng: &mut dyn RngCore,
+        start: &Instant,
+        time_out: Duration,
+    ) -> Option<([f32; N], PlayedActions<A>)> {
+        // random playout on copy of node until game ends
+        // actions are chosen by simulation_policy or, if None, uniformly random
+        // node must be prepared for playout, see MonteCarloTreeSearch::prepare_playout()
+        // returns rewards of all players and played actions with acting player
+        // with max_simulation_depth playout is truncated after max_simulation_depth steps and
+        // heuristic of game data is scaled to playout score by logistic function
+        let mut playout = *self;
+        let mut played_actions: PlayedActions<A> = Vec::new();
+        let mut simulation_depth = 0;
+
+        while !playout.game_end_node {
+            if start.elapsed() >= time_out {
+                // return None, if playout cannot finish in time
+                return None;
+            }
+            if max_simulation_depth.is_some_and(|max_depth| simulation_depth >= max_depth) {
+                let heuristic = playout.game_data.calc_heuristic();
+                let playout_score = 1.0 / (1.0 + (-heuristic).exp());
+                return Some((score_to_rewards(playout_score), played_actions));
+            }
+            simulation_depth += 1;
+            match playout.next_node {
+                MonteCarloNodeType::GameDataUpdate => {
+                    // create new game game_data update
+                    let parent_game_data = playout.game_data;
+                    let game_data_update =
+                        U::iter_game_data_updates(&playout.game_data, force_update)
+                            .choose(rng)
+                            .unwrap();
+                    playout = playout.new_game_data_update_child(game_data_update);
+                    playout.apply_game_data_update(&parent_game_data, false);
+                    playout.set_next_node(force_update);
+                }
+                MonteCarloNodeType::ActionResult => {
+                    // set random next action
+                    let parent_game_data = playout.game_data;
+                    let parent_action = playout.player_action;
+                    let player_action = match simulation_policy {
+                        Some(simulation_policy) => simulation_policy.choose_action(
+                            &playout.game_data,
+                            MonteCarloPlayer::from_index(playout.player),
+                            playout.game_turn,
+                            rng,
+                        ),
+                        None => A::iter_player_actions(
+                            &playout.game_data,
+                            playout.player,
+                            playout.game_turn,
+                        )
+                        .choose(rng),
+                    }
+                    .unwrap();
+                    playout = playout.new_player_action_child(player_action);
+                    played_actions.push((playout.player, player_action));
+                    playout.apply_action(
+                        &parent_game_data,
+                        &parent_action,
+                        game_mode,
+                        max_number_of_turns,
+                        use_heuristic_score,
+                    );
+                    playout.set_next_node(force_update);
+                }
+            }
         }
+        Some((playout.calc_playout_rewards(), played_actions))
     }
 
     fn score_playout_result(
         &mut self,
-        playout_score: f32,
+        playout_rewards: &[f32; N],
         samples: f32,
         use_heuristic_score: bool,
     ) {
-        self.wins += playout_score;
+        for (sum, reward) in self.rewards.iter_mut().zip(playout_rewards.iter()) {
+            *sum += reward;
+        }
         self.samples += samples;
-        self.exploitation_score = match self.player {
-            MonteCarloPlayer::Me => 1.0 - self.wins / self.samples,
-            MonteCarloPlayer::Opp => self.wins / self.samples,
-        };
+        // exploitation_score is from view of player, who chose action of node
+        self.exploitation_score = self.rewards[self.choosing_player()] / self.samples;
         if use_heuristic_score {
-            self.heuristic_score = match self.player {
-                MonteCarloPlayer::Me => {
-                    if self.alpha.is_finite() {
-                        self.alpha / self.samples
-                    } else {
-                        0.0
-                    }
-                }
-                MonteCarloPlayer::Opp => {
-                    if self.beta.is_finite() {
-                        self.beta / self.samples
-                    } else {
-                        0.0
-                    }
-                }
+            let alpha_beta = if self.player == MonteCarloPlayer::Me.index() {
+                self.alpha
+            } else {
+                self.beta
+            };
+            self.heuristic_score = if alpha_beta.is_finite() {
+                alpha_beta / self.samples
+            } else {
+                0.0
             };
         }
     }
 
+    fn calc_puct_score(&mut self, parent_samples: f32, c_puct: f32) {
+        // PUCT score of AlphaZero: child without samples has exploitation_score 0.0
+        let samples = if self.samples.is_nan() {
+            0.0
+        } else {
+            self.samples
+        };
+        self.parent_samples = parent_samples;
+        self.exploration_score = c_puct * self.prior * parent_samples.sqrt() / (1.0 + samples);
+        self.total_score = self.exploitation_score + self.exploration_score;
+    }
+
     fn update_exploration_score(&mut self, parent_samples: f32, weighting_factor: f32) {
         self.parent_samples = parent_samples;
+        self.exploration_weight = weighting_factor;
         self.exploration_score =
-            weighting_factor * (self.parent_samples.log10() / self.samples).sqrt();
+            uct_exploration_score(weighting_factor, self.parent_samples, self.samples);
     }
 
     fn update_consistent_node_during_init_phase(
@@ -363,29 +681,46 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
     }
 }
 
+// each player maximizes his own reward. Heuristic is from view of me (player 0), therefore with
+// use_heuristic_score all other players minimize heuristic
 pub struct MonteCarloTreeSearch<
     G: MonteCarloGameData,
     A: MonteCarloPlayerAction,
     U: MonteCarloGameDataUpdate,
+    const N: usize = 2,
 > {
-    tree_root: Rc<TreeNode<MonteCarloNode<G, A, U>>>,
-    keep_root: Option<Rc<TreeNode<MonteCarloNode<G, A, U>>>>,
+    tree_root: Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+    keep_root: Option<Rc<TreeNode<MonteCarloNode<G, A, U, N>>>>,
     root_level: usize,
     game_mode: MonteCarloGameMode,
-    starting_player: MonteCarloPlayer,
+    starting_player: usize,
     played_turns: usize,
     max_number_of_turns: usize,
+    max_simulation_depth: Option<usize>,
     force_update: bool,
     first_turn: bool,
     time_out_first_turn: Duration,
     time_out_successive_turns: Duration,
     weighting_factor: f32,
+    exploration_decay: ExplorationDecayPolicy,
+    expansion_mode: MonteCarloExpansionMode,
+    min_visits_before_expansion: usize,
     use_heuristic_score: bool,
+    use_rave: bool,
+    simulation_policy: Option<Arc<dyn SimulationPolicy<G, A>>>, // shared with workers of expand_tree_parallel()
+    alpha_zero: Option<Arc<AlphaZeroConfig<G, A>>>,
+    rng: RefCell<StdRng>, // random choices of selection and playouts
+    #[cfg(feature = "serde")]
+    search_statistics: Option<search_statistics::SearchStatistics<A>>,
     debug: bool,
 }
 
-impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpdate>
-    MonteCarloTreeSearch<G, A, U>
+impl<
+        G: MonteCarloGameData,
+        A: MonteCarloPlayerAction,
+        U: MonteCarloGameDataUpdate,
+        const N: usize,
+    > MonteCarloTreeSearch<G, A, U, N>
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -399,20 +734,37 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
         debug: bool,
         keep_root: bool,
     ) -> Self {
+        if N < 2 || (N > 2 && game_mode != MonteCarloGameMode::RoundRobin) {
+            panic!(
+                "line {}, game with {} players requires MonteCarloGameMode::RoundRobin",
+                line!(),
+                N
+            );
+        }
         let mut result = MonteCarloTreeSearch {
-            tree_root: TreeNode::seed_root(MonteCarloNode::<G, A, U>::new(), 0),
+            tree_root: TreeNode::seed_root(MonteCarloNode::<G, A, U, N>::new(), 0),
             keep_root: None,
             root_level: 0,
             game_mode,
-            starting_player: MonteCarloPlayer::Me,
+            starting_player: MonteCarloPlayer::Me.index(),
             played_turns: 0,
             max_number_of_turns,
+            max_simulation_depth: None,
             force_update,
             first_turn: true,
             time_out_first_turn,
             time_out_successive_turns,
             weighting_factor, // try starting with 1.0 and find a way to applicate a better value
+            exploration_decay: ExplorationDecayPolicy::Constant,
+            expansion_mode: MonteCarloExpansionMode::TimeBounded,
+            min_visits_before_expansion: 1,
             use_heuristic_score,
+            use_rave: false,
+            simulation_policy: None,
+            alpha_zero: None,
+            rng: RefCell::new(StdRng::from_entropy()),
+            #[cfg(feature = "serde")]
+            search_statistics: None,
             debug,
         };
         if keep_root {
@@ -420,47 +772,98 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
         }
         result
     }
-    pub fn init_root(&mut self, game_data: &G, starting_player: MonteCarloPlayer) -> Instant {
+    pub fn set_max_simulation_depth(&mut self, max_simulation_depth: Option<usize>) {
+        // truncates playouts after max_simulation_depth actions; truncated game state is scored
+        // by heuristic. Default is None: playouts run until game ends
+        self.max_simulation_depth = max_simulation_depth;
+    }
+    pub fn set_exploration_decay(&mut self, exploration_decay: ExplorationDecayPolicy) {
+        // default is ExplorationDecayPolicy::Constant
+        self.exploration_decay = exploration_decay;
+    }
+    pub fn set_use_rave(&mut self, use_rave: bool) {
+        // default is false: children are selected by UCT score only
+        self.use_rave = use_rave;
+    }
+    pub fn set_simulation_policy(&mut self, simulation_policy: Box<dyn SimulationPolicy<G, A>>) {
+        // default is uniform random choice of actions during playout
+        // SimulationPolicy knows only me and opp, therefore it requires a two player game
+        if N != 2 {
+            panic!("line {}, simulation policy requires two players", line!());
+        }
+        self.simulation_policy = Some(Arc::from(simulation_policy));
+    }
+    pub fn set_alpha_zero_config(&mut self, alpha_zero: AlphaZeroConfig<G, A>) {
+        // PolicyValueNetwork knows only me and opp, therefore it requires a two player game
+        if N != 2 {
+            panic!("line {}, AlphaZeroConfig requires two players", line!());
+        }
+        self.alpha_zero = Some(Arc::new(alpha_zero));
+    }
+    pub fn set_seed(&mut self, seed: u64) {
+        // default is random seed. With fixed seed search is reproducible, if it does not depend
+        // on time, e.g. with MonteCarloExpansionMode::IterativeDeepening
+        self.rng = RefCell::new(StdRng::seed_from_u64(seed));
+    }
+    pub fn set_expansion_mode(&mut self, expansion_mode: MonteCarloExpansionMode) {
+        self.expansion_mode = expansion_mode;
+    }
+    pub fn set_min_visits_before_expansion(&mut self, min_visits_before_expansion: usize) {
+        // expansion threshold: leave is played out until it has min_visits_before_expansion samples,
+        // before children are added. This concentrates early playouts on fewer nodes.
+        // default is 1: each leave is played out once before expansion
+        self.min_visits_before_expansion = min_visits_before_expansion;
+    }
+    pub fn init_root(
+        &mut self,
+        game_data: &G,
+        starting_player: impl Into<MultiPlayer<N>>,
+    ) -> Instant {
+        // starting_player is index of starting player from view of me (player 0)
         let start = Instant::now();
         if self.first_turn {
-            self.starting_player = starting_player;
+            self.starting_player = starting_player.into().index();
             // init root with initial game data
             self.tree_root.get_mut_value().game_data = *game_data;
             self.tree_root.get_mut_value().samples = 0.0;
-            if self.game_mode == MonteCarloGameMode::ByTurns
-                && self.starting_player == MonteCarloPlayer::Opp
+            if self.game_mode != MonteCarloGameMode::SameTurnParallel
+                && self.starting_player != MonteCarloPlayer::Me.index()
             {
-                // if opp is starting player, than with turn wise actions opp player already played a turn
-                self.played_turns = 1;
-                self.tree_root.get_mut_value().game_turn = 1;
-                self.tree_root.get_mut_value().player = MonteCarloPlayer::Me;
+                // if other player is starting player, than with turn wise actions all players from
+                // starting player to player N-1 already played a turn
+                self.played_turns = N - self.starting_player;
+                self.tree_root.get_mut_value().game_turn = self.played_turns;
+                self.tree_root.get_mut_value().player = MonteCarloPlayer::Me.index();
             } else {
                 // no action made yet: tree_root represents initial game data
                 self.tree_root.get_mut_value().node_type = MonteCarloNodeType::GameDataUpdate;
-                self.tree_root.get_mut_value().player = starting_player;
+                self.tree_root.get_mut_value().player = self.starting_player;
             }
         } else {
             // search new root node and move tree_root to it
             // root node is one node before next possible node with starting player as node owner
+            // with turn wise actions each other player acts once, each action may be followed by
+            // a game data update
             let (search_turn, end_level) = match self.game_mode {
-                MonteCarloGameMode::SameTurnParallel => (self.played_turns, Some(3)),
-                MonteCarloGameMode::ByTurns => (self.played_turns + 1, Some(2)),
+                MonteCarloGameMode::SameTurnParallel => (self.played_turns, 3),
+                MonteCarloGameMode::ByTurns | MonteCarloGameMode::RoundRobin => {
+                    (self.played_turns + N - 1, 2 * (N - 1))
+                }
             };
-            match self
-                .tree_root
-                .iter_level_order_traversal_with_borders(1, end_level)
-                .find(|(n, _)| {
+            match (1..=end_level)
+                .flat_map(|depth| self.tree_root.iter_nodes_at_depth(depth))
+                .find(|n| {
                     let mut n_value = n.get_mut_value();
                     n_value.game_turn == search_turn
                         && n_value.next_node == MonteCarloNodeType::ActionResult
-                        && n_value.player == MonteCarloPlayer::Me
+                        && n_value.player == MonteCarloPlayer::Me.index()
                         && n_value.update_consistent_node_during_init_phase(
                             game_data,
                             self.played_turns,
                             self.force_update,
                         )
                 }) {
-                Some((new_root, _)) => {
+                Some(new_root) => {
                     self.tree_root = new_root;
                     self.root_level = self.tree_root.get_level();
                 }
@@ -472,47 +875,121 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
                     if self.keep_root.is_some() {
                         panic!("quit since root has been reset.");
                     }
-                    self.tree_root = TreeNode::seed_root(MonteCarloNode::<G, A, U>::new(), 0);
+                    self.tree_root = TreeNode::seed_root(MonteCarloNode::<G, A, U, N>::new(), 0);
                     self.root_level = 0;
                     self.tree_root.get_mut_value().game_data = *game_data;
                     self.tree_root.get_mut_value().samples = 0.0;
-                    self.tree_root.get_mut_value().player = MonteCarloPlayer::Me;
+                    self.tree_root.get_mut_value().player = MonteCarloPlayer::Me.index();
                     self.tree_root.get_mut_value().game_turn = search_turn;
                 }
             }
         }
         start
     }
-    pub fn expand_tree(&mut self, start: Instant) {
-        let time_out = if self.first_turn {
+    fn turn_time_out(&mut self) -> Duration {
+        if self.first_turn {
             self.first_turn = false;
             self.time_out_first_turn
         } else {
             self.time_out_successive_turns
-        };
-        // loop until time out or no more nodes to cycle
+        }
+    }
+    pub fn expand_tree(&mut self, start: Instant) -> bool {
+        // returns true, if outcome of tree_root is proven
+        #[cfg(feature = "serde")]
+        let expansion_start = Instant::now();
+        #[cfg(feature = "serde")]
+        let nodes_before_search = self.tree_root.subtree_size();
+        let time_out = self.turn_time_out();
+        let mut counter = 0;
+        match self.expansion_mode {
+            MonteCarloExpansionMode::TimeBounded => {
+                // loop until time out or no more nodes to cycle
+                while start.elapsed() < time_out && !self.one_cycle(&start, time_out, None, None) {
+                    counter += 1;
+                }
+            }
+            MonteCarloExpansionMode::IterativeDeepening {
+                max_depth,
+                iterations_per_depth,
+            } => {
+                // each pass builds on statistics of previous passes. Therefore best action
+                // chosen by choose_and_execute_actions() comes from deepest completed pass
+                'passes: for depth_limit in 1..=max_depth {
+                    for _ in 0..iterations_per_depth {
+                        if self.one_cycle(&start, Duration::MAX, Some(depth_limit), None) {
+                            break 'passes;
+                        }
+                        counter += 1;
+                    }
+                    if self.debug {
+                        eprintln!("completed pass with depth limit {}", depth_limit);
+                    }
+                }
+            }
+        }
+        if self.debug {
+            eprintln!("number of expand cycles: {}", counter);
+        }
+        #[cfg(feature = "serde")]
+        self.record_search_statistics(counter, nodes_before_search, expansion_start);
+        self.is_root_proven()
+    }
+    pub fn expand_tree_progressive_widening(
+        &mut self,
+        start: Instant,
+        widening_constant: f32,
+        widening_exponent: f32,
+    ) -> bool {
+        // progressive widening for large action spaces: node with n samples has at most
+        // ceil(widening_constant * n^widening_exponent) player action children
+        // actions are tried in order of A::iter_actions(); a new action is added to node, if
+        // number of samples of node raises limit of children
+        // game data update children are always expanded completely
+        #[cfg(feature = "serde")]
+        let expansion_start = Instant::now();
+        #[cfg(feature = "serde")]
+        let nodes_before_search = self.tree_root.subtree_size();
+        let time_out = self.turn_time_out();
+        let widening = Some((widening_constant, widening_exponent));
         let mut counter = 0;
-        while start.elapsed() < time_out && !self.one_cycle(&start, time_out) {
+        while start.elapsed() < time_out && !self.one_cycle(&start, time_out, None, widening) {
             counter += 1;
         }
         if self.debug {
             eprintln!("number of expand cycles: {}", counter);
         }
+        #[cfg(feature = "serde")]
+        self.record_search_statistics(counter, nodes_before_search, expansion_start);
+        self.is_root_proven()
+    }
+    pub fn is_root_proven(&self) -> bool {
+        self.tree_root.get_value().proven_outcome.is_some()
     }
     pub fn choose_and_execute_actions(
         &mut self,
     ) -> (impl MonteCarloGameData, impl MonteCarloPlayerAction) {
-        // my best action is at max exploitation_score
-        let child = self
-            .tree_root
-            .iter_children()
-            .max_by(|x, y| {
-                x.get_value()
-                    .exploitation_score
-                    .partial_cmp(&y.get_value().exploitation_score)
-                    .unwrap()
-            })
-            .unwrap();
+        // if outcome of tree_root is proven, my best action is child with same proven outcome
+        // else my best action is at max exploitation_score
+        let root_proven_outcome = self.tree_root.get_value().proven_outcome;
+        let child = match root_proven_outcome {
+            Some(proven_outcome) => self
+                .tree_root
+                .iter_children()
+                .find(|c| c.get_value().proven_outcome == Some(proven_outcome)),
+            None => None,
+        };
+        let child = child.unwrap_or_else(|| {
+            self.tree_root
+                .iter_children()
+                .max_by(|x, y| {
+                    x.get_value()
+                        .exploitation_score
+                        .partial_cmp(&y.get_value().exploitation_score)
+                        .unwrap()
+                })
+                .unwrap()
+        });
         self.played_turns = child.get_value().game_turn;
         self.tree_root = child.clone();
         self.root_level = self.tree_root.get_level();
@@ -521,15 +998,30 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
         result
     }
 
-    fn one_cycle(&self, start: &Instant, time_out: Duration) -> bool {
-        let selection_node = self.selection(start, time_out);
+    fn one_cycle(
+        &self,
+        start: &Instant,
+        time_out: Duration,
+        depth_limit: Option<usize>,
+        widening: Option<(f32, f32)>,
+    ) -> bool {
+        if self.is_root_proven() {
+            // no need to search any further
+            return true;
+        }
+        let selection_node = self.selection(start, time_out, depth_limit, widening);
         match selection_node {
             Some(selection_node) => {
-                let child_node = self.expansion(selection_node);
-                if let Some((playout_score, backtrack_heuristic)) =
+                let child_node = self.expansion(selection_node, depth_limit, widening);
+                if let Some((playout_rewards, backtrack_heuristic, played_actions)) =
                     self.playout(child_node.clone(), start, time_out)
                 {
-                    self.propagation(child_node, playout_score, backtrack_heuristic)
+                    self.propagate_playout_result(
+                        child_node,
+                        playout_rewards,
+                        backtrack_heuristic,
+                        played_actions,
+                    );
                 }
             }
             None => return true, // no more nodes to simulate in tree or time over
@@ -541,8 +1033,10 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
         &self,
         start: &Instant,
         time_out: Duration,
-    ) -> Option<Rc<TreeNode<MonteCarloNode<G, A, U>>>> {
-        let mut rng = thread_rng();
+        depth_limit: Option<usize>,
+        widening: Option<(f32, f32)>,
+    ) -> Option<Rc<TreeNode<MonteCarloNode<G, A, U, N>>>> {
+        let mut rng = self.rng.borrow_mut();
         // search for node to select
         let mut selection_node = self.tree_root.clone();
         while !selection_node.is_leave() {
@@ -550,6 +1044,10 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
                 // return None, if selection cannot finish in time
                 return None;
             }
+            if self.is_at_depth_limit(&selection_node, depth_limit) {
+                // children beyond depth limit are ignored in this pass
+                return Some(selection_node);
+            }
             // remove inconsistent children, if next_node is GameDataUpdate
             // if consistent child is detected it will be updated
             // if all children removed, return selection_node
@@ -557,24 +1055,51 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
                 return Some(selection_node);
             }
 
+            // with AlphaZeroConfig player action children are selected by PUCT score
+            let alpha_zero = self.alpha_zero.as_ref().filter(|_| {
+                selection_node.get_value().next_node == MonteCarloNodeType::ActionResult
+            });
             // search children without samples
             if let Some(child_without_samples) = selection_node
                 .iter_children()
-                .filter(|c| c.get_value().samples.is_nan())
-                .choose(&mut rng)
+                .filter(|c| alpha_zero.is_none() && c.get_value().samples.is_nan())
+                .choose(&mut *rng)
             {
                 return Some(child_without_samples);
             }
-            selection_node.iter_children().for_each(|c| {
-                c.get_mut_value()
-                    .calc_node_score(selection_node.get_value().samples, self.weighting_factor)
-            });
+            if self.can_widen(&selection_node, widening) {
+                // add next action as new child during expansion
+                return Some(selection_node);
+            }
+            match alpha_zero {
+                Some(alpha_zero) => selection_node.iter_children().for_each(|c| {
+                    c.get_mut_value()
+                        .calc_puct_score(selection_node.get_value().samples, alpha_zero.c_puct)
+                }),
+                None => selection_node.iter_children().for_each(|c| {
+                    c.get_mut_value().calc_node_score(
+                        selection_node.get_value().samples,
+                        self.weighting_factor,
+                        self.exploration_decay,
+                        self.played_turns,
+                        self.max_number_of_turns,
+                        self.use_rave,
+                    )
+                }),
+            }
             let selected_child = selection_node.iter_children().max_by(|a, b| {
                 a.get_value()
                     .total_score
                     .partial_cmp(&b.get_value().total_score)
                     .unwrap()
             });
+            if let Some(child_without_samples) = selected_child
+                .as_ref()
+                .filter(|c| c.get_value().samples.is_nan())
+            {
+                // only possible with PUCT score
+                return Some(child_without_samples.clone());
+            }
             selection_node = match selected_child {
                 Some(child) => {
                     if self.force_update {
@@ -587,9 +1112,10 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
                                 let child_action = child.get_value().player_action;
                                 let apply_player_actions_to_game_data = match self.game_mode {
                                     MonteCarloGameMode::SameTurnParallel => {
-                                        child.get_value().player == MonteCarloPlayer::Me
+                                        child.get_value().player == MonteCarloPlayer::Me.index()
                                     }
-                                    MonteCarloGameMode::ByTurns => true,
+                                    MonteCarloGameMode::ByTurns
+                                    | MonteCarloGameMode::RoundRobin => true,
                                 };
                                 let child_game_data_changed = child
                                     .get_mut_value()
@@ -622,147 +1148,251 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
 
     fn expansion(
         &self,
-        expansion_node: Rc<TreeNode<MonteCarloNode<G, A, U>>>,
-    ) -> Rc<TreeNode<MonteCarloNode<G, A, U>>> {
+        expansion_node: Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+        depth_limit: Option<usize>,
+        widening: Option<(f32, f32)>,
+    ) -> Rc<TreeNode<MonteCarloNode<G, A, U, N>>> {
+        let samples = expansion_node.get_value().samples;
         if expansion_node.get_value().game_end_node
             || (expansion_node.get_level() > self.root_level
-                && expansion_node.get_value().samples.is_nan())
+                && (samples.is_nan() || samples < self.min_visits_before_expansion as f32))
+            || self.is_at_depth_limit(&expansion_node, depth_limit)
         {
             return expansion_node;
         }
 
-        let next_node = expansion_node.get_value().next_node;
+        // without progressive widening only leaves are expanded
+        let num_children = expansion_node.len_children();
+        self.add_children(&expansion_node, widening);
+        expansion_node.get_child(num_children).unwrap()
+    }
+
+    fn add_children(
+        &self,
+        node: &Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+        widening: Option<(f32, f32)>,
+    ) {
+        // children are added in order of U::iter_game_data_updates() or A::iter_player_actions()
+        let num_children = node.len_children();
+        let next_node = node.get_value().next_node;
         match next_node {
             MonteCarloNodeType::GameDataUpdate => {
-                for game_data_update in U::iter_game_data_updates(
-                    &expansion_node.get_value().game_data,
-                    self.force_update,
-                ) {
-                    let new_game_data_update_node = expansion_node
+                for game_data_update in
+                    U::iter_game_data_updates(&node.get_value().game_data, self.force_update)
+                {
+                    let new_game_data_update_node = node
                         .get_value()
                         .new_game_data_update_child(game_data_update);
-                    expansion_node.add_child(new_game_data_update_node, 0);
+                    node.add_child(new_game_data_update_node, 0);
                 }
             }
             MonteCarloNodeType::ActionResult => {
-                for player_action in A::iter_actions(
-                    &expansion_node.get_value().game_data,
-                    expansion_node.get_value().player,
-                    expansion_node.get_value().game_turn,
-                ) {
-                    let new_player_action_node = expansion_node
-                        .get_value()
-                        .new_player_action_child(player_action);
-                    expansion_node.add_child(new_player_action_node, 0);
+                let max_new_children = match widening {
+                    Some(widening) => Self::widening_limit(node.get_value().samples, widening)
+                        .saturating_sub(num_children)
+                        .max(1),
+                    None => usize::MAX,
+                };
+                // with AlphaZeroConfig children are added, when network is evaluated
+                if self.evaluate_network(node).is_none() {
+                    for player_action in A::iter_player_actions(
+                        &node.get_value().game_data,
+                        node.get_value().player,
+                        node.get_value().game_turn,
+                    )
+                    .skip(num_children)
+                    .take(max_new_children)
+                    {
+                        let new_player_action_node =
+                            node.get_value().new_player_action_child(player_action);
+                        node.add_child(new_player_action_node, 0);
+                    }
                 }
             }
         }
-        expansion_node.get_child(0).unwrap()
+    }
+
+    fn widening_limit(samples: f32, widening: (f32, f32)) -> usize {
+        // max number of children of node with samples
+        let (widening_constant, widening_exponent) = widening;
+        let samples = if samples.is_nan() { 0.0 } else { samples };
+        ((widening_constant * samples.powf(widening_exponent)).ceil() as usize).max(1)
+    }
+
+    fn can_widen(
+        &self,
+        node: &Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+        widening: Option<(f32, f32)>,
+    ) -> bool {
+        // true, if limit of children of node has risen and there are untried actions
+        let Some(widening) = widening else {
+            return false;
+        };
+        let node_value = node.get_value();
+        if node_value.next_node != MonteCarloNodeType::ActionResult || node_value.game_end_node {
+            return false;
+        }
+        let num_children = node.len_children();
+        num_children < Self::widening_limit(node_value.samples, widening)
+            && A::iter_player_actions(
+                &node_value.game_data,
+                node_value.player,
+                node_value.game_turn,
+            )
+            .nth(num_children)
+            .is_some()
     }
 
     fn playout(
         &self,
-        playout_node: Rc<TreeNode<MonteCarloNode<G, A, U>>>,
+        playout_node: Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
         start: &Instant,
         time_out: Duration,
-    ) -> Option<(f32, bool)> {
-        if playout_node.get_value().game_end_node {
-            Some((playout_node.get_value().calc_playout_score(), false))
-        } else {
-            let node_type = playout_node.get_value().node_type;
-            let parent = playout_node.get_parent().unwrap();
-            let backtrack_heuristic = match node_type {
-                MonteCarloNodeType::GameDataUpdate => {
-                    if !playout_node
-                        .get_mut_value()
-                        .apply_game_data_update(&parent.get_value().game_data, !self.force_update)
-                    {
-                        // node is inconsistent -> delete this node from parent and search for new child
-                        let index = parent
-                            .iter_children()
-                            .position(|c| *c.get_value() == *playout_node.get_value())
-                            .unwrap();
-                        parent.swap_remove_child(index);
-                        return None;
-                    }
-                    playout_node
-                        .get_mut_value()
-                        .set_next_node(self.force_update);
-                    false
-                }
-                MonteCarloNodeType::ActionResult => {
-                    let parent_action = parent.get_value().player_action;
-                    let backtrack_heuristic = playout_node.get_mut_value().apply_action(
-                        &parent.get_value().game_data,
-                        &parent_action,
-                        self.game_mode,
-                        self.max_number_of_turns,
-                        self.use_heuristic_score,
-                    );
-                    playout_node
-                        .get_mut_value()
-                        .set_next_node(self.force_update);
-                    backtrack_heuristic
-                }
-            };
+    ) -> Option<([f32; N], bool, PlayedActions<A>)> {
+        let backtrack_heuristic = self.prepare_playout(playout_node.clone())?;
+        if let Some(playout_rewards) = self.evaluate_network(&playout_node) {
+            // value of network replaces random playout
+            return Some((playout_rewards, backtrack_heuristic, Vec::new()));
+        }
+        let (playout_rewards, played_actions) = playout_node.get_value().simulate_playout(
+            self.game_mode,
+            self.max_number_of_turns,
+            self.max_simulation_depth,
+            self.force_update,
+            self.use_heuristic_score,
+            self.simulation_policy.as_deref(),
+            &mut *self.rng.borrow_mut(),
+            start,
+            time_out,
+        )?;
+        Some((playout_rewards, backtrack_heuristic, played_actions))
+    }
 
-            let mut rng = thread_rng();
-            let mut playout = *playout_node.get_value();
+    fn evaluate_network(
+        &self,
+        node: &Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+    ) -> Option<[f32; N]> {
+        // AlphaZero: network is evaluated only once per node. Value is cached as network_score of
+        // node, priors are cached as prior of player action children, which are added at evaluation
+        // returns None without AlphaZeroConfig or at game end node, which is scored by playout
+        let alpha_zero = self.alpha_zero.as_ref()?;
+        if node.get_value().game_end_node {
+            return None;
+        }
+        if node.get_value().network_score.is_nan() {
+            let (priors, network_score) = {
+                let node_value = node.get_value();
+                alpha_zero.evaluate(
+                    &node_value.game_data,
+                    MonteCarloPlayer::from_index(node_value.player),
+                    node_value.game_turn,
+                )
+            };
+            node.get_mut_value().network_score = network_score;
+            if node.get_value().next_node == MonteCarloNodeType::ActionResult && node.is_leave() {
+                for player_action in A::iter_player_actions(
+                    &node.get_value().game_data,
+                    node.get_value().player,
+                    node.get_value().game_turn,
+                ) {
+                    let mut new_player_action_node =
+                        node.get_value().new_player_action_child(player_action);
+                    new_player_action_node.prior = priors
+                        .iter()
+                        .find(|(action, _)| *action == player_action)
+                        .map_or(0.0, |(_, prior)| *prior);
+                    node.add_child(new_player_action_node, 0);
+                }
+            }
+        }
+        Some(score_to_rewards(node.get_value().network_score))
+    }
 
-            while !playout.game_end_node {
-                if start.elapsed() >= time_out {
-                    // return tie, if playout cannot finish in time
+    fn prepare_playout(
+        &self,
+        playout_node: Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+    ) -> Option<bool> {
+        // apply game data update or action of playout_node to game data of parent
+        // returns backtrack_heuristic or None, if node is inconsistent and has been removed from tree
+        if playout_node.get_value().game_end_node {
+            return Some(false);
+        }
+        let node_type = playout_node.get_value().node_type;
+        let parent = playout_node.get_parent().unwrap();
+        let backtrack_heuristic = match node_type {
+            // node at depth limit has already been played out: game data is up to date
+            _ if !playout_node.get_value().samples.is_nan() => false,
+            MonteCarloNodeType::GameDataUpdate => {
+                if !playout_node
+                    .get_mut_value()
+                    .apply_game_data_update(&parent.get_value().game_data, !self.force_update)
+                {
+                    // node is inconsistent -> delete this node from parent and search for new child
+                    parent.remove_child_by_value_swap(&playout_node.get_value());
                     return None;
                 }
-                match playout.next_node {
-                    MonteCarloNodeType::GameDataUpdate => {
-                        // create new game game_data update
-                        let parent_game_data = playout.game_data;
-                        let game_data_update =
-                            U::iter_game_data_updates(&playout.game_data, self.force_update)
-                                .choose(&mut rng)
-                                .unwrap();
-                        playout = playout.new_game_data_update_child(game_data_update);
-                        playout.apply_game_data_update(&parent_game_data, false);
-                        playout.set_next_node(self.force_update);
-                    }
-                    MonteCarloNodeType::ActionResult => {
-                        // set random next action
-                        let parent_game_data = playout.game_data;
-                        let parent_action = playout.player_action;
-                        let player_action =
-                            A::iter_actions(&playout.game_data, playout.player, playout.game_turn)
-                                .choose(&mut rng)
-                                .unwrap();
-                        playout = playout.new_player_action_child(player_action);
-                        playout.apply_action(
-                            &parent_game_data,
-                            &parent_action,
-                            self.game_mode,
-                            self.max_number_of_turns,
-                            self.use_heuristic_score,
-                        );
-                        playout.set_next_node(self.force_update);
-                    }
-                }
+                playout_node
+                    .get_mut_value()
+                    .set_next_node(self.force_update);
+                false
+            }
+            MonteCarloNodeType::ActionResult => {
+                let parent_action = parent.get_value().player_action;
+                let backtrack_heuristic = playout_node.get_mut_value().apply_action(
+                    &parent.get_value().game_data,
+                    &parent_action,
+                    self.game_mode,
+                    self.max_number_of_turns,
+                    self.use_heuristic_score,
+                );
+                playout_node
+                    .get_mut_value()
+                    .set_next_node(self.force_update);
+                backtrack_heuristic
             }
-            Some((playout.calc_playout_score(), backtrack_heuristic))
+        };
+        Some(backtrack_heuristic)
+    }
+
+    fn is_at_depth_limit(
+        &self,
+        node: &Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+        depth_limit: Option<usize>,
+    ) -> bool {
+        match depth_limit {
+            Some(depth_limit) => node.get_level() >= self.root_level + depth_limit,
+            None => false,
+        }
+    }
+
+    fn propagate_playout_result(
+        &self,
+        start_node: Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+        playout_rewards: [f32; N],
+        backtrack_heuristic: bool,
+        played_actions: PlayedActions<A>,
+    ) {
+        if self.use_rave {
+            self.rave_propagation(start_node.clone(), &playout_rewards, played_actions);
         }
+        self.propagation(start_node, playout_rewards, backtrack_heuristic);
     }
 
     fn propagation(
         &self,
-        start_node: Rc<TreeNode<MonteCarloNode<G, A, U>>>,
-        mut playout_score: f32,
+        start_node: Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+        mut playout_rewards: [f32; N],
         backtrack_heuristic: bool,
     ) {
         // score playout result and calc new exploitation score for start_node
         start_node.get_mut_value().score_playout_result(
-            playout_score,
+            &playout_rewards,
             1.0,
             self.use_heuristic_score,
         );
-        // backtrack playout_score and heuristic if score event
+        // only ancestors of a newly proven node may become proven
+        let mut check_proven = start_node.get_value().proven_outcome.is_some();
+        // backtrack playout_rewards and heuristic if score event
         for node in start_node
             .iter_back_track()
             .skip(1)
@@ -772,46 +1402,136 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
             if backtrack_heuristic {
                 // ToDo: how to do this with MonteCarloNodeType::GameDataUpdate
                 let player = node.get_value().player;
-                match player {
-                    MonteCarloPlayer::Me => {
-                        let max_beta = node
-                            .iter_children()
-                            .map(|c| c.get_value().beta)
-                            .max_by(|a, b| a.partial_cmp(b).unwrap())
-                            .unwrap();
-                        node.get_mut_value().alpha = max_beta;
-                    }
-                    MonteCarloPlayer::Opp => {
-                        let min_alpha = node
-                            .iter_children()
-                            .map(|c| c.get_value().alpha)
-                            .min_by(|a, b| a.partial_cmp(b).unwrap())
-                            .unwrap();
-                        node.get_mut_value().beta = min_alpha;
-                    }
+                if player == MonteCarloPlayer::Me.index() {
+                    let max_beta = node
+                        .iter_children()
+                        .map(|c| c.get_value().beta)
+                        .max_by(|a, b| a.partial_cmp(b).unwrap())
+                        .unwrap();
+                    node.get_mut_value().alpha = max_beta;
+                } else {
+                    let min_alpha = node
+                        .iter_children()
+                        .map(|c| c.get_value().alpha)
+                        .min_by(|a, b| a.partial_cmp(b).unwrap())
+                        .unwrap();
+                    node.get_mut_value().beta = min_alpha;
                 }
             }
             // do score_playout_result()
             if node.get_value().next_node == MonteCarloNodeType::GameDataUpdate {
                 let num_children = node.len_children() as f32;
-                playout_score /= num_children;
+                playout_rewards
+                    .iter_mut()
+                    .for_each(|reward| *reward /= num_children);
             }
             // score playout result and calc new exploitation score
-            node.get_mut_value()
-                .score_playout_result(playout_score, 1.0, self.use_heuristic_score);
+            node.get_mut_value().score_playout_result(
+                &playout_rewards,
+                1.0,
+                self.use_heuristic_score,
+            );
+            if check_proven {
+                check_proven = self.prove_node(&node);
+            }
+        }
+    }
+
+    fn prove_node(&self, node: &Rc<TreeNode<MonteCarloNode<G, A, U, N>>>) -> bool {
+        // node is proven, if any child is proven best outcome for player of node (me: 1.0, opp: 0.0)
+        // or if all possible actions are expanded and proven: minimax of children
+        // nodes with game data update children are not proven, since updates are random
+        // with SameTurnParallel opp does not know my action, therefore minimax is not valid
+        // minimax of outcome of me is only valid for two players
+        // returns true, if node is newly proven
+        let minimax = match self.game_mode {
+            MonteCarloGameMode::SameTurnParallel => false,
+            MonteCarloGameMode::ByTurns => true,
+            MonteCarloGameMode::RoundRobin => N == 2,
+        };
+        let node_value = node.get_value();
+        if node_value.proven_outcome.is_some()
+            || node_value.next_node != MonteCarloNodeType::ActionResult
+            || !minimax
+        {
+            return false;
+        }
+        let maximize = node_value.player == MonteCarloPlayer::Me.index();
+        let best_outcome = if maximize { 1.0 } else { 0.0 };
+        let children_outcomes: Vec<Option<f32>> = node
+            .iter_children()
+            .map(|c| c.get_value().proven_outcome)
+            .collect();
+        let proven_outcome = if children_outcomes.contains(&Some(best_outcome)) {
+            Some(best_outcome)
+        } else if children_outcomes.iter().all(|o| o.is_some())
+            && A::iter_player_actions(
+                &node_value.game_data,
+                node_value.player,
+                node_value.game_turn,
+            )
+            .nth(children_outcomes.len())
+            .is_none()
+        {
+            children_outcomes.into_iter().flatten().reduce(|a, b| {
+                if maximize {
+                    a.max(b)
+                } else {
+                    a.min(b)
+                }
+            })
+        } else {
+            None
+        };
+        drop(node_value);
+        node.get_mut_value().proven_outcome = proven_outcome;
+        proven_outcome.is_some()
+    }
+
+    fn rave_propagation(
+        &self,
+        start_node: Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+        playout_rewards: &[f32; N],
+        mut played_actions: PlayedActions<A>,
+    ) {
+        // All Moves As First: update RAVE statistics of all children of nodes on path from
+        // start_node to tree_root, if action of child has been played later on by same player
+        // RAVE wins of child are rewards of player, who chose action of child
+        for node in start_node
+            .iter_back_track()
+            .filter(|n| n.get_level() >= self.root_level)
+        {
+            if node.get_value().next_node == MonteCarloNodeType::ActionResult {
+                // children of node are actions of player of node
+                let player = node.get_value().player;
+                for child in node.iter_children() {
+                    let mut child_value = child.get_mut_value();
+                    if played_actions.contains(&(player, child_value.player_action)) {
+                        child_value.rave_wins += playout_rewards[player];
+                        child_value.rave_samples += 1.0;
+                    }
+                }
+            }
+            if node.get_value().node_type == MonteCarloNodeType::ActionResult {
+                // action of node is played later on from view of parent
+                let node_value = node.get_value();
+                played_actions.push((node_value.choosing_player(), node_value.player_action));
+            }
         }
     }
 
     fn reverse_propagation(
         &self,
-        start_node: Rc<TreeNode<MonteCarloNode<G, A, U>>>,
-        mut wins: f32,
+        start_node: Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
+        mut rewards: [f32; N],
         mut samples: f32,
     ) {
-        // remove samples and wins of inconsistent children and calc new exploitation score for start_node
-        start_node
-            .get_mut_value()
-            .score_playout_result(wins, samples, self.use_heuristic_score);
+        // remove samples and rewards of inconsistent children and calc new exploitation score for start_node
+        start_node.get_mut_value().score_playout_result(
+            &rewards,
+            samples,
+            self.use_heuristic_score,
+        );
         for node in start_node
             .iter_back_track()
             .skip(1)
@@ -819,18 +1539,20 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
         {
             if node.get_value().next_node == MonteCarloNodeType::GameDataUpdate {
                 let num_children = node.len_children() as f32;
-                wins /= num_children;
+                rewards
+                    .iter_mut()
+                    .for_each(|reward| *reward /= num_children);
                 samples /= num_children;
             }
-            // remove samples and wins of inconsistent children and calc new exploitation score
+            // remove samples and rewards of inconsistent children and calc new exploitation score
             node.get_mut_value()
-                .score_playout_result(wins, samples, self.use_heuristic_score);
+                .score_playout_result(&rewards, samples, self.use_heuristic_score);
         }
     }
 
     fn remove_inconsistent_children(
         &self,
-        selection_node: Rc<TreeNode<MonteCarloNode<G, A, U>>>,
+        selection_node: Rc<TreeNode<MonteCarloNode<G, A, U, N>>>,
     ) -> bool {
         if self.force_update
             || selection_node.get_value().next_node == MonteCarloNodeType::ActionResult
@@ -840,39 +1562,39 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
         }
 
         let n_children = selection_node.len_children() as f32;
-        let mut index = 0;
         let mut samples = 0.0;
-        let mut wins = 0.0;
+        let mut rewards = [0.0; N];
         let mut inconsistency_detected = false;
-        while index < selection_node.len_children() {
-            let child = selection_node.get_child(index).unwrap();
-            // find child with samples
-            if !child.get_value().samples.is_nan() {
-                samples += child.get_value().samples;
-                wins += child.get_value().wins;
-                let child_game_data_update = child.get_value().game_data_update;
-                if child
-                    .get_mut_value()
-                    .game_data
-                    .check_consistency_of_game_data_update(
-                        &selection_node.get_value().game_data,
-                        &child_game_data_update,
-                        self.played_turns,
-                    )
-                {
-                    index += 1;
-                } else {
-                    selection_node.swap_remove_child(index);
-                    inconsistency_detected = true;
-                }
-            } else {
-                index += 1;
+        // children with samples are checked; inconsistent children are removed by identity
+        let children_with_samples: Vec<Rc<TreeNode<MonteCarloNode<G, A, U, N>>>> = selection_node
+            .iter_children()
+            .filter(|c| !c.get_value().samples.is_nan())
+            .collect();
+        for child in children_with_samples {
+            samples += child.get_value().samples;
+            for (sum, reward) in rewards.iter_mut().zip(child.get_value().rewards.iter()) {
+                *sum += reward;
+            }
+            let child_game_data_update = child.get_value().game_data_update;
+            if !child
+                .get_mut_value()
+                .game_data
+                .check_consistency_of_game_data_update(
+                    &selection_node.get_value().game_data,
+                    &child_game_data_update,
+                    self.played_turns,
+                )
+            {
+                selection_node.swap_remove_child_node(&child);
+                inconsistency_detected = true;
             }
         }
 
         if inconsistency_detected {
             // calc inconsistent playout results
-            wins = -wins / n_children;
+            rewards
+                .iter_mut()
+                .for_each(|reward| *reward = -*reward / n_children);
             samples = -samples / n_children;
 
             let consistent_child_index = selection_node
@@ -882,17 +1604,23 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
                 Some(index) => {
                     // If inconsistent children were removed and a child with samples remains, only
                     // this child can be consistent, while all other children are inconsistent.
-                    // It's wins and samples are valid and thus not removed by reverse_propagation.
-                    wins += selection_node.get_child(index).unwrap().get_value().wins;
-                    samples += selection_node.get_child(index).unwrap().get_value().samples;
-                    self.reverse_propagation(selection_node.clone(), wins, samples);
+                    // It's rewards and samples are valid and thus not removed by reverse_propagation.
+                    let consistent_child = selection_node.get_child(index).unwrap();
+                    for (sum, reward) in rewards
+                        .iter_mut()
+                        .zip(consistent_child.get_value().rewards.iter())
+                    {
+                        *sum += reward;
+                    }
+                    samples += consistent_child.get_value().samples;
+                    self.reverse_propagation(selection_node.clone(), rewards, samples);
                     // remove all other children, since they are inconsistent
                     selection_node.split_off_children(index, true);
                     selection_node.split_off_children(1, false);
                 }
                 None => {
                     // no consistent child with samples left -> remove all children and reset next node
-                    self.reverse_propagation(selection_node.clone(), wins, samples);
+                    self.reverse_propagation(selection_node.clone(), rewards, samples);
                     selection_node.clear_children(0);
                     selection_node
                         .get_mut_value()
@@ -905,12 +1633,257 @@ impl<G: MonteCarloGameData, A: MonteCarloPlayerAction, U: MonteCarloGameDataUpda
     }
 }
 
+impl<
+        G: MonteCarloGameData + Send,
+        A: MonteCarloPlayerAction + Send,
+        U: MonteCarloGameDataUpdate + Send,
+        const N: usize,
+    > MonteCarloTreeSearch<G, A, U, N>
+{
+    pub fn expand_tree_parallel(&mut self, start: Instant, num_threads: usize) -> bool {
+        // root parallelization: each of num_threads worker threads builds its own tree from a copy
+        // of tree_root and runs selection, expansion, playout and propagation on it. Workers send
+        // playout results of each batch of PARALLEL_BATCH_SIZE cycles with path from tree_root to
+        // playout node. Calling thread applies all results of a batch sequentially to tree, which is
+        // therefore only accessed by calling thread. Since each worker propagates only its own
+        // results, no virtual loss is needed to spread workers over tree.
+        // expansion_mode is ignored, parallel expansion is always time bounded
+        // returns true, if outcome of tree_root is proven
+        #[cfg(feature = "serde")]
+        let expansion_start = Instant::now();
+        #[cfg(feature = "serde")]
+        let nodes_before_search = self.tree_root.subtree_size();
+        let time_out = self.turn_time_out();
+        let mut counter = 0;
+        if !self.is_root_proven() {
+            let stop = AtomicBool::new(false);
+            thread::scope(|scope| {
+                let (result_sender, result_receiver) = mpsc::channel::<Vec<WorkerResult<A, N>>>();
+                for _ in 0..num_threads.max(1) {
+                    let result_sender = result_sender.clone();
+                    let stop = &stop;
+                    // copy of tree_root and config of search for worker
+                    let root_value = *self.tree_root.get_value();
+                    let (game_mode, starting_player, played_turns, max_number_of_turns) = (
+                        self.game_mode,
+                        self.starting_player,
+                        self.played_turns,
+                        self.max_number_of_turns,
+                    );
+                    let (max_simulation_depth, force_update, weighting_factor, exploration_decay) = (
+                        self.max_simulation_depth,
+                        self.force_update,
+                        self.weighting_factor,
+                        self.exploration_decay,
+                    );
+                    let (min_visits_before_expansion, use_heuristic_score, use_rave) = (
+                        self.min_visits_before_expansion,
+                        self.use_heuristic_score,
+                        self.use_rave,
+                    );
+                    let (simulation_policy, alpha_zero) =
+                        (self.simulation_policy.clone(), self.alpha_zero.clone());
+                    // seed of worker is drawn from rng of search
+                    let seed: u64 = self.rng.borrow_mut().gen();
+                    scope.spawn(move || {
+                        let worker = MonteCarloTreeSearch {
+                            tree_root: TreeNode::seed_root(root_value, 0),
+                            keep_root: None,
+                            root_level: 0,
+                            game_mode,
+                            starting_player,
+                            played_turns,
+                            max_number_of_turns,
+                            max_simulation_depth,
+                            force_update,
+                            first_turn: false,
+                            time_out_first_turn: time_out,
+                            time_out_successive_turns: time_out,
+                            weighting_factor,
+                            exploration_decay,
+                            expansion_mode: MonteCarloExpansionMode::TimeBounded,
+                            min_visits_before_expansion,
+                            use_heuristic_score,
+                            use_rave,
+                            simulation_policy,
+                            alpha_zero,
+                            rng: RefCell::new(StdRng::seed_from_u64(seed)),
+                            #[cfg(feature = "serde")]
+                            search_statistics: None,
+                            debug: false,
+                        };
+                        let mut worker_finished = false;
+                        while !worker_finished {
+                            let mut batch: Vec<WorkerResult<A, N>> =
+                                Vec::with_capacity(PARALLEL_BATCH_SIZE);
+                            while batch.len() < PARALLEL_BATCH_SIZE {
+                                if stop.load(Ordering::Relaxed)
+                                    || start.elapsed() >= time_out
+                                    || worker.is_root_proven()
+                                {
+                                    worker_finished = true;
+                                    break;
+                                }
+                                let Some(selection_node) =
+                                    worker.selection(&start, time_out, None, None)
+                                else {
+                                    // no more nodes to simulate in tree or time over
+                                    worker_finished = true;
+                                    break;
+                                };
+                                let child_node = worker.expansion(selection_node, None, None);
+                                if let Some((
+                                    playout_rewards,
+                                    backtrack_heuristic,
+                                    played_actions,
+                                )) = worker.playout(child_node.clone(), &start, time_out)
+                                {
+                                    batch.push((
+                                        worker.path_from_root(&child_node),
+                                        playout_rewards,
+                                        backtrack_heuristic,
+                                        played_actions.clone(),
+                                    ));
+                                    worker.propagate_playout_result(
+                                        child_node,
+                                        playout_rewards,
+                                        backtrack_heuristic,
+                                        played_actions,
+                                    );
+                                }
+                            }
+                            if !batch.is_empty() && result_sender.send(batch).is_err() {
+                                break;
+                            }
+                        }
+                    });
+                }
+                // receiving ends, if all workers are finished and their senders are dropped
+                drop(result_sender);
+                for batch in result_receiver {
+                    for (path, playout_rewards, backtrack_heuristic, played_actions) in batch {
+                        if self.apply_worker_result(
+                            &path,
+                            playout_rewards,
+                            backtrack_heuristic,
+                            played_actions,
+                        ) {
+                            counter += 1;
+                        }
+                    }
+                    if self.is_root_proven() {
+                        // no need to search any further
+                        stop.store(true, Ordering::Relaxed);
+                    }
+                }
+            });
+        }
+        if self.debug {
+            eprintln!("number of parallel playouts: {}", counter);
+        }
+        #[cfg(feature = "serde")]
+        self.record_search_statistics(counter, nodes_before_search, expansion_start);
+        self.is_root_proven()
+    }
+
+    fn path_from_root(&self, node: &Rc<TreeNode<MonteCarloNode<G, A, U, N>>>) -> Vec<usize> {
+        // index of each node on path from tree_root to node in order of U::iter_game_data_updates()
+        // or A::iter_player_actions() of its parent. In contrast to index of child in tree this
+        // index does not depend on order, in which children were added or removed
+        let mut path: Vec<usize> = node
+            .iter_back_track()
+            .filter(|n| n.get_level() > self.root_level)
+            .map(|n| {
+                let parent = n.get_parent().unwrap();
+                let parent_value = parent.get_value();
+                let child_value = n.get_value();
+                match parent_value.next_node {
+                    MonteCarloNodeType::GameDataUpdate => {
+                        U::iter_game_data_updates(&parent_value.game_data, self.force_update)
+                            .position(|u| u == child_value.game_data_update)
+                    }
+                    MonteCarloNodeType::ActionResult => A::iter_player_actions(
+                        &parent_value.game_data,
+                        parent_value.player,
+                        parent_value.game_turn,
+                    )
+                    .position(|a| a == child_value.player_action),
+                }
+                .unwrap()
+            })
+            .collect();
+        path.reverse();
+        path
+    }
+
+    fn apply_worker_result(
+        &self,
+        path: &[usize],
+        playout_rewards: [f32; N],
+        backtrack_heuristic: bool,
+        played_actions: PlayedActions<A>,
+    ) -> bool {
+        // follow path from tree_root, add missing children and prepare new nodes like a playout
+        // node. Then propagate playout result of worker.
+        // returns false, if path does not fit to tree, e.g. since inconsistent node was removed
+        let mut node = self.tree_root.clone();
+        for index in path.iter() {
+            if node.is_leave() {
+                if node.get_value().game_end_node {
+                    return false;
+                }
+                self.add_children(&node, None);
+            }
+            let child = {
+                let node_value = node.get_value();
+                match node_value.next_node {
+                    MonteCarloNodeType::GameDataUpdate => {
+                        let Some(game_data_update) =
+                            U::iter_game_data_updates(&node_value.game_data, self.force_update)
+                                .nth(*index)
+                        else {
+                            return false;
+                        };
+                        node.iter_children()
+                            .find(|c| c.get_value().game_data_update == game_data_update)
+                    }
+                    MonteCarloNodeType::ActionResult => {
+                        let Some(player_action) = A::iter_player_actions(
+                            &node_value.game_data,
+                            node_value.player,
+                            node_value.game_turn,
+                        )
+                        .nth(*index) else {
+                            return false;
+                        };
+                        node.iter_children()
+                            .find(|c| c.get_value().player_action == player_action)
+                    }
+                }
+            };
+            let Some(child) = child else {
+                return false;
+            };
+            if child.get_value().samples.is_nan() && self.prepare_playout(child.clone()).is_none() {
+                return false;
+            }
+            node = child;
+        }
+        self.propagate_playout_result(node, playout_rewards, backtrack_heuristic, played_actions);
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::alpha_zero::*;
+    use super::simulation_policy::*;
     use super::*;
+    use crate::my_map_point::MapPoint;
     use crate::my_tic_tac_toe::mcts_tic_tac_toe::*;
     use crate::my_tic_tac_toe::*;
 
+    use std::sync::atomic::AtomicUsize;
     use std::time::Duration;
     const MAX_NUMBER_OF_TURNS: usize = 9;
     const FORCE_UPDATE: bool = true;
@@ -920,6 +1893,930 @@ mod tests {
     const DEBUG: bool = true;
     const KEEP_ROOT: bool = true;
 
+    #[test]
+    fn test_exploration_decay_policy() {
+        let constant = ExplorationDecayPolicy::Constant;
+        assert_eq!(constant.decay_factor(5, MAX_NUMBER_OF_TURNS), 1.0);
+        let linear = ExplorationDecayPolicy::Linear { final_factor: 0.1 };
+        assert_eq!(linear.decay_factor(0, MAX_NUMBER_OF_TURNS), 1.0);
+        assert!((linear.decay_factor(MAX_NUMBER_OF_TURNS, MAX_NUMBER_OF_TURNS) - 0.1).abs() < 1e-6);
+        assert!((linear.decay_factor(20, MAX_NUMBER_OF_TURNS) - 0.1).abs() < 1e-6);
+        let exponential = ExplorationDecayPolicy::Exponential { decay_rate: 0.5 };
+        assert_eq!(exponential.decay_factor(3, MAX_NUMBER_OF_TURNS), 0.125);
+        let custom = ExplorationDecayPolicy::Custom(|t| 1.0 / (t + 1) as f32);
+        assert_eq!(custom.decay_factor(3, MAX_NUMBER_OF_TURNS), 0.25);
+
+        // exploration_score is updated, if only played_turns or weighting_factor change
+        let mut node: MonteCarloNode<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > = MonteCarloNode::new();
+        node.samples = 1.0;
+        node.calc_node_score(10.0, 1.0, exponential, 0, MAX_NUMBER_OF_TURNS, false);
+        assert_eq!(node.exploration_score, 1.0);
+        node.calc_node_score(10.0, 1.0, exponential, 2, MAX_NUMBER_OF_TURNS, false);
+        assert_eq!(node.exploration_score, 0.25);
+        node.calc_node_score(10.0, 2.0, exponential, 2, MAX_NUMBER_OF_TURNS, false);
+        assert_eq!(node.exploration_score, 0.5);
+    }
+
+    #[test]
+    fn test_iterative_deepening_expansion() {
+        let max_depth = 3;
+        let mut mcts_player: MonteCarloTreeSearch<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > = MonteCarloTreeSearch::new(
+            MonteCarloGameMode::ByTurns,
+            MAX_NUMBER_OF_TURNS,
+            FORCE_UPDATE,
+            TIME_OUT_FIRST_TURN,
+            TIME_OUT_SUCCESSIVE_TURNS,
+            WEIGHTING_FACTOR,
+            false,
+            false,
+            KEEP_ROOT,
+        );
+        mcts_player.set_expansion_mode(MonteCarloExpansionMode::IterativeDeepening {
+            max_depth,
+            iterations_per_depth: 100,
+        });
+        let ttt_match = TicTacToeGameData::new();
+        let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+        mcts_player.expand_tree(start);
+        let tree_depth = mcts_player
+            .tree_root
+            .iter_level_order_traversal()
+            .map(|(_, l)| l)
+            .max()
+            .unwrap();
+        assert_eq!(tree_depth, max_depth);
+        assert_eq!(mcts_player.tree_root.get_value().samples, 300.0);
+        let (_, _) = mcts_player.choose_and_execute_actions();
+        assert_eq!(mcts_player.played_turns, 1);
+    }
+
+    #[test]
+    fn test_expand_tree_parallel() {
+        let mut mcts_player: MonteCarloTreeSearch<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > = MonteCarloTreeSearch::new(
+            MonteCarloGameMode::ByTurns,
+            MAX_NUMBER_OF_TURNS,
+            FORCE_UPDATE,
+            Duration::from_millis(200),
+            TIME_OUT_SUCCESSIVE_TURNS,
+            1.4,
+            false,
+            false,
+            false,
+        );
+        // me: (0, 0), (1, 0); opp: (0, 1), (1, 1) -> me wins with (2, 0)
+        let mut ttt_match = TicTacToeGameData::new();
+        ttt_match.set_me(MapPoint::new(0, 0));
+        ttt_match.set_me(MapPoint::new(1, 0));
+        ttt_match.set_opp(MapPoint::new(0, 1));
+        ttt_match.set_opp(MapPoint::new(1, 1));
+        let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+        // search stops, if win of (2, 0) is proven
+        assert!(mcts_player.expand_tree_parallel(start, 4));
+        assert_eq!(mcts_player.tree_root.len_children(), 5);
+        // each applied playout result of workers adds one sample to tree_root and its child on path
+        let root_samples = mcts_player.tree_root.get_value().samples;
+        assert!(root_samples > 0.0);
+        let children_samples: f32 = mcts_player
+            .tree_root
+            .iter_children()
+            .map(|c| c.get_value().samples)
+            .filter(|s| !s.is_nan())
+            .sum();
+        assert_eq!(root_samples, children_samples);
+        let (_, my_action) = mcts_player.choose_and_execute_actions();
+        let my_action = TicTacToePlayerAction::downcast_self(&my_action);
+        assert_eq!(my_action.cell, MapPoint::new(2, 0));
+
+        // time bounded search on empty board: batches of all workers are merged into tree
+        let mut mcts_player: MonteCarloTreeSearch<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > = MonteCarloTreeSearch::new(
+            MonteCarloGameMode::ByTurns,
+            MAX_NUMBER_OF_TURNS,
+            FORCE_UPDATE,
+            Duration::from_millis(200),
+            TIME_OUT_SUCCESSIVE_TURNS,
+            1.4,
+            false,
+            false,
+            false,
+        );
+        let start = mcts_player.init_root(&TicTacToeGameData::new(), MonteCarloPlayer::Me);
+        mcts_player.expand_tree_parallel(start, 4);
+        assert!(start.elapsed() < Duration::from_millis(400));
+        assert_eq!(mcts_player.tree_root.len_children(), 9);
+        let root_samples = mcts_player.tree_root.get_value().samples;
+        assert!(root_samples > (4 * PARALLEL_BATCH_SIZE) as f32);
+        let children_samples: f32 = mcts_player
+            .tree_root
+            .iter_children()
+            .map(|c| c.get_value().samples)
+            .sum();
+        assert_eq!(root_samples, children_samples);
+        // nodes below children of tree_root are added by paths of workers
+        assert!(mcts_player
+            .tree_root
+            .iter_children()
+            .all(|c| c.len_children() == 8));
+    }
+
+    #[test]
+    fn test_proven_outcome() {
+        let new_player = || -> MonteCarloTreeSearch<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > {
+            MonteCarloTreeSearch::new(
+                MonteCarloGameMode::ByTurns,
+                MAX_NUMBER_OF_TURNS,
+                FORCE_UPDATE,
+                Duration::from_secs(10),
+                TIME_OUT_SUCCESSIVE_TURNS,
+                WEIGHTING_FACTOR,
+                false,
+                false,
+                false,
+            )
+        };
+        // me: (0, 0), (1, 0); opp: (0, 1), (1, 1) -> me wins with (2, 0)
+        let mut mcts_player = new_player();
+        let mut ttt_match = TicTacToeGameData::new();
+        ttt_match.set_me(MapPoint::new(0, 0));
+        ttt_match.set_me(MapPoint::new(1, 0));
+        ttt_match.set_opp(MapPoint::new(0, 1));
+        ttt_match.set_opp(MapPoint::new(1, 1));
+        let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+        assert!(mcts_player.expand_tree(start));
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(mcts_player.tree_root.get_value().proven_outcome, Some(1.0));
+        let (_, my_action) = mcts_player.choose_and_execute_actions();
+        let my_action = TicTacToePlayerAction::downcast_self(&my_action);
+        assert_eq!(my_action.cell, MapPoint::new(2, 0));
+
+        // X O X
+        // X O O
+        // O _ _
+        // me at (1, 2) results in tie, me at (2, 2) lets opp win with (1, 2)
+        let mut mcts_player = new_player();
+        let mut ttt_match = TicTacToeGameData::new();
+        ttt_match.set_opp(MapPoint::new(1, 0));
+        ttt_match.set_me(MapPoint::new(0, 0));
+        ttt_match.set_opp(MapPoint::new(1, 1));
+        ttt_match.set_me(MapPoint::new(2, 0));
+        ttt_match.set_opp(MapPoint::new(2, 1));
+        ttt_match.set_me(MapPoint::new(0, 1));
+        ttt_match.set_opp(MapPoint::new(0, 2));
+        let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Opp);
+        assert!(mcts_player.expand_tree(start));
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(mcts_player.tree_root.get_value().proven_outcome, Some(0.5));
+        let (_, my_action) = mcts_player.choose_and_execute_actions();
+        let my_action = TicTacToePlayerAction::downcast_self(&my_action);
+        assert_eq!(my_action.cell, MapPoint::new(1, 2));
+    }
+
+    #[test]
+    fn test_max_simulation_depth() {
+        let mut node: MonteCarloNode<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > = MonteCarloNode::new();
+        // me in center: heuristic is 4.0
+        node.game_data.set_me(MapPoint::new(1, 1));
+        node.player = MonteCarloPlayer::Opp.index();
+        node.game_turn = 1;
+        let start = Instant::now();
+        let playout = |max_simulation_depth| {
+            node.simulate_playout(
+                MonteCarloGameMode::ByTurns,
+                MAX_NUMBER_OF_TURNS,
+                max_simulation_depth,
+                FORCE_UPDATE,
+                false,
+                None,
+                &mut thread_rng(),
+                &start,
+                Duration::MAX,
+            )
+            .unwrap()
+        };
+        let (playout_rewards, played_actions) = playout(Some(0));
+        let heuristic_score = 1.0 / (1.0 + (-4.0_f32).exp());
+        assert_eq!(playout_rewards, [heuristic_score, 1.0 - heuristic_score]);
+        assert!(played_actions.is_empty());
+        // game cannot end before 4 more actions
+        let (playout_rewards, played_actions) = playout(Some(3));
+        assert!((0.0..=1.0).contains(&playout_rewards[0]));
+        assert_eq!(played_actions.len(), 3);
+        let (playout_rewards, _) = playout(None);
+        assert!([[0.0, 1.0], [0.5, 0.5], [1.0, 0.0]].contains(&playout_rewards));
+    }
+
+    #[test]
+    fn test_min_visits_before_expansion() {
+        // number of nodes after fixed number of cycles
+        // since children without samples are always played out first, tree grows level by level
+        let tree_size = |min_visits_before_expansion| {
+            let mut mcts_player: MonteCarloTreeSearch<
+                TicTacToeGameData,
+                TicTacToePlayerAction,
+                TicTacToeGameDataUpdate,
+            > = MonteCarloTreeSearch::new(
+                MonteCarloGameMode::ByTurns,
+                MAX_NUMBER_OF_TURNS,
+                FORCE_UPDATE,
+                TIME_OUT_FIRST_TURN,
+                TIME_OUT_SUCCESSIVE_TURNS,
+                WEIGHTING_FACTOR,
+                false,
+                false,
+                false,
+            );
+            mcts_player.set_min_visits_before_expansion(min_visits_before_expansion);
+            let start = mcts_player.init_root(&TicTacToeGameData::new(), MonteCarloPlayer::Me);
+            for _ in 0..5_000 {
+                mcts_player.one_cycle(&start, Duration::MAX, None, None);
+            }
+            mcts_player.tree_root.subtree_size()
+        };
+        let default_size = tree_size(1);
+        let threshold_size = tree_size(2);
+        // default: level 4 is expanded; threshold 2: only levels 0 to 3 are expanded
+        assert_eq!(threshold_size, 1 + 9 + 9 * 8 + 9 * 8 * 7 + 9 * 8 * 7 * 6);
+        assert!(2 * threshold_size < default_size);
+    }
+
+    #[test]
+    fn test_biased_simulation_policy() {
+        // me: (0, 0), (1, 0); opp: (0, 1), (1, 1) -> me wins with (2, 0), opp wins with (2, 1)
+        let mut ttt_match = TicTacToeGameData::new();
+        ttt_match.set_me(MapPoint::new(0, 0));
+        ttt_match.set_me(MapPoint::new(1, 0));
+        ttt_match.set_opp(MapPoint::new(0, 1));
+        ttt_match.set_opp(MapPoint::new(1, 1));
+        let mut rng = thread_rng();
+        let choose_actions = |policy: &BiasedSimulationPolicy, player, rng: &mut ThreadRng| {
+            (0..100)
+                .map(|_| {
+                    let action: TicTacToePlayerAction =
+                        policy.choose_action(&ttt_match, player, 5, rng).unwrap();
+                    action.cell
+                })
+                .collect::<Vec<MapPoint<X, Y>>>()
+        };
+        let greedy = BiasedSimulationPolicy::new(1.0);
+        assert!(choose_actions(&greedy, MonteCarloPlayer::Me, &mut rng)
+            .iter()
+            .all(|cell| *cell == MapPoint::new(2, 0)));
+        assert!(choose_actions(&greedy, MonteCarloPlayer::Opp, &mut rng)
+            .iter()
+            .all(|cell| *cell == MapPoint::new(2, 1)));
+        let uniform = BiasedSimulationPolicy::new(1_000_000.0);
+        let mut cells = choose_actions(&uniform, MonteCarloPlayer::Me, &mut rng);
+        cells.sort_by_key(|c| (c.x(), c.y()));
+        cells.dedup();
+        assert!(cells.len() > 1);
+
+        // policy is used by playouts of search
+        let mut mcts_player: MonteCarloTreeSearch<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > = MonteCarloTreeSearch::new(
+            MonteCarloGameMode::ByTurns,
+            MAX_NUMBER_OF_TURNS,
+            FORCE_UPDATE,
+            TIME_OUT_FIRST_TURN,
+            TIME_OUT_SUCCESSIVE_TURNS,
+            WEIGHTING_FACTOR,
+            false,
+            false,
+            false,
+        );
+        mcts_player.set_simulation_policy(Box::new(BiasedSimulationPolicy::new(1.0)));
+        mcts_player.set_expansion_mode(MonteCarloExpansionMode::IterativeDeepening {
+            max_depth: 1,
+            iterations_per_depth: 100,
+        });
+        let start = mcts_player.init_root(&TicTacToeGameData::new(), MonteCarloPlayer::Me);
+        mcts_player.expand_tree(start);
+        assert_eq!(mcts_player.tree_root.get_value().samples, 100.0);
+    }
+
+    #[test]
+    fn test_calc_rave_score() {
+        let mut node: MonteCarloNode<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > = MonteCarloNode::new();
+        node.player = MonteCarloPlayer::Opp.index();
+        node.exploitation_score = 0.5;
+        node.exploration_score = 0.25;
+        // without RAVE samples score is pure UCT
+        assert_eq!(node.calc_rave_score(0.5), 0.75);
+        node.rave_wins = 3.0;
+        node.rave_samples = 4.0;
+        assert_eq!(node.calc_rave_score(0.0), 0.75);
+        assert_eq!(node.calc_rave_score(1.0), 1.0);
+        assert_eq!(node.calc_rave_score(0.5), 0.875);
+        // RAVE wins are rewards of player, who chose action of node
+        node.player = MonteCarloPlayer::Me.index();
+        assert_eq!(node.calc_rave_score(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_rave() {
+        let mut mcts_player: MonteCarloTreeSearch<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > = MonteCarloTreeSearch::new(
+            MonteCarloGameMode::ByTurns,
+            MAX_NUMBER_OF_TURNS,
+            FORCE_UPDATE,
+            TIME_OUT_FIRST_TURN,
+            TIME_OUT_SUCCESSIVE_TURNS,
+            1.4,
+            false,
+            false,
+            false,
+        );
+        mcts_player.set_use_rave(true);
+        mcts_player.set_expansion_mode(MonteCarloExpansionMode::IterativeDeepening {
+            max_depth: 5,
+            iterations_per_depth: 200,
+        });
+        // me: (0, 0), (1, 0); opp: (0, 1), (1, 1) -> me wins with (2, 0)
+        let mut ttt_match = TicTacToeGameData::new();
+        ttt_match.set_me(MapPoint::new(0, 0));
+        ttt_match.set_me(MapPoint::new(1, 0));
+        ttt_match.set_opp(MapPoint::new(0, 1));
+        ttt_match.set_opp(MapPoint::new(1, 1));
+        let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+        mcts_player.expand_tree(start);
+        // each playout through a child is also an AMAF sample of this child
+        // search stops, if win of (2, 0) is proven; therefore not all children may have samples
+        for child in mcts_player
+            .tree_root
+            .iter_children()
+            .filter(|c| !c.get_value().samples.is_nan())
+        {
+            let child_value = child.get_value();
+            assert!(child_value.rave_samples >= child_value.samples);
+            assert!(child_value.rave_wins >= child_value.rewards[MonteCarloPlayer::Me.index()]);
+        }
+        let (_, my_action) = mcts_player.choose_and_execute_actions();
+        let my_action = TicTacToePlayerAction::downcast_self(&my_action);
+        assert_eq!(my_action.cell, MapPoint::new(2, 0));
+    }
+
+    #[test]
+    fn test_rave_convergence() {
+        // opp: (0, 0), (1, 0); me: (1, 1) -> me must block with (2, 0)
+        let mut ttt_match = TicTacToeGameData::new();
+        ttt_match.set_opp(MapPoint::new(0, 0));
+        ttt_match.set_opp(MapPoint::new(1, 0));
+        ttt_match.set_me(MapPoint::new(1, 1));
+        // returns last iteration, at which best root move was not (2, 0)
+        let iterations_to_stabilize = |use_rave: bool, seed: u64| -> usize {
+            let mut mcts_player: MonteCarloTreeSearch<
+                TicTacToeGameData,
+                TicTacToePlayerAction,
+                TicTacToeGameDataUpdate,
+            > = MonteCarloTreeSearch::new(
+                MonteCarloGameMode::ByTurns,
+                MAX_NUMBER_OF_TURNS,
+                FORCE_UPDATE,
+                TIME_OUT_FIRST_TURN,
+                TIME_OUT_SUCCESSIVE_TURNS,
+                0.5,
+                false,
+                false,
+                false,
+            );
+            mcts_player.set_use_rave(use_rave);
+            mcts_player.set_seed(seed);
+            let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+            let mut last_wrong_best_move = 0;
+            for iteration in 1..=3_000 {
+                if mcts_player.one_cycle(&start, Duration::MAX, None, None) {
+                    break;
+                }
+                let best_move = mcts_player
+                    .tree_root
+                    .iter_children()
+                    .filter(|c| !c.get_value().samples.is_nan())
+                    .max_by(|x, y| {
+                        x.get_value()
+                            .exploitation_score
+                            .partial_cmp(&y.get_value().exploitation_score)
+                            .unwrap()
+                    })
+                    .map(|c| {
+                        TicTacToePlayerAction::downcast_self(&c.get_value().player_action).cell
+                    })
+                    .unwrap();
+                if best_move != MapPoint::new(2, 0) {
+                    last_wrong_best_move = iteration;
+                }
+            }
+            last_wrong_best_move
+        };
+        // single seeds may favor uct; therefore compare sum over same seeds
+        let (uct_iterations, rave_iterations) = (0..20).fold((0, 0), |(uct, rave), seed| {
+            (
+                uct + iterations_to_stabilize(false, seed),
+                rave + iterations_to_stabilize(true, seed),
+            )
+        });
+        assert!(rave_iterations < uct_iterations);
+    }
+
+    #[test]
+    fn test_progressive_widening() {
+        let widening_constant = 0.3;
+        let widening_exponent = 0.25;
+        let mut mcts_player: MonteCarloTreeSearch<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > = MonteCarloTreeSearch::new(
+            MonteCarloGameMode::ByTurns,
+            MAX_NUMBER_OF_TURNS,
+            FORCE_UPDATE,
+            Duration::from_millis(200),
+            TIME_OUT_SUCCESSIVE_TURNS,
+            1.4,
+            false,
+            false,
+            false,
+        );
+        let ttt_match = TicTacToeGameData::new();
+        let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+        mcts_player.expand_tree_progressive_widening(start, widening_constant, widening_exponent);
+        let widening_limit = |samples: f32| {
+            ((widening_constant * samples.powf(widening_exponent)).ceil() as usize).max(1)
+        };
+        let root_samples = mcts_player.tree_root.get_value().samples;
+        let root_children = mcts_player.tree_root.len_children();
+        assert!(root_children > 1);
+        assert!(root_children < 9);
+        assert!(root_children <= widening_limit(root_samples));
+        for node in mcts_player.tree_root.iter_pre_order_traversal() {
+            if !node.get_value().samples.is_nan() {
+                assert!(node.len_children() <= widening_limit(node.get_value().samples));
+            }
+        }
+        // actions are tried in order of iter_actions()
+        let first_actions: Vec<MapPoint<X, Y>> = mcts_player
+            .tree_root
+            .iter_children()
+            .map(|c| TicTacToePlayerAction::downcast_self(&c.get_value().player_action).cell)
+            .collect();
+        let expected_actions: Vec<MapPoint<X, Y>> = (0..root_children)
+            .map(|i| MapPoint::new(i % X, i / X))
+            .collect();
+        assert_eq!(first_actions, expected_actions);
+    }
+
+    #[test]
+    fn test_alpha_zero() {
+        // counts evaluations of network to check, that each node is evaluated only once
+        struct CountingPVN {
+            evaluations: Arc<AtomicUsize>,
+        }
+        impl PolicyValueNetwork<TicTacToeGameData, TicTacToePlayerAction> for CountingPVN {
+            fn evaluate(
+                &self,
+                game_data: &TicTacToeGameData,
+                player: MonteCarloPlayer,
+                game_turn: usize,
+            ) -> (Vec<(TicTacToePlayerAction, f32)>, f32) {
+                self.evaluations.fetch_add(1, Ordering::Relaxed);
+                UniformPVN {}.evaluate(game_data, player, game_turn)
+            }
+        }
+        let new_player = |evaluations: Arc<AtomicUsize>| {
+            let mut mcts_player: MonteCarloTreeSearch<
+                TicTacToeGameData,
+                TicTacToePlayerAction,
+                TicTacToeGameDataUpdate,
+            > = MonteCarloTreeSearch::new(
+                MonteCarloGameMode::ByTurns,
+                MAX_NUMBER_OF_TURNS,
+                FORCE_UPDATE,
+                TIME_OUT_FIRST_TURN,
+                TIME_OUT_SUCCESSIVE_TURNS,
+                WEIGHTING_FACTOR,
+                false,
+                false,
+                false,
+            );
+            mcts_player.set_alpha_zero_config(AlphaZeroConfig::new(
+                Box::new(CountingPVN { evaluations }),
+                1.5,
+            ));
+            mcts_player
+        };
+        let evaluated_nodes = |mcts_player: &MonteCarloTreeSearch<_, _, _>| -> usize {
+            mcts_player
+                .tree_root
+                .iter_pre_order_traversal()
+                .filter(|n| !n.get_value().network_score.is_nan())
+                .count()
+        };
+        // me: (0, 0), (1, 0); opp: (0, 1), (1, 1) -> me wins with (2, 0)
+        let mut ttt_match = TicTacToeGameData::new();
+        ttt_match.set_me(MapPoint::new(0, 0));
+        ttt_match.set_me(MapPoint::new(1, 0));
+        ttt_match.set_opp(MapPoint::new(0, 1));
+        ttt_match.set_opp(MapPoint::new(1, 1));
+
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let mut mcts_player = new_player(evaluations.clone());
+        mcts_player.set_expansion_mode(MonteCarloExpansionMode::IterativeDeepening {
+            max_depth: 3,
+            iterations_per_depth: 50,
+        });
+        let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+        mcts_player.expand_tree(start);
+        assert_eq!(mcts_player.tree_root.len_children(), 5);
+        for child in mcts_player.tree_root.iter_children() {
+            assert_eq!(child.get_value().prior, 0.2);
+        }
+        assert_eq!(
+            evaluations.load(Ordering::Relaxed),
+            evaluated_nodes(&mcts_player)
+        );
+        let (_, my_action) = mcts_player.choose_and_execute_actions();
+        let my_action = TicTacToePlayerAction::downcast_self(&my_action);
+        assert_eq!(my_action.cell, MapPoint::new(2, 0));
+
+        // parallel expansion uses network instead of playouts in trees of worker threads
+        // calling thread evaluates network only at nodes, to which it adds children
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let mut mcts_player = new_player(evaluations.clone());
+        let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+        assert!(mcts_player.expand_tree_parallel(start, 4));
+        assert!(evaluated_nodes(&mcts_player) > 0);
+        assert!(evaluations.load(Ordering::Relaxed) > evaluated_nodes(&mcts_player));
+        let (_, my_action) = mcts_player.choose_and_execute_actions();
+        let my_action = TicTacToePlayerAction::downcast_self(&my_action);
+        assert_eq!(my_action.cell, MapPoint::new(2, 0));
+    }
+
+    #[test]
+    fn test_multi_player() {
+        let player = MultiPlayer::<3>::new(1);
+        assert_eq!(player.index(), 1);
+        assert_eq!(player.next_player(), MultiPlayer::new(2));
+        assert_eq!(player.next_player().next_player(), MultiPlayer::new(0));
+        let opp: MultiPlayer<3> = MonteCarloPlayer::Opp.into();
+        assert_eq!(opp.index(), MonteCarloPlayer::Opp.index());
+        assert_eq!(MonteCarloPlayer::from_index(1), MonteCarloPlayer::Opp);
+    }
+
+    // Notakto: three players place X on a 3x3 board; player, who completes a line of three X, loses
+    const NOTAKTO_LINES: [[usize; 3]; 8] = [
+        [0, 1, 2],
+        [3, 4, 5],
+        [6, 7, 8],
+        [0, 3, 6],
+        [1, 4, 7],
+        [2, 5, 8],
+        [0, 4, 8],
+        [2, 4, 6],
+    ];
+
+    // each search sees game from view of its own player: player index of search is relative to me
+    #[derive(Copy, Clone, PartialEq, Default)]
+    struct NotaktoGameData {
+        cells: [bool; 9],
+        loser: Option<usize>, // absolute index of player
+        me: usize,            // absolute index of player 0 of search
+    }
+
+    #[derive(Copy, Clone, PartialEq, Default, Debug)]
+    struct NotaktoPlayerAction {
+        cell: usize,
+    }
+
+    impl MonteCarloPlayerAction for NotaktoPlayerAction {
+        fn downcast_self(player_action: &impl MonteCarloPlayerAction) -> &Self {
+            match player_action.as_any().downcast_ref::<Self>() {
+                Some(notakto_pa) => notakto_pa,
+                None => panic!("player_action is not of type NotaktoPlayerAction!"),
+            }
+        }
+        fn iter_actions(
+            game_data: &impl MonteCarloGameData,
+            _player: MonteCarloPlayer,
+            _parent_game_turn: usize,
+        ) -> Box<dyn Iterator<Item = Self> + '_> {
+            let game_data = NotaktoGameData::downcast_self(game_data);
+            Box::new(
+                (0..9)
+                    .filter(|cell| !game_data.cells[*cell])
+                    .map(|cell| NotaktoPlayerAction { cell }),
+            )
+        }
+        fn iter_player_actions(
+            game_data: &impl MonteCarloGameData,
+            _player: usize,
+            parent_game_turn: usize,
+        ) -> Box<dyn Iterator<Item = Self> + '_> {
+            // all players have same actions
+            Self::iter_actions(game_data, MonteCarloPlayer::Me, parent_game_turn)
+        }
+    }
+
+    impl MonteCarloGameData for NotaktoGameData {
+        fn downcast_self(game_data: &impl MonteCarloGameData) -> &Self {
+            match game_data.as_any().downcast_ref::<Self>() {
+                Some(notakto_gd) => notakto_gd,
+                None => panic!("game_data is not of type NotaktoGameData!"),
+            }
+        }
+        fn apply_my_action(&mut self, player_action: &impl MonteCarloPlayerAction) -> bool {
+            self.apply_player_action(MonteCarloPlayer::Me.index(), player_action)
+        }
+        fn apply_opp_action(&mut self, player_action: &impl MonteCarloPlayerAction) -> bool {
+            self.apply_player_action(MonteCarloPlayer::Opp.index(), player_action)
+        }
+        fn apply_player_action(
+            &mut self,
+            player: usize,
+            player_action: &impl MonteCarloPlayerAction,
+        ) -> bool {
+            let cell = NotaktoPlayerAction::downcast_self(player_action).cell;
+            self.cells[cell] = true;
+            if NOTAKTO_LINES
+                .iter()
+                .any(|line| line.contains(&cell) && line.iter().all(|c| self.cells[*c]))
+            {
+                self.loser = Some((self.me + player) % 3);
+            }
+            false
+        }
+        fn simultaneous_player_actions_for_simultaneous_game_data_change(
+            &mut self,
+            _my_action: &impl MonteCarloPlayerAction,
+            _opp_action: &impl MonteCarloPlayerAction,
+        ) {
+            // players act by turns
+        }
+        fn is_game_data_update_required(&self, _force_update: bool) -> bool {
+            false
+        }
+        fn apply_game_data_update(
+            &mut self,
+            _game_data_update: &impl MonteCarloGameDataUpdate,
+            _check_update_consistency: bool,
+        ) -> bool {
+            true
+        }
+        fn calc_heuristic(&self) -> f32 {
+            0.0
+        }
+        fn check_game_ending(&self, _game_turn: usize) -> bool {
+            self.loser.is_some()
+        }
+        fn game_winner(&self, _game_turn: usize) -> Option<MonteCarloPlayer> {
+            // not used, since game_reward() is implemented
+            None
+        }
+        fn game_reward(&self, player: usize, _game_turn: usize) -> f32 {
+            if self.loser == Some((self.me + player) % 3) {
+                0.0
+            } else {
+                1.0
+            }
+        }
+        fn check_consistency_of_game_data_during_init_root(
+            &mut self,
+            _current_game_state: &Self,
+            _played_turns: usize,
+        ) -> bool {
+            true
+        }
+        fn check_consistency_of_game_data_update(
+            &mut self,
+            _current_game_state: &Self,
+            _game_data_update: &impl MonteCarloGameDataUpdate,
+            _played_turns: usize,
+        ) -> bool {
+            true
+        }
+        fn check_consistency_of_action_result(
+            &mut self,
+            _current_game_state: Self,
+            _my_action: &impl MonteCarloPlayerAction,
+            _opp_action: &impl MonteCarloPlayerAction,
+            _played_turns: usize,
+            _apply_player_actions_to_game_data: bool,
+        ) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_notakto() {
+        let mut mcts_players: Vec<
+            MonteCarloTreeSearch<NotaktoGameData, NotaktoPlayerAction, TicTacToeGameDataUpdate, 3>,
+        > = (0..3)
+            .map(|_| {
+                MonteCarloTreeSearch::new(
+                    MonteCarloGameMode::RoundRobin,
+                    MAX_NUMBER_OF_TURNS,
+                    FORCE_UPDATE,
+                    Duration::from_millis(50),
+                    Duration::from_millis(20),
+                    1.4,
+                    false,
+                    false,
+                    false,
+                )
+            })
+            .collect();
+        // game data from view of absolute player
+        let view = |notakto: NotaktoGameData, player: usize| NotaktoGameData {
+            me: player,
+            ..notakto
+        };
+        // X on cells 0, 1 and 3: cells 2 and 6 complete a line
+        let mut notakto = NotaktoGameData::default();
+        for (player, cell) in [0, 1, 3].into_iter().enumerate() {
+            notakto.apply_player_action(player, &NotaktoPlayerAction { cell });
+        }
+        let mut player = MultiPlayer::<3>::new(0);
+        let mut first_turn = true;
+        // play until one player loses
+        while notakto.loser.is_none() {
+            let mcts_player = &mut mcts_players[player.index()];
+            let player_view = view(notakto, player.index());
+            // absolute player 0 starts: relative to player it is player (3 - player) % 3
+            let start =
+                mcts_player.init_root(&player_view, MultiPlayer::new((3 - player.index()) % 3));
+            if player.index() == 0 && !first_turn {
+                // game state after actions of other players is found in tree
+                assert!(mcts_player.tree_root.get_level() > 1);
+            }
+            mcts_player.expand_tree(start);
+            let (game_data, action) = mcts_player.choose_and_execute_actions();
+            let game_data = *NotaktoGameData::downcast_self(&game_data);
+            let action = *NotaktoPlayerAction::downcast_self(&action);
+            let mut expected_game_data = player_view;
+            expected_game_data.apply_player_action(MonteCarloPlayer::Me.index(), &action);
+            assert!(game_data == expected_game_data);
+            // next player is relative player 1
+            assert_eq!(mcts_player.tree_root.get_value().player, 1);
+            if first_turn {
+                // player 0 does not complete a line
+                assert!(action.cell != 2 && action.cell != 6);
+                assert_eq!(game_data.loser, None);
+                first_turn = false;
+            }
+            notakto = game_data;
+            player = player.next_player();
+        }
+        assert!(notakto.cells.iter().filter(|c| **c).count() >= 5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_export_search_stats() {
+        let mut mcts_player: MonteCarloTreeSearch<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > = MonteCarloTreeSearch::new(
+            MonteCarloGameMode::ByTurns,
+            MAX_NUMBER_OF_TURNS,
+            FORCE_UPDATE,
+            TIME_OUT_FIRST_TURN,
+            TIME_OUT_SUCCESSIVE_TURNS,
+            WEIGHTING_FACTOR,
+            false,
+            false,
+            false,
+        );
+        assert!(mcts_player.export_search_stats().is_null());
+        mcts_player.set_expansion_mode(MonteCarloExpansionMode::IterativeDeepening {
+            max_depth: 2,
+            iterations_per_depth: 50,
+        });
+        let ttt_match = TicTacToeGameData::new();
+        let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+        mcts_player.expand_tree(start);
+        let (_, my_action) = mcts_player.choose_and_execute_actions();
+        let my_action = *TicTacToePlayerAction::downcast_self(&my_action);
+        // statistics are still available after tree_root moved to chosen child
+        let stats = mcts_player.export_search_stats();
+        assert_eq!(stats["simulations"], 100);
+        assert_eq!(stats["tree_depth"], 2);
+        assert_eq!(stats["expanded_nodes"], 9 + 9 * 8);
+        assert!(stats["elapsed_ms"].as_f64().unwrap() >= 0.0);
+        let children = stats["children"].as_array().unwrap();
+        assert_eq!(children.len(), 9);
+        let samples: f64 = children
+            .iter()
+            .map(|c| c["samples"].as_f64().unwrap())
+            .sum();
+        assert_eq!(samples, 100.0);
+        let chosen = children
+            .iter()
+            .find(|c| c["action"] == serde_json::json!([my_action.cell.x(), my_action.cell.y()]))
+            .unwrap();
+        let max_exploitation_score = children
+            .iter()
+            .map(|c| c["exploitation_score"].as_f64().unwrap())
+            .fold(f64::MIN, f64::max);
+        assert_eq!(chosen["exploitation_score"], max_exploitation_score);
+
+        // search on kept tree_root only counts nodes added by this search
+        let nodes_before_search = mcts_player.tree_root.subtree_size();
+        assert_eq!(nodes_before_search, 1 + 8);
+        mcts_player.expand_tree(Instant::now());
+        let stats = mcts_player.export_search_stats();
+        assert_eq!(
+            stats["expanded_nodes"],
+            mcts_player.tree_root.subtree_size() - nodes_before_search
+        );
+    }
+
+    #[cfg(feature = "postcard")]
+    #[test]
+    fn test_serialize_tree() {
+        let new_player = || -> MonteCarloTreeSearch<
+            TicTacToeGameData,
+            TicTacToePlayerAction,
+            TicTacToeGameDataUpdate,
+        > {
+            MonteCarloTreeSearch::new(
+                MonteCarloGameMode::ByTurns,
+                MAX_NUMBER_OF_TURNS,
+                FORCE_UPDATE,
+                TIME_OUT_FIRST_TURN,
+                TIME_OUT_SUCCESSIVE_TURNS,
+                WEIGHTING_FACTOR,
+                false,
+                false,
+                false,
+            )
+        };
+        let mut mcts_player = new_player();
+        let mut ttt_match = TicTacToeGameData::new();
+        // play two turns of me and opp
+        for _ in 0..2 {
+            let start = mcts_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+            mcts_player.expand_tree(start);
+            let (game_data, _) = mcts_player.choose_and_execute_actions();
+            ttt_match = *TicTacToeGameData::downcast_self(&game_data);
+            let opp_action = ttt_match.choose_random_next_action().unwrap();
+            ttt_match.apply_opp_action(&opp_action);
+        }
+        let data = mcts_player.serialize_tree();
+        let mut restored_player = new_player();
+        assert!(restored_player
+            .deserialize_tree(&data[..data.len() - 1])
+            .is_err());
+        restored_player.deserialize_tree(&data).unwrap();
+        // nodes above tree_root are pruned
+        assert!(mcts_player.tree_root.get_level() > 0);
+        assert_eq!(restored_player.tree_root.get_level(), 0);
+        assert!(restored_player.tree_root.get_parent().is_none());
+        assert_eq!(
+            restored_player.tree_root.subtree_size(),
+            mcts_player.tree_root.subtree_size()
+        );
+        assert_eq!(restored_player.played_turns, mcts_player.played_turns);
+        assert!(!restored_player.first_turn);
+        assert_eq!(restored_player.serialize_tree(), data);
+        // restored search continues game with tree of previous turns
+        let start = restored_player.init_root(&ttt_match, MonteCarloPlayer::Me);
+        assert!(restored_player.tree_root.get_value().samples > 0.0);
+        restored_player.expand_tree(start);
+        let (game_data, _) = restored_player.choose_and_execute_actions();
+        assert!(*TicTacToeGameData::downcast_self(&game_data) != ttt_match);
+    }
+
     #[test]
     fn test_tree_width_and_depth_opp_first() {
         let use_heuristic_score = false;
@@ -960,14 +2857,14 @@ mod tests {
                     let child_node = child.get_value();
                     let child_action =
                         TicTacToePlayerAction::downcast_self(&child_node.player_action);
-                    eprintln!("({}, {}): w: {:.1}, s: {:.0}, ets: {:.2}, ers: {:.2}, hs: {:.2}, beta: {:.0}", child_action.cell.x(), child_action.cell.y(), child_node.wins, child_node.samples, child_node.exploitation_score, child_node.exploration_score, child_node.heuristic_score, child_node.beta);
+                    eprintln!("({}, {}): w: {:.1}, s: {:.0}, ets: {:.2}, ers: {:.2}, hs: {:.2}, beta: {:.0}", child_action.cell.x(), child_action.cell.y(), child_node.rewards[MonteCarloPlayer::Me.index()], child_node.samples, child_node.exploitation_score, child_node.exploration_score, child_node.heuristic_score, child_node.beta);
                 }
                 eprintln!("opp options:");
                 for child in mcts_player.tree_root.iter_children() {
                     let child_node = child.get_value();
                     let child_action =
                         TicTacToePlayerAction::downcast_self(&child_node.player_action);
-                    eprintln!("({}, {}): w: {:.1}, s: {:.0}, ets: {:.2}, ers: {:.2}, hs: {:.2}, alpha: {:.0}", child_action.cell.x(), child_action.cell.y(), child_node.wins, child_node.samples, child_node.exploitation_score, child_node.exploration_score, child_node.heuristic_score, child_node.alpha);
+                    eprintln!("({}, {}): w: {:.1}, s: {:.0}, ets: {:.2}, ers: {:.2}, hs: {:.2}, alpha: {:.0}", child_action.cell.x(), child_action.cell.y(), child_node.rewards[MonteCarloPlayer::Me.index()], child_node.samples, child_node.exploitation_score, child_node.exploration_score, child_node.heuristic_score, child_node.alpha);
                 }
                 ttt_match = *TicTacToeGameData::downcast_self(&current_game_data);
                 if !ttt_match.check_game_ending(0) {
@@ -1032,7 +2929,7 @@ mod tests {
                     let child_node = child.get_value();
                     let child_action =
                         TicTacToePlayerAction::downcast_self(&child_node.player_action);
-                    eprintln!("({}, {}): w: {:.1}, s: {:.0}, ets: {:.2}, ers: {:.2}, hs: {:.2}, beta: {:.0}", child_action.cell.x(), child_action.cell.y(), child_node.wins, child_node.samples, child_node.exploitation_score, child_node.exploration_score, child_node.heuristic_score, child_node.beta);
+                    eprintln!("({}, {}): w: {:.1}, s: {:.0}, ets: {:.2}, ers: {:.2}, hs: {:.2}, beta: {:.0}", child_action.cell.x(), child_action.cell.y(), child_node.rewards[MonteCarloPlayer::Me.index()], child_node.samples, child_node.exploitation_score, child_node.exploration_score, child_node.heuristic_score, child_node.beta);
                 }
                 ttt_match = *TicTacToeGameData::downcast_self(&current_game_data);
                 if !ttt_match.check_game_ending(0) {
@@ -0,0 +1,63 @@
+use super::*;
+
+// trait for AlphaZero style evaluation of game data, e.g. by a neural network
+// returns prior probability of each action of player and value of game data in [-1.0, 1.0]
+// from view of player (1.0: player wins, -1.0: player loses)
+// Send + Sync, since network is shared by workers of expand_tree_parallel()
+pub trait PolicyValueNetwork<G: MonteCarloGameData, A: MonteCarloPlayerAction>:
+    Send + Sync
+{
+    fn evaluate(
+        &self,
+        game_data: &G,
+        player: MonteCarloPlayer,
+        game_turn: usize,
+    ) -> (Vec<(A, f32)>, f32);
+}
+
+// config of AlphaZero style tree search
+// priors of network replace random choice of children without samples: children are selected by
+// PUCT score exploitation_score + c_puct * prior * sqrt(parent_samples) / (1 + samples)
+// value of network replaces random playouts of expand_tree() and expand_tree_parallel()
+// network is evaluated once per node: value and priors are cached in tree
+pub struct AlphaZeroConfig<G: MonteCarloGameData, A: MonteCarloPlayerAction> {
+    pub network: Box<dyn PolicyValueNetwork<G, A>>,
+    pub c_puct: f32,
+}
+
+impl<G: MonteCarloGameData, A: MonteCarloPlayerAction> AlphaZeroConfig<G, A> {
+    pub fn new(network: Box<dyn PolicyValueNetwork<G, A>>, c_puct: f32) -> Self {
+        AlphaZeroConfig { network, c_puct }
+    }
+    pub(super) fn evaluate(
+        &self,
+        game_data: &G,
+        player: MonteCarloPlayer,
+        game_turn: usize,
+    ) -> (Vec<(A, f32)>, f32) {
+        // priors of network and value of network from view of Me scaled to [0.0, 1.0]
+        // like calc_playout_score()
+        let (priors, value) = self.network.evaluate(game_data, player, game_turn);
+        let playout_score = match player {
+            MonteCarloPlayer::Me => (value + 1.0) / 2.0,
+            MonteCarloPlayer::Opp => (1.0 - value) / 2.0,
+        };
+        (priors, playout_score)
+    }
+}
+
+// dummy network for testing: equal priors for all actions and value 0.0
+pub struct UniformPVN {}
+
+impl<G: MonteCarloGameData, A: MonteCarloPlayerAction> PolicyValueNetwork<G, A> for UniformPVN {
+    fn evaluate(
+        &self,
+        game_data: &G,
+        player: MonteCarloPlayer,
+        game_turn: usize,
+    ) -> (Vec<(A, f32)>, f32) {
+        let actions: Vec<A> = A::iter_actions(game_data, player, game_turn).collect();
+        let prior = 1.0 / actions.len() as f32;
+        (actions.into_iter().map(|a| (a, prior)).collect(), 0.0)
+    }
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+// statistics of last expansion of tree, which are recorded at end of each expand_tree*() call
+// since choose_and_execute_actions() moves tree_root to chosen child, statistics of children
+// of tree_root are copied at recording
+pub(super) struct SearchStatistics<A: MonteCarloPlayerAction> {
+    // (action, wins, samples, exploitation_score, exploration_score, total_score)
+    // wins are rewards of player, who chose action
+    children: Vec<(A, f32, f32, f32, f32, f32)>,
+    expanded_nodes: usize,
+    simulations: usize,
+    tree_depth: usize,
+    elapsed: Duration,
+}
+
+impl<
+        G: MonteCarloGameData,
+        A: MonteCarloPlayerAction,
+        U: MonteCarloGameDataUpdate,
+        const N: usize,
+    > MonteCarloTreeSearch<G, A, U, N>
+{
+    pub(super) fn record_search_statistics(
+        &mut self,
+        simulations: usize,
+        nodes_before_search: usize,
+        expansion_start: Instant,
+    ) {
+        let elapsed = expansion_start.elapsed();
+        let children = self
+            .tree_root
+            .iter_children()
+            .map(|c| {
+                let c = c.get_value();
+                (
+                    c.player_action,
+                    c.rewards[c.choosing_player()],
+                    c.samples,
+                    c.exploitation_score,
+                    c.exploration_score,
+                    c.total_score,
+                )
+            })
+            .collect();
+        let (tree_depth, subtree_size) = self.tree_root.height_and_subtree_size();
+        self.search_statistics = Some(SearchStatistics {
+            children,
+            // tree_root may keep nodes of previous searches, therefore only growth of tree counts;
+            // nodes removed by this search reduce growth
+            expanded_nodes: subtree_size.saturating_sub(nodes_before_search),
+            simulations,
+            tree_depth,
+            elapsed,
+        });
+    }
+}
+
+impl<
+        G: MonteCarloGameData,
+        A: MonteCarloPlayerAction + serde::Serialize,
+        U: MonteCarloGameDataUpdate,
+        const N: usize,
+    > MonteCarloTreeSearch<G, A, U, N>
+{
+    pub fn export_search_stats(&self) -> serde_json::Value {
+        // statistics of last expand_tree*() call; Null if tree has not been expanded yet
+        // scores of children without samples are NaN and therefore exported as Null
+        let Some(statistics) = self.search_statistics.as_ref() else {
+            return serde_json::Value::Null;
+        };
+        let children: Vec<serde_json::Value> = statistics
+            .children
+            .iter()
+            .map(
+                |(action, wins, samples, exploitation_score, exploration_score, total_score)| {
+                    serde_json::json!({
+                        "action": serde_json::to_value(action).unwrap_or(serde_json::Value::Null),
+                        "wins": wins,
+                        "samples": samples,
+                        "exploitation_score": exploitation_score,
+                        "exploration_score": exploration_score,
+                        "total_score": total_score,
+                    })
+                },
+            )
+            .collect();
+        serde_json::json!({
+            "children": children,
+            "expanded_nodes": statistics.expanded_nodes,
+            "simulations": statistics.simulations,
+            "tree_depth": statistics.tree_depth,
+            "elapsed_ms": statistics.elapsed.as_secs_f64() * 1000.0,
+        })
+    }
+}
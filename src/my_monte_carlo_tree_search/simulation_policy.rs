@@ -0,0 +1,74 @@
+use super::*;
+
+// policy to choose actions of player during random playouts
+// Send + Sync, since playouts of expand_tree_parallel() run in worker threads
+pub trait SimulationPolicy<G: MonteCarloGameData, A: MonteCarloPlayerAction>: Send + Sync {
+    fn choose_action(
+        &self,
+        game_data: &G,
+        player: MonteCarloPlayer,
+        game_turn: usize,
+        rng: &mut dyn RngCore,
+    ) -> Option<A>;
+}
+
+// softmax sampling over change of heuristic caused by each action
+// change of heuristic is taken from view of player: me maximizes heuristic, opp minimizes it
+// low temperature prefers best action, high temperature approaches uniform random choice
+pub struct BiasedSimulationPolicy {
+    pub temperature: f32,
+}
+
+impl BiasedSimulationPolicy {
+    pub fn new(temperature: f32) -> Self {
+        BiasedSimulationPolicy { temperature }
+    }
+}
+
+impl<G: MonteCarloGameData, A: MonteCarloPlayerAction> SimulationPolicy<G, A>
+    for BiasedSimulationPolicy
+{
+    fn choose_action(
+        &self,
+        game_data: &G,
+        player: MonteCarloPlayer,
+        game_turn: usize,
+        rng: &mut dyn RngCore,
+    ) -> Option<A> {
+        let heuristic = game_data.calc_heuristic();
+        let deltas: Vec<(A, f32)> = A::iter_actions(game_data, player, game_turn)
+            .map(|action| {
+                let mut candidate = *game_data;
+                let delta = match player {
+                    MonteCarloPlayer::Me => {
+                        candidate.apply_my_action(&action);
+                        candidate.calc_heuristic() - heuristic
+                    }
+                    MonteCarloPlayer::Opp => {
+                        candidate.apply_opp_action(&action);
+                        heuristic - candidate.calc_heuristic()
+                    }
+                };
+                (action, delta)
+            })
+            .collect();
+        // subtract max delta to prevent overflow of exp()
+        let max_delta = deltas
+            .iter()
+            .map(|(_, delta)| *delta)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let weights: Vec<f32> = deltas
+            .iter()
+            .map(|(_, delta)| ((delta - max_delta) / self.temperature).exp())
+            .collect();
+        let mut threshold = rng.gen::<f32>() * weights.iter().sum::<f32>();
+        for ((action, _), weight) in deltas.iter().zip(weights.iter()) {
+            if threshold < *weight {
+                return Some(*action);
+            }
+            threshold -= weight;
+        }
+        // rounding errors
+        deltas.last().map(|(action, _)| *action)
+    }
+}
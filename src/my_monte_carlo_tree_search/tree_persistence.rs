@@ -0,0 +1,39 @@
+use super::*;
+
+// Persistence of search tree between process invocations, e.g. if each turn of a game is a new call of the
+// game server. Only the subtree of tree_root is stored, since all other nodes are unreachable for coming turns.
+// Configuration of search (time outs, exploration decay, AlphaZeroConfig, ...) contains function pointers and
+// trait objects, which cannot be serialized. Therefore the tree is deserialized into a search created with new().
+impl<
+        G: MonteCarloGameData + serde::Serialize + serde::de::DeserializeOwned,
+        A: MonteCarloPlayerAction + serde::Serialize + serde::de::DeserializeOwned,
+        U: MonteCarloGameDataUpdate + serde::Serialize + serde::de::DeserializeOwned,
+        const N: usize,
+    > MonteCarloTreeSearch<G, A, U, N>
+{
+    pub fn serialize_tree(&self) -> Vec<u8> {
+        // search state is followed by tree in pre order
+        let search_state = (self.starting_player, self.played_turns, self.first_turn);
+        let data = postcard::to_allocvec(&search_state).unwrap();
+        postcard::to_extend(&*self.tree_root, data).unwrap()
+    }
+    pub fn deserialize_tree(&mut self, data: &[u8]) -> Result<(), &'static str> {
+        let ((starting_player, played_turns, first_turn), tree_data): (
+            (usize, usize, bool),
+            &[u8],
+        ) = postcard::take_from_bytes(data)
+            .map_err(|_| "invalid search state in serialized tree")?;
+        let mut deserializer = postcard::Deserializer::from_bytes(tree_data);
+        self.tree_root =
+            TreeNode::deserialize_tree(&mut deserializer).map_err(|_| "invalid serialized tree")?;
+        // deserialized tree_root is a new root
+        self.root_level = 0;
+        if self.keep_root.is_some() {
+            self.keep_root = Some(self.tree_root.clone());
+        }
+        self.starting_player = starting_player;
+        self.played_turns = played_turns;
+        self.first_turn = first_turn;
+        Ok(())
+    }
+}
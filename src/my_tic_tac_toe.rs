@@ -12,6 +12,7 @@ pub const Y: usize = X;
 pub const N: usize = X * Y;
 
 #[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TicTacToeStatus {
     #[default]
     Vacant,
@@ -16,6 +16,28 @@ impl TicTacToePlayerAction {
     }
 }
 
+// action is serialized as cell coordinates (x, y)
+#[cfg(feature = "serde")]
+impl serde::Serialize for TicTacToePlayerAction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.cell.x(), self.cell.y()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TicTacToePlayerAction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        let (x, y) = <(usize, usize)>::deserialize(deserializer)?;
+        if x >= X || y >= Y {
+            return Err(D::Error::custom("cell of action is out of range"));
+        }
+        Ok(TicTacToePlayerAction {
+            cell: MapPoint::new(x, y),
+        })
+    }
+}
+
 impl MonteCarloPlayerAction for TicTacToePlayerAction {
     fn downcast_self(player_action: &impl MonteCarloPlayerAction) -> &Self {
         match player_action.as_any().downcast_ref::<Self>() {
@@ -81,6 +103,7 @@ impl<'a> Iterator for IterTicTacToePlayerAction<'a> {
 }
 
 #[derive(Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TicTacToeGameDataUpdate {}
 
 impl MonteCarloGameDataUpdate for TicTacToeGameDataUpdate {
@@ -95,6 +118,30 @@ impl MonteCarloGameDataUpdate for TicTacToeGameDataUpdate {
     }
 }
 
+// game data is serialized as (cells in row major order, status)
+#[cfg(feature = "serde")]
+impl serde::Serialize for TicTacToeGameData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let cells: Vec<&TicTacToeStatus> = self.map.iter().map(|(_, v)| v).collect();
+        (cells, self.status).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TicTacToeGameData {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        let (cells, status) = <(Vec<TicTacToeStatus>, TicTacToeStatus)>::deserialize(deserializer)?;
+        if cells.len() != N {
+            return Err(D::Error::custom("number of cells does not fit to map size"));
+        }
+        Ok(TicTacToeGameData {
+            map: MyMap2D::from_fn(|p| cells[p.y() * X + p.x()]),
+            status,
+        })
+    }
+}
+
 impl TicTacToeGameData {
     pub fn choose_random_next_action(&self) -> Option<TicTacToePlayerAction> {
         let mut rng = thread_rng();
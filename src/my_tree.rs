@@ -1,3 +1,6 @@
+pub mod arc_tree_node;
+
+use std::cell::Cell;
 use std::cell::RefCell;
 use std::cmp::Ordering;
 use std::rc::Rc;
@@ -230,6 +233,37 @@ impl<N: PartialEq> Iterator for LevelOrderTraversal<N> {
     }
 }
 
+struct NodesAtDepth<N> {
+    stack: Vec<(Rc<TreeNode<N>>, usize)>, // nodes to visit with depth relative to start node
+    target_depth: usize,
+}
+
+impl<N: PartialEq> NodesAtDepth<N> {
+    fn new(start_node: Rc<TreeNode<N>>, target_depth: usize) -> Self {
+        NodesAtDepth {
+            stack: vec![(start_node, 0)],
+            target_depth,
+        }
+    }
+}
+
+impl<N: PartialEq> Iterator for NodesAtDepth<N> {
+    type Item = Rc<TreeNode<N>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, depth)) = self.stack.pop() {
+            if depth == self.target_depth {
+                return Some(node);
+            }
+            // push children in reverse order to visit them from left to right
+            for child in node.children.borrow().iter().rev() {
+                self.stack.push((child.clone(), depth + 1));
+            }
+        }
+        None
+    }
+}
+
 struct BackTrack<N> {
     current_node: Rc<TreeNode<N>>,
     finished: bool, // true if iterator finished
@@ -334,9 +368,23 @@ impl<N: PartialEq> Iterator for IterSelf<N> {
     }
 }
 
+// estimated heap memory of a (sub)tree in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryBreakdown {
+    pub node_data: usize,   // TreeNode<N> including value N of each node
+    pub children: usize,    // allocated capacity of children vectors
+    pub rc_overhead: usize, // strong and weak counter of each Rc allocation
+}
+
+impl MemoryBreakdown {
+    pub fn total(&self) -> usize {
+        self.node_data + self.children + self.rc_overhead
+    }
+}
+
 pub struct TreeNode<N> {
     value: RefCell<N>,
-    level: usize,
+    level: Cell<usize>,
     node: RefCell<Weak<TreeNode<N>>>,
     parent: RefCell<Weak<TreeNode<N>>>,
     children: RefCell<Vec<Rc<TreeNode<N>>>>,
@@ -349,7 +397,7 @@ impl<N: PartialEq> TreeNode<N> {
     fn new(value: N, level: usize, children_capacity: usize) -> Rc<TreeNode<N>> {
         let result = Rc::new(TreeNode {
             value: RefCell::new(value),
-            level,
+            level: Cell::new(level),
             node: RefCell::new(Weak::new()), // weak reference on itself!
             parent: RefCell::new(Weak::new()),
             children: RefCell::new(Vec::with_capacity(children_capacity)),
@@ -373,7 +421,7 @@ impl<N: PartialEq> TreeNode<N> {
         match self.iter_children().find(|n| *n.value.borrow() == value) {
             Some(node) => node,
             None => {
-                let child = TreeNode::new(value, self.level + 1, children_capacity);
+                let child = TreeNode::new(value, self.level.get() + 1, children_capacity);
                 *child.parent.borrow_mut() = self.node.borrow().clone();
                 self.children.borrow_mut().push(child.clone());
                 child
@@ -401,7 +449,7 @@ impl<N: PartialEq> TreeNode<N> {
         match self.iter_children().find(|n| *n.value.borrow() == value) {
             Some(node) => node,
             None => {
-                let child = TreeNode::new(value, self.level + 1, children_capacity);
+                let child = TreeNode::new(value, self.level.get() + 1, children_capacity);
                 *child.parent.borrow_mut() = self.node.borrow().clone();
                 let number_of_children = self.children.borrow().len();
                 if index < number_of_children {
@@ -439,7 +487,7 @@ impl<N: PartialEq> TreeNode<N> {
         {
             Some(_) => None, // child already exists
             None => {
-                let child = TreeNode::new(value, self.level + 1, children_capacity);
+                let child = TreeNode::new(value, self.level.get() + 1, children_capacity);
                 *child.parent.borrow_mut() = self.node.borrow().clone();
                 self.children.borrow_mut().push(child.clone());
                 Some(child)
@@ -473,7 +521,7 @@ impl<N: PartialEq> TreeNode<N> {
         {
             Some(_) => None, // child already exists,
             None => {
-                let child = TreeNode::new(value, self.level + 1, children_capacity);
+                let child = TreeNode::new(value, self.level.get() + 1, children_capacity);
                 *child.parent.borrow_mut() = self.node.borrow().clone();
                 let number_of_children = self.children.borrow().len();
                 if index < number_of_children {
@@ -492,6 +540,47 @@ impl<N: PartialEq> TreeNode<N> {
         let result = self.children.borrow_mut().swap_remove(index);
         Some(result)
     }
+    pub fn remove_child_by_value(&self, value: &N) -> Option<Rc<TreeNode<N>>> {
+        // removes first child with value and keeps order of remaining children
+        let index = self
+            .children
+            .borrow()
+            .iter()
+            .position(|c| *c.value.borrow() == *value)?;
+        let child = self.children.borrow_mut().remove(index);
+        child.detach_from_parent();
+        Some(child)
+    }
+    pub fn remove_child_by_value_swap(&self, value: &N) -> Option<Rc<TreeNode<N>>> {
+        // removes first child with value in O(1); last child takes position of removed child
+        let index = self
+            .children
+            .borrow()
+            .iter()
+            .position(|c| *c.value.borrow() == *value)?;
+        let child = self.children.borrow_mut().swap_remove(index);
+        child.detach_from_parent();
+        Some(child)
+    }
+    pub fn swap_remove_child_node(&self, child: &Rc<TreeNode<N>>) -> Option<Rc<TreeNode<N>>> {
+        // removes child by identity in O(1); last child takes position of removed child
+        let index = self
+            .children
+            .borrow()
+            .iter()
+            .position(|c| Rc::ptr_eq(c, child))?;
+        let child = self.children.borrow_mut().swap_remove(index);
+        child.detach_from_parent();
+        Some(child)
+    }
+    fn detach_from_parent(&self) {
+        // removed child becomes root of its own subtree with level 0
+        *self.parent.borrow_mut() = Weak::new();
+        let level = self.get_level();
+        for node in self.iter_pre_order_traversal() {
+            node.level.set(node.get_level() - level);
+        }
+    }
     pub fn split_off_children(&self, at: usize, keep_split_off: bool) {
         let split_off = self.children.borrow_mut().split_off(at);
         if keep_split_off {
@@ -517,7 +606,7 @@ impl<N: PartialEq> TreeNode<N> {
         self.value.borrow_mut()
     }
     pub fn get_level(&self) -> usize {
-        self.level
+        self.level.get()
     }
     pub fn get_self(&self) -> Option<Rc<TreeNode<N>>> {
         self.node.borrow().upgrade().as_ref().cloned()
@@ -544,6 +633,75 @@ impl<N: PartialEq> TreeNode<N> {
             }
         }
     }
+    pub fn least_common_ancestor(
+        a: Rc<TreeNode<N>>,
+        b: Rc<TreeNode<N>>,
+    ) -> Option<Rc<TreeNode<N>>> {
+        // deepest node, which is on back track path of a and b. None, if a and b do not share a root
+        let path_a: Vec<Rc<TreeNode<N>>> = a.iter_back_track().collect();
+        b.iter_back_track()
+            .filter(|n| n.level.get() <= a.level.get())
+            .find(|n| path_a.iter().any(|p| Rc::ptr_eq(p, n)))
+    }
+    pub fn find_path(from: Rc<TreeNode<N>>, to: Rc<TreeNode<N>>) -> Option<Vec<Rc<TreeNode<N>>>> {
+        // path from "from" over least common ancestor to "to", including both end points
+        let lca = TreeNode::least_common_ancestor(from.clone(), to.clone())?;
+        let mut path: Vec<Rc<TreeNode<N>>> = from
+            .iter_back_track()
+            .take_while(|n| !Rc::ptr_eq(n, &lca))
+            .collect();
+        path.push(lca.clone());
+        let mut down_path: Vec<Rc<TreeNode<N>>> = to
+            .iter_back_track()
+            .take_while(|n| !Rc::ptr_eq(n, &lca))
+            .collect();
+        down_path.reverse();
+        path.append(&mut down_path);
+        Some(path)
+    }
+    pub fn swap_subtrees(a: Rc<TreeNode<N>>, b: Rc<TreeNode<N>>) -> Result<(), &'static str> {
+        // a takes position of b in tree and vice versa; levels of both subtrees are updated
+        if !Rc::ptr_eq(&a.get_root(), &b.get_root()) {
+            return Err("nodes are not in same tree");
+        }
+        if a.iter_back_track().any(|n| Rc::ptr_eq(&n, &b))
+            || b.iter_back_track().any(|n| Rc::ptr_eq(&n, &a))
+        {
+            return Err("one node is ancestor of other node");
+        }
+        // since neither node is ancestor of the other, both have a parent
+        let parent_a = a.get_parent().unwrap();
+        let parent_b = b.get_parent().unwrap();
+        let index_a = parent_a
+            .children
+            .borrow()
+            .iter()
+            .position(|c| Rc::ptr_eq(c, &a))
+            .ok_or("node is not child of its parent")?;
+        let index_b = parent_b
+            .children
+            .borrow()
+            .iter()
+            .position(|c| Rc::ptr_eq(c, &b))
+            .ok_or("node is not child of its parent")?;
+        if Rc::ptr_eq(&parent_a, &parent_b) {
+            parent_a.children.borrow_mut().swap(index_a, index_b);
+            return Ok(());
+        }
+        parent_a.children.borrow_mut()[index_a] = b.clone();
+        parent_b.children.borrow_mut()[index_b] = a.clone();
+        *a.parent.borrow_mut() = Rc::downgrade(&parent_b);
+        *b.parent.borrow_mut() = Rc::downgrade(&parent_a);
+        let level_a = a.get_level();
+        let level_b = b.get_level();
+        for node in a.iter_pre_order_traversal() {
+            node.level.set(node.get_level() + level_b - level_a);
+        }
+        for node in b.iter_pre_order_traversal() {
+            node.level.set(node.get_level() + level_a - level_b);
+        }
+        Ok(())
+    }
     pub fn is_root(&self) -> bool {
         self.get_self().unwrap().get_parent().is_none()
     }
@@ -563,15 +721,60 @@ impl<N: PartialEq> TreeNode<N> {
         self.get_root()
             .iter_level_order_traversal()
             .max_by_key(|(_, l)| *l)
-            .map(|(n, l)| (n.level, l))
+            .map(|(n, l)| (n.level.get(), l))
             .unwrap()
     }
+    pub fn height(&self) -> usize {
+        // max depth of subtree relative to this node; 0 for a leave
+        self.height_and_subtree_size().0
+    }
+    pub fn subtree_size(&self) -> usize {
+        // number of nodes in subtree including this node
+        self.height_and_subtree_size().1
+    }
+    pub fn height_and_subtree_size(&self) -> (usize, usize) {
+        // both metrics with a single post order traversal
+        let mut height = 0;
+        let mut subtree_size = 0;
+        for node in self.iter_post_order_traversal() {
+            height = height.max(node.level.get() - self.level.get());
+            subtree_size += 1;
+        }
+        (height, subtree_size)
+    }
+    pub fn memory_usage_bytes(&self) -> usize {
+        self.memory_usage_breakdown().total()
+    }
+    pub fn memory_usage_breakdown(&self) -> MemoryBreakdown {
+        let mut breakdown = MemoryBreakdown::default();
+        for node in self.iter_pre_order_traversal() {
+            breakdown.node_data += std::mem::size_of::<TreeNode<N>>();
+            breakdown.children +=
+                node.children.borrow().capacity() * std::mem::size_of::<Rc<TreeNode<N>>>();
+            breakdown.rc_overhead += 2 * std::mem::size_of::<usize>();
+        }
+        breakdown
+    }
     pub fn iter_self(&self) -> impl Iterator<Item = Rc<TreeNode<N>>> {
         IterSelf::new(self.get_self().unwrap()) // iterator over single node; usefull for functions, which have an iterator as output and you want to be able to iterate over different outcomes
     }
     pub fn iter_children(&self) -> impl Iterator<Item = Rc<TreeNode<N>>> {
         IterChildren::new(self.get_self().unwrap())
     }
+    pub fn iter_siblings(&self) -> impl Iterator<Item = Rc<TreeNode<N>>> {
+        // all children of parent except self; empty if node is root or parent has already been dropped
+        let siblings: Vec<Rc<TreeNode<N>>> = match self.get_parent() {
+            Some(parent) => parent
+                .children
+                .borrow()
+                .iter()
+                .filter(|c| !std::ptr::eq(Rc::as_ptr(c), self))
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        };
+        siblings.into_iter()
+    }
     pub fn iter_back_track(&self) -> impl Iterator<Item = Rc<TreeNode<N>>> {
         BackTrack::new(self.get_self().unwrap())
     }
@@ -585,6 +788,13 @@ impl<N: PartialEq> TreeNode<N> {
     pub fn iter_level_order_traversal(&self) -> impl Iterator<Item = (Rc<TreeNode<N>>, usize)> {
         LevelOrderTraversal::new(self.get_self().unwrap(), 0, None)
     }
+    // only descends to nodes with relative_depth to this node without visiting deeper levels
+    pub fn iter_nodes_at_depth(
+        &self,
+        relative_depth: usize,
+    ) -> impl Iterator<Item = Rc<TreeNode<N>>> {
+        NodesAtDepth::new(self.get_self().unwrap(), relative_depth)
+    }
     pub fn iter_level_order_traversal_with_borders(
         &self,
         start_level: usize,
@@ -594,6 +804,87 @@ impl<N: PartialEq> TreeNode<N> {
     }
 }
 
+impl<N: PartialEq + Clone> TreeNode<N> {
+    pub fn clone_subtree(&self) -> Rc<TreeNode<N>> {
+        // deep copy of subtree starting at self. Copy of self is new root with level 0
+        let new_root = TreeNode::seed_root(self.get_value().clone(), self.len_children());
+        self.clone_children_into(&new_root);
+        new_root
+    }
+    fn clone_children_into(&self, target: &Rc<TreeNode<N>>) {
+        for child in self.children.borrow().iter() {
+            let new_child = TreeNode::new(
+                child.get_value().clone(),
+                target.level.get() + 1,
+                child.len_children(),
+            );
+            *new_child.parent.borrow_mut() = Rc::downgrade(target);
+            target.children.borrow_mut().push(new_child.clone());
+            child.clone_children_into(&new_child);
+        }
+    }
+}
+
+// Serialization of subtree starting at node as flat list of (parent index, value) in pre order.
+// Parent index is None for first node, which becomes root after deserialization.
+#[cfg(feature = "serde")]
+impl<N: PartialEq + serde::Serialize> serde::Serialize for TreeNode<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+        let mut seq = serializer.serialize_seq(Some(self.subtree_size()))?;
+        // in pre order the parent of a node is the last visited node one level above
+        let mut last_index_of_level: Vec<usize> = Vec::new();
+        for (index, node) in self.iter_pre_order_traversal().enumerate() {
+            let relative_level = node.level.get() - self.level.get();
+            let parent_index = match relative_level {
+                0 => None,
+                _ => Some(last_index_of_level[relative_level - 1]),
+            };
+            last_index_of_level.truncate(relative_level);
+            last_index_of_level.push(index);
+            seq.serialize_element(&(parent_index, &*node.get_value()))?;
+        }
+        seq.end()
+    }
+}
+
+// Since a TreeNode references itself, it has to be created inside an Rc. Therefore deserialization is done
+// with deserialize_tree(), which returns the root of the rebuild tree.
+#[cfg(feature = "serde")]
+impl<N: PartialEq + serde::de::DeserializeOwned> TreeNode<N> {
+    pub fn deserialize_tree<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Rc<TreeNode<N>>, D::Error> {
+        use serde::de::Error;
+        use serde::Deserialize;
+        let flat_tree: Vec<(Option<usize>, N)> = Vec::deserialize(deserializer)?;
+        // first pass: create nodes with level
+        let mut nodes: Vec<Rc<TreeNode<N>>> = Vec::with_capacity(flat_tree.len());
+        let mut parents: Vec<Option<usize>> = Vec::with_capacity(flat_tree.len());
+        for (index, (parent_index, value)) in flat_tree.into_iter().enumerate() {
+            let level = match parent_index {
+                None if index == 0 => 0,
+                Some(parent_index) if parent_index < index => nodes[parent_index].level.get() + 1,
+                _ => return Err(D::Error::custom("invalid parent index in serialized tree")),
+            };
+            nodes.push(TreeNode::new(value, level, 0));
+            parents.push(parent_index);
+        }
+        // second pass: link parents and children
+        for (node, parent_index) in nodes.iter().zip(parents) {
+            if let Some(parent_index) = parent_index {
+                let parent = &nodes[parent_index];
+                *node.parent.borrow_mut() = Rc::downgrade(parent);
+                parent.children.borrow_mut().push(node.clone());
+            }
+        }
+        nodes
+            .into_iter()
+            .next()
+            .ok_or_else(|| D::Error::custom("serialized tree is empty"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -736,4 +1027,272 @@ mod tests {
         }
         assert_eq!(*child_b.get_value(), 'X');
     }
+
+    #[test]
+    fn test_memory_usage() {
+        let test_tree = TreeNode::seed_root(0_u64, 4);
+        for i in 1..=4 {
+            test_tree.add_child(i, 0);
+        }
+        let breakdown = test_tree.memory_usage_breakdown();
+        assert_eq!(
+            breakdown.node_data,
+            5 * std::mem::size_of::<TreeNode<u64>>()
+        );
+        assert_eq!(
+            breakdown.children,
+            4 * std::mem::size_of::<Rc<TreeNode<u64>>>()
+        );
+        assert_eq!(breakdown.rc_overhead, 5 * 2 * std::mem::size_of::<usize>());
+        assert_eq!(test_tree.memory_usage_bytes(), breakdown.total());
+        let child = test_tree.get_child(0).unwrap();
+        assert_eq!(
+            child.memory_usage_bytes(),
+            std::mem::size_of::<TreeNode<u64>>() + 2 * std::mem::size_of::<usize>()
+        );
+    }
+
+    #[test]
+    fn test_iter_siblings() {
+        let test_tree = TreeNode::seed_root('A', 3);
+        let child_b = test_tree.add_child('B', 0);
+        test_tree.add_child('C', 0);
+        test_tree.add_child('D', 0);
+        assert_eq!(test_tree.iter_siblings().count(), 0);
+        let siblings: Vec<char> = child_b.iter_siblings().map(|n| *n.get_value()).collect();
+        assert_eq!(siblings, vec!['C', 'D']);
+        // parent has been dropped
+        drop(test_tree);
+        assert_eq!(child_b.iter_siblings().count(), 0);
+    }
+
+    #[test]
+    fn test_least_common_ancestor() {
+        let test_tree = TreeNode::seed_root('A', 2);
+        let child_b = test_tree.add_child('B', 2);
+        let child_c = test_tree.add_child('C', 0);
+        let child_d = child_b.add_child('D', 0);
+        let child_e = child_b.add_child('E', 0);
+        let lca = TreeNode::least_common_ancestor(child_d.clone(), child_e.clone()).unwrap();
+        assert_eq!(*lca.get_value(), 'B');
+        let lca = TreeNode::least_common_ancestor(child_d.clone(), child_c).unwrap();
+        assert_eq!(*lca.get_value(), 'A');
+        let lca = TreeNode::least_common_ancestor(child_b, child_e).unwrap();
+        assert_eq!(*lca.get_value(), 'B');
+        let lca = TreeNode::least_common_ancestor(child_d.clone(), child_d.clone()).unwrap();
+        assert_eq!(*lca.get_value(), 'D');
+        let other_tree = TreeNode::seed_root('A', 0);
+        assert!(TreeNode::least_common_ancestor(child_d, other_tree).is_none());
+    }
+
+    #[test]
+    fn test_clone_subtree() {
+        let test_tree = TreeNode::seed_root('A', 2);
+        let child_b = test_tree.add_child('B', 2);
+        test_tree.add_child('C', 0);
+        child_b.add_child('D', 0);
+        child_b.add_child('E', 0);
+        let clone = child_b.clone_subtree();
+        assert!(clone.is_root());
+        assert_eq!(clone.get_level(), 0);
+        let values: Vec<char> = clone
+            .iter_pre_order_traversal()
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(values, vec!['B', 'D', 'E']);
+        let clone_d = clone.get_child(0).unwrap();
+        assert_eq!(clone_d.get_level(), 1);
+        assert!(Rc::ptr_eq(&clone_d.get_parent().unwrap(), &clone));
+        // modifying clone does not change original tree
+        *clone_d.get_mut_value() = 'X';
+        clone.add_child('F', 0);
+        assert_eq!(*child_b.get_child(0).unwrap().get_value(), 'D');
+        assert_eq!(child_b.len_children(), 2);
+    }
+
+    #[test]
+    fn test_find_path() {
+        let test_tree = TreeNode::seed_root('A', 2);
+        let child_b = test_tree.add_child('B', 2);
+        let child_c = test_tree.add_child('C', 1);
+        let child_d = child_b.add_child('D', 0);
+        let child_f = child_c.add_child('F', 0);
+        let path: Vec<char> = TreeNode::find_path(child_d.clone(), child_f.clone())
+            .unwrap()
+            .iter()
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(path, vec!['D', 'B', 'A', 'C', 'F']);
+        let path: Vec<char> = TreeNode::find_path(test_tree.clone(), child_d.clone())
+            .unwrap()
+            .iter()
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(path, vec!['A', 'B', 'D']);
+        assert_eq!(
+            TreeNode::find_path(child_f.clone(), child_f).unwrap().len(),
+            1
+        );
+        let other_tree = TreeNode::seed_root('A', 0);
+        assert!(TreeNode::find_path(child_d, other_tree).is_none());
+    }
+
+    #[test]
+    fn test_height_and_subtree_size() {
+        let test_tree = TreeNode::seed_root('A', 2);
+        let child_b = test_tree.add_child('B', 2);
+        let child_c = test_tree.add_child('C', 0);
+        let child_d = child_b.add_child('D', 1);
+        child_b.add_child('E', 0);
+        child_d.add_child('F', 0);
+        assert_eq!(test_tree.height(), 3);
+        assert_eq!(test_tree.subtree_size(), 6);
+        assert_eq!(child_b.height(), 2);
+        assert_eq!(child_b.subtree_size(), 4);
+        assert_eq!(child_c.height(), 0);
+        assert_eq!(child_c.subtree_size(), 1);
+        assert_eq!(child_d.height_and_subtree_size(), (1, 2));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        // small game tree with (action, wins, samples) as node value
+        let test_tree = TreeNode::seed_root(('R', 3.0_f32, 5.0_f32), 2);
+        let child_a = test_tree.add_child(('A', 1.0, 3.0), 2);
+        test_tree.add_child(('B', 2.0, 2.0), 0);
+        child_a.add_child(('C', 0.0, 1.0), 0);
+        child_a.add_child(('D', 1.0, 1.0), 0);
+        let json = serde_json::to_string(&*test_tree).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let restored = TreeNode::<(char, f32, f32)>::deserialize_tree(&mut deserializer).unwrap();
+        assert!(restored.is_root());
+        assert_eq!(restored.subtree_size(), 5);
+        for (original, restored) in test_tree
+            .iter_pre_order_traversal()
+            .zip(restored.iter_pre_order_traversal())
+        {
+            assert_eq!(*original.get_value(), *restored.get_value());
+            assert_eq!(original.get_level(), restored.get_level());
+            assert_eq!(original.len_children(), restored.len_children());
+        }
+        let restored_d = restored.get_node(&('D', 1.0, 1.0)).unwrap();
+        assert_eq!(restored_d.get_parent().unwrap().get_value().0, 'A');
+
+        // serializing a subtree creates a new root
+        let json = serde_json::to_string(&*child_a).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let restored = TreeNode::<(char, f32, f32)>::deserialize_tree(&mut deserializer).unwrap();
+        assert_eq!(restored.get_value().0, 'A');
+        assert_eq!(restored.get_level(), 0);
+        assert_eq!(restored.height(), 1);
+
+        // invalid input
+        let mut deserializer = serde_json::Deserializer::from_str("[[1,[\"A\",0.0,0.0]]]");
+        assert!(TreeNode::<(char, f32, f32)>::deserialize_tree(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn test_iter_nodes_at_depth() {
+        let test_tree = TreeNode::seed_root('A', 2);
+        let child_b = test_tree.add_child('B', 2);
+        let child_c = test_tree.add_child('C', 1);
+        child_b.add_child('D', 1).add_child('G', 0);
+        child_b.add_child('E', 0);
+        child_c.add_child('F', 0);
+        for depth in 0..5 {
+            let nodes: Vec<char> = test_tree
+                .iter_nodes_at_depth(depth)
+                .map(|n| *n.get_value())
+                .collect();
+            let level_order: Vec<char> = test_tree
+                .iter_level_order_traversal()
+                .filter(|(_, l)| *l == depth)
+                .map(|(n, _)| *n.get_value())
+                .collect();
+            assert_eq!(nodes, level_order);
+        }
+        let nodes: Vec<char> = child_b
+            .iter_nodes_at_depth(1)
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(nodes, vec!['D', 'E']);
+    }
+
+    #[test]
+    fn test_swap_subtrees() {
+        let test_tree = TreeNode::seed_root('A', 2);
+        let child_b = test_tree.add_child('B', 2);
+        let child_c = test_tree.add_child('C', 1);
+        let child_d = child_b.add_child('D', 1);
+        let child_e = child_b.add_child('E', 0);
+        let child_g = child_d.add_child('G', 0);
+        child_c.add_child('F', 0);
+        assert!(TreeNode::swap_subtrees(child_b.clone(), child_g.clone()).is_err());
+        assert!(TreeNode::swap_subtrees(test_tree.clone(), child_c.clone()).is_err());
+        let other_tree = TreeNode::seed_root('X', 1);
+        let child_y = other_tree.add_child('Y', 0);
+        assert!(TreeNode::swap_subtrees(child_y, child_c.clone()).is_err());
+
+        // siblings
+        TreeNode::swap_subtrees(child_d.clone(), child_e.clone()).unwrap();
+        let values: Vec<char> = test_tree
+            .iter_pre_order_traversal()
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(values, vec!['A', 'B', 'E', 'D', 'G', 'C', 'F']);
+
+        // different levels
+        TreeNode::swap_subtrees(child_d.clone(), child_c.clone()).unwrap();
+        let values: Vec<char> = test_tree
+            .iter_pre_order_traversal()
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(values, vec!['A', 'B', 'E', 'C', 'F', 'D', 'G']);
+        assert_eq!(child_d.get_level(), 1);
+        assert_eq!(child_g.get_level(), 2);
+        assert_eq!(child_c.get_level(), 2);
+        assert!(Rc::ptr_eq(&child_c.get_parent().unwrap(), &child_b));
+        assert!(Rc::ptr_eq(&child_d.get_parent().unwrap(), &test_tree));
+        for (node, level) in test_tree.iter_level_order_traversal() {
+            assert_eq!(node.get_level(), level);
+        }
+    }
+
+    #[test]
+    fn test_remove_child_by_value() {
+        let test_tree = TreeNode::seed_root('A', 4);
+        for value in ['B', 'C', 'D', 'E'] {
+            test_tree.add_child(value, 0);
+        }
+        let removed = test_tree.remove_child_by_value(&'C').unwrap();
+        assert_eq!(*removed.get_value(), 'C');
+        let values: Vec<char> = test_tree.iter_children().map(|n| *n.get_value()).collect();
+        assert_eq!(values, vec!['B', 'D', 'E']);
+        assert!(test_tree.remove_child_by_value(&'C').is_none());
+        let removed = test_tree.remove_child_by_value_swap(&'B').unwrap();
+        assert_eq!(*removed.get_value(), 'B');
+        let values: Vec<char> = test_tree.iter_children().map(|n| *n.get_value()).collect();
+        assert_eq!(values, vec!['E', 'D']);
+        assert!(test_tree.remove_child_by_value_swap(&'X').is_none());
+        // removal by identity ignores value
+        let child_f = test_tree.add_child('F', 0);
+        let other_f = TreeNode::seed_root('F', 0);
+        assert!(test_tree.swap_remove_child_node(&other_f).is_none());
+        let removed = test_tree.swap_remove_child_node(&child_f).unwrap();
+        assert!(Rc::ptr_eq(&removed, &child_f));
+        assert_eq!(test_tree.len_children(), 2);
+        // removed children are detached from tree
+        let child_g = test_tree.add_child('G', 1);
+        let grandchild_h = child_g.add_child('H', 0);
+        let removed = test_tree.remove_child_by_value(&'G').unwrap();
+        assert!(removed.get_parent().is_none());
+        assert_eq!(removed.get_level(), 0);
+        assert_eq!(grandchild_h.get_level(), 1);
+        let removed = test_tree.remove_child_by_value_swap(&'D').unwrap();
+        assert!(removed.get_parent().is_none());
+        assert_eq!(removed.get_level(), 0);
+        assert!(child_f.get_parent().is_none());
+        assert_eq!(child_f.get_level(), 0);
+    }
 }
@@ -0,0 +1,793 @@
+// thread safe variant of TreeNode. Instead of Rc and RefCell ArcTreeNode uses Arc and RwLock.
+// Iterators only hold read guards while collecting children of current node. Therefore
+// concurrent reads are not blocked and the tree can be modified between two calls of next().
+// Mutations of children of a node hold one write guard for check and modification. Mutations,
+// which check or change more than one node (unambiguous children, swap_subtrees), are serialized
+// by tree_lock of root, which is always locked before any children lock.
+
+use super::*;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering as AtomicOrdering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::sync::RwLockReadGuard;
+use std::sync::RwLockWriteGuard;
+use std::sync::Weak as ArcWeak;
+
+struct ArcPreOrderTraversal<N> {
+    stack: Vec<Arc<ArcTreeNode<N>>>,
+}
+
+impl<N: PartialEq> Iterator for ArcPreOrderTraversal<N> {
+    type Item = Arc<ArcTreeNode<N>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        // push children in reverse order to visit them from left to right
+        self.stack
+            .extend(node.children.read().unwrap().iter().rev().cloned());
+        Some(node)
+    }
+}
+
+struct ArcPostOrderTraversal<N> {
+    stack: Vec<(Arc<ArcTreeNode<N>>, bool)>, // node and flag, if children are already on stack
+}
+
+impl<N: PartialEq> Iterator for ArcPostOrderTraversal<N> {
+    type Item = Arc<ArcTreeNode<N>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, children_visited)) = self.stack.pop() {
+            if children_visited || node.is_leave() {
+                return Some(node);
+            }
+            self.stack.push((node.clone(), true));
+            self.stack.extend(
+                node.children
+                    .read()
+                    .unwrap()
+                    .iter()
+                    .rev()
+                    .map(|c| (c.clone(), false)),
+            );
+        }
+        None
+    }
+}
+
+struct ArcLevelOrderTraversal<N> {
+    queue: VecDeque<(Arc<ArcTreeNode<N>>, usize)>,
+}
+
+impl<N: PartialEq> Iterator for ArcLevelOrderTraversal<N> {
+    type Item = (Arc<ArcTreeNode<N>>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, level) = self.queue.pop_front()?;
+        self.queue.extend(
+            node.children
+                .read()
+                .unwrap()
+                .iter()
+                .map(|c| (c.clone(), level + 1)),
+        );
+        Some((node, level))
+    }
+}
+
+struct ArcNodesAtDepth<N> {
+    stack: Vec<(Arc<ArcTreeNode<N>>, usize)>, // nodes to visit with depth relative to start node
+    target_depth: usize,
+}
+
+impl<N: PartialEq> Iterator for ArcNodesAtDepth<N> {
+    type Item = Arc<ArcTreeNode<N>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, depth)) = self.stack.pop() {
+            if depth == self.target_depth {
+                return Some(node);
+            }
+            // push children in reverse order to visit them from left to right
+            self.stack.extend(
+                node.children
+                    .read()
+                    .unwrap()
+                    .iter()
+                    .rev()
+                    .map(|c| (c.clone(), depth + 1)),
+            );
+        }
+        None
+    }
+}
+
+struct ArcBackTrack<N> {
+    next_node: Option<Arc<ArcTreeNode<N>>>,
+}
+
+impl<N: PartialEq> Iterator for ArcBackTrack<N> {
+    type Item = Arc<ArcTreeNode<N>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next_node.take()?;
+        self.next_node = node.get_parent();
+        Some(node)
+    }
+}
+
+pub struct ArcTreeNode<N> {
+    value: RwLock<N>,
+    level: AtomicUsize,
+    node: ArcWeak<ArcTreeNode<N>>, // weak reference on itself!
+    parent: RwLock<ArcWeak<ArcTreeNode<N>>>,
+    children: RwLock<Vec<Arc<ArcTreeNode<N>>>>,
+    tree_lock: Mutex<()>, // only used at root
+}
+
+impl<N: PartialEq> ArcTreeNode<N> {
+    pub fn seed_root(value: N, children_capacity: usize) -> Arc<ArcTreeNode<N>> {
+        ArcTreeNode::new(value, 0, children_capacity)
+    }
+    fn new(value: N, level: usize, children_capacity: usize) -> Arc<ArcTreeNode<N>> {
+        Arc::new_cyclic(|node| ArcTreeNode {
+            value: RwLock::new(value),
+            level: AtomicUsize::new(level),
+            node: node.clone(),
+            parent: RwLock::new(ArcWeak::new()),
+            children: RwLock::new(Vec::with_capacity(children_capacity)),
+            tree_lock: Mutex::new(()),
+        })
+    }
+    fn new_child(&self, value: N, children_capacity: usize) -> Arc<ArcTreeNode<N>> {
+        let child = ArcTreeNode::new(value, self.get_level() + 1, children_capacity);
+        *child.parent.write().unwrap() = self.node.clone();
+        child
+    }
+    fn push_new_child(
+        &self,
+        value: N,
+        index: Option<usize>,
+        children_capacity: usize,
+    ) -> Arc<ArcTreeNode<N>> {
+        let child = self.new_child(value, children_capacity);
+        let mut children = self.children.write().unwrap();
+        match index {
+            Some(index) if index < children.len() => children.insert(index, child.clone()),
+            _ => children.push(child.clone()),
+        }
+        child
+    }
+    fn find_or_push_new_child(
+        &self,
+        value: N,
+        index: Option<usize>,
+        children_capacity: usize,
+    ) -> (Arc<ArcTreeNode<N>>, bool) {
+        // returns existing or new child and true, if child is new
+        // check and insert are done with one write guard, therefore concurrent calls cannot insert
+        // the same value twice
+        let mut children = self.children.write().unwrap();
+        if let Some(node) = children.iter().find(|n| *n.get_value() == value) {
+            return (node.clone(), false);
+        }
+        let child = self.new_child(value, children_capacity);
+        match index {
+            Some(index) if index < children.len() => children.insert(index, child.clone()),
+            _ => children.push(child.clone()),
+        }
+        (child, true)
+    }
+    fn push_new_unambiguous_child(
+        &self,
+        value: N,
+        index: Option<usize>,
+        children_capacity: usize,
+    ) -> Option<Arc<ArcTreeNode<N>>> {
+        // search always from root to make sure that all added children values are checked
+        let root = self.get_root();
+        let _tree_guard = root.tree_lock.lock().unwrap();
+        if root.get_node(&value).is_some() {
+            return None; // child already exists
+        }
+        // children of self may have been changed by add_child() since check of tree
+        match self.find_or_push_new_child(value, index, children_capacity) {
+            (child, true) => Some(child),
+            (_, false) => None,
+        }
+    }
+    pub fn add_child_to_parent(
+        &self,
+        child_value: N,
+        parent_value: &N,
+        children_capacity: usize,
+    ) -> Option<Arc<ArcTreeNode<N>>> {
+        // search always from root to make sure that parent will be found
+        self.get_root()
+            .get_node(parent_value)
+            .map(|parent| parent.add_child(child_value, children_capacity))
+    }
+    pub fn add_child(&self, value: N, children_capacity: usize) -> Arc<ArcTreeNode<N>> {
+        self.find_or_push_new_child(value, None, children_capacity)
+            .0
+    }
+    pub fn insert_child_at_parent(
+        &self,
+        child_value: N,
+        parent_value: &N,
+        index: usize,
+        children_capacity: usize,
+    ) -> Option<Arc<ArcTreeNode<N>>> {
+        // search always from root to make sure that parent will be found
+        self.get_root()
+            .get_node(parent_value)
+            .map(|parent| parent.insert_child(child_value, index, children_capacity))
+    }
+    pub fn insert_child(
+        &self,
+        value: N,
+        index: usize,
+        children_capacity: usize,
+    ) -> Arc<ArcTreeNode<N>> {
+        self.find_or_push_new_child(value, Some(index), children_capacity)
+            .0
+    }
+    pub fn add_unambiguous_child_to_parent(
+        &self,
+        child_value: N,
+        parent_value: &N,
+        children_capacity: usize,
+    ) -> Option<Arc<ArcTreeNode<N>>> {
+        self.get_root()
+            .get_node(parent_value)
+            .and_then(|parent| parent.add_unambiguous_child(child_value, children_capacity))
+    }
+    pub fn add_unambiguous_child(
+        &self,
+        value: N,
+        children_capacity: usize,
+    ) -> Option<Arc<ArcTreeNode<N>>> {
+        self.push_new_unambiguous_child(value, None, children_capacity)
+    }
+    pub fn insert_unambiguous_child_at_parent(
+        &self,
+        child_value: N,
+        parent_value: &N,
+        index: usize,
+        children_capacity: usize,
+    ) -> Option<Arc<ArcTreeNode<N>>> {
+        self.get_root().get_node(parent_value).and_then(|parent| {
+            parent.insert_unambiguous_child(child_value, index, children_capacity)
+        })
+    }
+    pub fn insert_unambiguous_child(
+        &self,
+        value: N,
+        index: usize,
+        children_capacity: usize,
+    ) -> Option<Arc<ArcTreeNode<N>>> {
+        self.push_new_unambiguous_child(value, Some(index), children_capacity)
+    }
+    pub fn swap_remove_child(&self, index: usize) -> Option<Arc<ArcTreeNode<N>>> {
+        let mut children = self.children.write().unwrap();
+        if index >= children.len() {
+            return None;
+        }
+        Some(children.swap_remove(index))
+    }
+    pub fn remove_child_by_value(&self, value: &N) -> Option<Arc<ArcTreeNode<N>>> {
+        // removes first child with value and keeps order of remaining children
+        let mut children = self.children.write().unwrap();
+        let index = children.iter().position(|c| *c.get_value() == *value)?;
+        let child = children.remove(index);
+        drop(children);
+        child.detach_from_parent();
+        Some(child)
+    }
+    pub fn remove_child_by_value_swap(&self, value: &N) -> Option<Arc<ArcTreeNode<N>>> {
+        // removes first child with value in O(1); last child takes position of removed child
+        let mut children = self.children.write().unwrap();
+        let index = children.iter().position(|c| *c.get_value() == *value)?;
+        let child = children.swap_remove(index);
+        drop(children);
+        child.detach_from_parent();
+        Some(child)
+    }
+    pub fn swap_remove_child_node(
+        &self,
+        child: &Arc<ArcTreeNode<N>>,
+    ) -> Option<Arc<ArcTreeNode<N>>> {
+        // removes child by identity in O(1); last child takes position of removed child
+        let mut children = self.children.write().unwrap();
+        let index = children.iter().position(|c| Arc::ptr_eq(c, child))?;
+        let child = children.swap_remove(index);
+        drop(children);
+        child.detach_from_parent();
+        Some(child)
+    }
+    fn detach_from_parent(&self) {
+        // removed child becomes root of its own subtree with level 0
+        *self.parent.write().unwrap() = ArcWeak::new();
+        let level = self.get_level();
+        for node in self.iter_pre_order_traversal() {
+            node.level
+                .store(node.get_level() - level, AtomicOrdering::Relaxed);
+        }
+    }
+    pub fn split_off_children(&self, at: usize, keep_split_off: bool) {
+        let mut children = self.children.write().unwrap();
+        let split_off = children.split_off(at);
+        if keep_split_off {
+            *children = split_off;
+        }
+    }
+    pub fn reserve_children(&self, additional_children: usize) {
+        // increases capacity of children by additional_children
+        self.children.write().unwrap().reserve(additional_children);
+    }
+    pub fn clear_children(&self, children_capacity: usize) {
+        *self.children.write().unwrap() = Vec::with_capacity(children_capacity);
+    }
+    pub fn clear_parent(&self) {
+        // removing parent makes this node to a root node. If no reference or variable exists, which holds at least
+        // one node above this node, then all nodes above this node are released from memory
+        *self.parent.write().unwrap() = ArcWeak::new();
+    }
+    pub fn get_value(&self) -> RwLockReadGuard<'_, N> {
+        self.value.read().unwrap()
+    }
+    pub fn get_mut_value(&self) -> RwLockWriteGuard<'_, N> {
+        self.value.write().unwrap()
+    }
+    pub fn get_level(&self) -> usize {
+        self.level.load(AtomicOrdering::Relaxed)
+    }
+    pub fn get_self(&self) -> Option<Arc<ArcTreeNode<N>>> {
+        self.node.upgrade()
+    }
+    pub fn get_child(&self, index: usize) -> Option<Arc<ArcTreeNode<N>>> {
+        self.children.read().unwrap().get(index).cloned()
+    }
+    pub fn len_children(&self) -> usize {
+        self.children.read().unwrap().len()
+    }
+    pub fn get_parent(&self) -> Option<Arc<ArcTreeNode<N>>> {
+        self.parent.read().unwrap().upgrade()
+    }
+    pub fn get_node(&self, value: &N) -> Option<Arc<ArcTreeNode<N>>> {
+        self.iter_pre_order_traversal()
+            .find(|n| *n.get_value() == *value)
+    }
+    pub fn get_root(&self) -> Arc<ArcTreeNode<N>> {
+        self.iter_back_track().last().unwrap()
+    }
+    pub fn least_common_ancestor(
+        a: Arc<ArcTreeNode<N>>,
+        b: Arc<ArcTreeNode<N>>,
+    ) -> Option<Arc<ArcTreeNode<N>>> {
+        // deepest node, which is on back track path of a and b. None, if a and b do not share a root
+        let path_a: Vec<Arc<ArcTreeNode<N>>> = a.iter_back_track().collect();
+        b.iter_back_track()
+            .filter(|n| n.get_level() <= a.get_level())
+            .find(|n| path_a.iter().any(|p| Arc::ptr_eq(p, n)))
+    }
+    pub fn find_path(
+        from: Arc<ArcTreeNode<N>>,
+        to: Arc<ArcTreeNode<N>>,
+    ) -> Option<Vec<Arc<ArcTreeNode<N>>>> {
+        // path from "from" over least common ancestor to "to", including both end points
+        let lca = ArcTreeNode::least_common_ancestor(from.clone(), to.clone())?;
+        let mut path: Vec<Arc<ArcTreeNode<N>>> = from
+            .iter_back_track()
+            .take_while(|n| !Arc::ptr_eq(n, &lca))
+            .collect();
+        path.push(lca.clone());
+        let mut down_path: Vec<Arc<ArcTreeNode<N>>> = to
+            .iter_back_track()
+            .take_while(|n| !Arc::ptr_eq(n, &lca))
+            .collect();
+        down_path.reverse();
+        path.append(&mut down_path);
+        Some(path)
+    }
+    pub fn swap_subtrees(
+        a: Arc<ArcTreeNode<N>>,
+        b: Arc<ArcTreeNode<N>>,
+    ) -> Result<(), &'static str> {
+        // a takes position of b in tree and vice versa; levels of both subtrees are updated
+        let root = a.get_root();
+        if !Arc::ptr_eq(&root, &b.get_root()) {
+            return Err("nodes are not in same tree");
+        }
+        let _tree_guard = root.tree_lock.lock().unwrap();
+        if a.iter_back_track().any(|n| Arc::ptr_eq(&n, &b))
+            || b.iter_back_track().any(|n| Arc::ptr_eq(&n, &a))
+        {
+            return Err("one node is ancestor of other node");
+        }
+        // since neither node is ancestor of the other, both have a parent
+        let parent_a = a.get_parent().unwrap();
+        let parent_b = b.get_parent().unwrap();
+        if Arc::ptr_eq(&parent_a, &parent_b) {
+            let mut children = parent_a.children.write().unwrap();
+            let index_a = children
+                .iter()
+                .position(|c| Arc::ptr_eq(c, &a))
+                .ok_or("node is not child of its parent")?;
+            let index_b = children
+                .iter()
+                .position(|c| Arc::ptr_eq(c, &b))
+                .ok_or("node is not child of its parent")?;
+            children.swap(index_a, index_b);
+            return Ok(());
+        }
+        {
+            let mut children_a = parent_a.children.write().unwrap();
+            let mut children_b = parent_b.children.write().unwrap();
+            let index_a = children_a
+                .iter()
+                .position(|c| Arc::ptr_eq(c, &a))
+                .ok_or("node is not child of its parent")?;
+            let index_b = children_b
+                .iter()
+                .position(|c| Arc::ptr_eq(c, &b))
+                .ok_or("node is not child of its parent")?;
+            children_a[index_a] = b.clone();
+            children_b[index_b] = a.clone();
+            *a.parent.write().unwrap() = Arc::downgrade(&parent_b);
+            *b.parent.write().unwrap() = Arc::downgrade(&parent_a);
+        }
+        let level_a = a.get_level();
+        let level_b = b.get_level();
+        for node in a.iter_pre_order_traversal() {
+            node.level.store(
+                node.get_level() + level_b - level_a,
+                AtomicOrdering::Relaxed,
+            );
+        }
+        for node in b.iter_pre_order_traversal() {
+            node.level.store(
+                node.get_level() + level_a - level_b,
+                AtomicOrdering::Relaxed,
+            );
+        }
+        Ok(())
+    }
+    pub fn is_root(&self) -> bool {
+        self.get_parent().is_none()
+    }
+    pub fn is_leave(&self) -> bool {
+        self.len_children() == 0
+    }
+    pub fn sort_children_by<F>(&self, compare: F)
+    where
+        F: Fn(&N, &N) -> Ordering,
+    {
+        self.children
+            .write()
+            .unwrap()
+            .sort_by(|a, b| compare(&a.get_value(), &b.get_value()));
+    }
+    pub fn get_max_level(&self) -> (usize, usize) {
+        // tuple of absolute level and relative level
+        self.get_root()
+            .iter_level_order_traversal()
+            .max_by_key(|(_, l)| *l)
+            .map(|(n, l)| (n.get_level(), l))
+            .unwrap()
+    }
+    pub fn height(&self) -> usize {
+        // max depth of subtree relative to this node; 0 for a leave
+        self.height_and_subtree_size().0
+    }
+    pub fn subtree_size(&self) -> usize {
+        // number of nodes in subtree including this node
+        self.height_and_subtree_size().1
+    }
+    pub fn height_and_subtree_size(&self) -> (usize, usize) {
+        // both metrics with a single post order traversal
+        let mut height = 0;
+        let mut subtree_size = 0;
+        for node in self.iter_post_order_traversal() {
+            height = height.max(node.get_level() - self.get_level());
+            subtree_size += 1;
+        }
+        (height, subtree_size)
+    }
+    pub fn memory_usage_bytes(&self) -> usize {
+        self.memory_usage_breakdown().total()
+    }
+    pub fn memory_usage_breakdown(&self) -> MemoryBreakdown {
+        // rc_overhead contains strong and weak counter of each Arc allocation
+        let mut breakdown = MemoryBreakdown::default();
+        for node in self.iter_pre_order_traversal() {
+            breakdown.node_data += std::mem::size_of::<ArcTreeNode<N>>();
+            breakdown.children += node.children.read().unwrap().capacity()
+                * std::mem::size_of::<Arc<ArcTreeNode<N>>>();
+            breakdown.rc_overhead += 2 * std::mem::size_of::<usize>();
+        }
+        breakdown
+    }
+    pub fn iter_self(&self) -> impl Iterator<Item = Arc<ArcTreeNode<N>>> {
+        // iterator over single node
+        self.get_self().into_iter()
+    }
+    pub fn iter_children(&self) -> impl Iterator<Item = Arc<ArcTreeNode<N>>> {
+        // snapshot of children, which does not hold a read guard
+        let children: Vec<Arc<ArcTreeNode<N>>> = self.children.read().unwrap().clone();
+        children.into_iter()
+    }
+    pub fn iter_siblings(&self) -> impl Iterator<Item = Arc<ArcTreeNode<N>>> {
+        // all children of parent except self; empty if node is root or parent has already been dropped
+        let siblings: Vec<Arc<ArcTreeNode<N>>> = match self.get_parent() {
+            Some(parent) => parent
+                .iter_children()
+                .filter(|c| !std::ptr::eq(Arc::as_ptr(c), self))
+                .collect(),
+            None => Vec::new(),
+        };
+        siblings.into_iter()
+    }
+    pub fn iter_back_track(&self) -> impl Iterator<Item = Arc<ArcTreeNode<N>>> {
+        ArcBackTrack {
+            next_node: self.get_self(),
+        }
+    }
+    pub fn iter_pre_order_traversal(&self) -> impl Iterator<Item = Arc<ArcTreeNode<N>>> {
+        ArcPreOrderTraversal {
+            stack: self.get_self().into_iter().collect(),
+        }
+    }
+    pub fn iter_post_order_traversal(&self) -> impl Iterator<Item = Arc<ArcTreeNode<N>>> {
+        ArcPostOrderTraversal {
+            stack: self.get_self().into_iter().map(|n| (n, false)).collect(),
+        }
+    }
+    // second return value is level of node relative to start node, from which iter_level_order_traversal() was called
+    pub fn iter_level_order_traversal(&self) -> impl Iterator<Item = (Arc<ArcTreeNode<N>>, usize)> {
+        ArcLevelOrderTraversal {
+            queue: self.get_self().into_iter().map(|n| (n, 0)).collect(),
+        }
+    }
+    // only descends to nodes with relative_depth to this node without visiting deeper levels
+    pub fn iter_nodes_at_depth(
+        &self,
+        relative_depth: usize,
+    ) -> impl Iterator<Item = Arc<ArcTreeNode<N>>> {
+        ArcNodesAtDepth {
+            stack: self.get_self().into_iter().map(|n| (n, 0)).collect(),
+            target_depth: relative_depth,
+        }
+    }
+    pub fn iter_level_order_traversal_with_borders(
+        &self,
+        start_level: usize,
+        end_level: Option<usize>,
+    ) -> impl Iterator<Item = (Arc<ArcTreeNode<N>>, usize)> {
+        if let Some(level) = end_level {
+            if start_level > level {
+                panic!("end_level must be >= start_level.");
+            }
+        }
+        self.iter_level_order_traversal()
+            .skip_while(move |(_, l)| *l < start_level)
+            .take_while(move |(_, l)| end_level.is_none_or(|end| *l <= end))
+    }
+}
+
+impl<N: PartialEq + Clone> ArcTreeNode<N> {
+    pub fn clone_subtree(&self) -> Arc<ArcTreeNode<N>> {
+        // deep copy of subtree starting at self. Copy of self is new root with level 0
+        let new_root = ArcTreeNode::seed_root(self.get_value().clone(), self.len_children());
+        let mut stack = vec![(self.get_self().unwrap(), new_root.clone())];
+        while let Some((source, target)) = stack.pop() {
+            for child in source.iter_children() {
+                let new_child =
+                    target.push_new_child(child.get_value().clone(), None, child.len_children());
+                stack.push((child, new_child));
+            }
+        }
+        new_root
+    }
+}
+
+// converts subtree starting at tree into an ArcTreeNode tree. tree becomes root with level 0
+pub fn to_arc_tree<N: PartialEq + Clone + Send + Sync>(
+    tree: Rc<TreeNode<N>>,
+) -> Arc<ArcTreeNode<N>> {
+    let arc_root = ArcTreeNode::seed_root(tree.get_value().clone(), tree.len_children());
+    let mut stack = vec![(tree, arc_root.clone())];
+    while let Some((source, target)) = stack.pop() {
+        for child in source.iter_children() {
+            let new_child =
+                target.push_new_child(child.get_value().clone(), None, child.len_children());
+            stack.push((child, new_child));
+        }
+    }
+    arc_root
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_arc_tree_node() {
+        let test_tree = ArcTreeNode::seed_root('A', 2);
+        let child_b = test_tree.add_child('B', 2);
+        let child_c = test_tree.add_child('C', 1);
+        child_b.add_child('D', 0);
+        child_b.add_child('E', 0);
+        child_c.add_child('F', 0);
+        assert!(test_tree.add_unambiguous_child('D', 0).is_none());
+        child_c.insert_child('G', 0, 0);
+
+        let pre_order: Vec<char> = test_tree
+            .iter_pre_order_traversal()
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(pre_order, vec!['A', 'B', 'D', 'E', 'C', 'G', 'F']);
+        let post_order: Vec<char> = test_tree
+            .iter_post_order_traversal()
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(post_order, vec!['D', 'E', 'B', 'G', 'F', 'C', 'A']);
+        let level_order: Vec<char> = test_tree
+            .iter_level_order_traversal_with_borders(1, Some(1))
+            .map(|(n, _)| *n.get_value())
+            .collect();
+        assert_eq!(level_order, vec!['B', 'C']);
+        let child_f = test_tree.get_node(&'F').unwrap();
+        let back_track: Vec<char> = child_f.iter_back_track().map(|n| *n.get_value()).collect();
+        assert_eq!(back_track, vec!['F', 'C', 'A']);
+        let siblings: Vec<char> = child_b.iter_siblings().map(|n| *n.get_value()).collect();
+        assert_eq!(siblings, vec!['C']);
+        assert_eq!(test_tree.height(), 2);
+        assert_eq!(test_tree.subtree_size(), 7);
+        assert_eq!(child_f.get_max_level(), (2, 2));
+        assert!(Arc::ptr_eq(&child_f.get_root(), &test_tree));
+
+        // concurrent reads and writes
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let tree = test_tree.clone();
+                thread::spawn(move || {
+                    let node = tree.get_node(&'E').unwrap();
+                    *node.get_mut_value() = 'E';
+                    tree.get_node(&'G')
+                        .unwrap()
+                        .add_child((b'0' + i) as char, 0);
+                    tree.subtree_size()
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle.join().unwrap() >= 8);
+        }
+        assert_eq!(test_tree.subtree_size(), 11);
+    }
+
+    #[test]
+    fn test_arc_tree_node_concurrent_add() {
+        let test_tree = ArcTreeNode::seed_root(0_u32, 8);
+        let children: Vec<_> = (1..=8).map(|v| test_tree.add_child(v, 8)).collect();
+        // every thread tries to add the same values; each value must be added exactly once
+        let handles: Vec<_> = children
+            .into_iter()
+            .map(|child| {
+                let tree = test_tree.clone();
+                thread::spawn(move || {
+                    let mut added = 0;
+                    for value in 100..200 {
+                        if child.add_unambiguous_child(value, 0).is_some() {
+                            added += 1;
+                        }
+                        tree.add_child(1_000 + value, 0);
+                    }
+                    added
+                })
+            })
+            .collect();
+        let added: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(added, 100);
+        for value in 100..200 {
+            assert_eq!(
+                test_tree
+                    .iter_pre_order_traversal()
+                    .filter(|n| *n.get_value() == value)
+                    .count(),
+                1
+            );
+        }
+        assert_eq!(test_tree.len_children(), 108);
+    }
+
+    #[test]
+    fn test_arc_tree_node_navigation() {
+        let test_tree = ArcTreeNode::seed_root('A', 2);
+        let child_b = test_tree.add_child('B', 2);
+        let child_c = test_tree.add_child('C', 1);
+        let child_d = child_b.add_child('D', 1);
+        child_b.add_child('E', 0);
+        let child_f = child_c.add_child('F', 0);
+        let child_g = child_d.add_child('G', 0);
+
+        let lca = ArcTreeNode::least_common_ancestor(child_g.clone(), child_f.clone()).unwrap();
+        assert!(Arc::ptr_eq(&lca, &test_tree));
+        let path: Vec<char> = ArcTreeNode::find_path(child_g.clone(), child_f.clone())
+            .unwrap()
+            .iter()
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(path, vec!['G', 'D', 'B', 'A', 'C', 'F']);
+        let depth_two: Vec<char> = test_tree
+            .iter_nodes_at_depth(2)
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(depth_two, vec!['D', 'E', 'F']);
+        assert_eq!(child_c.iter_self().count(), 1);
+        assert_eq!(test_tree.height_and_subtree_size(), (3, 7));
+        assert_eq!(
+            test_tree.memory_usage_bytes(),
+            test_tree.memory_usage_breakdown().total()
+        );
+
+        assert!(ArcTreeNode::swap_subtrees(child_b.clone(), child_g.clone()).is_err());
+        ArcTreeNode::swap_subtrees(child_d.clone(), child_f.clone()).unwrap();
+        assert!(Arc::ptr_eq(&child_d.get_parent().unwrap(), &child_c));
+        assert_eq!(child_g.get_level(), 3);
+        assert_eq!(child_f.get_level(), 2);
+        assert_eq!(*child_b.get_child(0).unwrap().get_value(), 'F');
+
+        assert_eq!(
+            *child_b.remove_child_by_value(&'F').unwrap().get_value(),
+            'F'
+        );
+        assert_eq!(*child_b.get_child(0).unwrap().get_value(), 'E');
+        assert!(child_b.remove_child_by_value_swap(&'X').is_none());
+        assert_eq!(
+            *child_b
+                .remove_child_by_value_swap(&'E')
+                .unwrap()
+                .get_value(),
+            'E'
+        );
+        assert!(child_b.is_leave());
+        assert!(Arc::ptr_eq(
+            &child_c.swap_remove_child_node(&child_d).unwrap(),
+            &child_d
+        ));
+        assert!(child_c.swap_remove_child_node(&child_d).is_none());
+        // removed children are detached from tree
+        assert!(child_f.get_parent().is_none());
+        assert_eq!(child_f.get_level(), 0);
+        assert!(child_d.get_parent().is_none());
+        assert_eq!(child_d.get_level(), 0);
+        assert_eq!(child_g.get_level(), 1);
+    }
+
+    #[test]
+    fn test_to_arc_tree() {
+        let test_tree = TreeNode::seed_root('A', 2);
+        let child_b = test_tree.add_child('B', 2);
+        test_tree.add_child('C', 0);
+        child_b.add_child('D', 0);
+        let arc_tree = to_arc_tree(test_tree.clone());
+        let values: Vec<char> = arc_tree
+            .iter_pre_order_traversal()
+            .map(|n| *n.get_value())
+            .collect();
+        assert_eq!(values, vec!['A', 'B', 'D', 'C']);
+        let arc_d = arc_tree.get_node(&'D').unwrap();
+        assert_eq!(arc_d.get_level(), 2);
+        assert_eq!(*arc_d.get_parent().unwrap().get_value(), 'B');
+        let clone = arc_d.get_parent().unwrap().clone_subtree();
+        assert!(clone.is_root());
+        assert_eq!(clone.subtree_size(), 2);
+    }
+}
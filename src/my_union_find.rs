@@ -0,0 +1,50 @@
+// disjoint set of indices 0..len; used for connected components and Kruskal's algorithm
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parents: (0..len).collect(),
+        }
+    }
+    pub fn find(&mut self, mut index: usize) -> usize {
+        // root of index with path halving
+        while self.parents[index] != index {
+            self.parents[index] = self.parents[self.parents[index]];
+            index = self.parents[index];
+        }
+        index
+    }
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        // returns false, if a and b are already in same set; smaller root becomes root of merged set
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return false;
+        }
+        self.parents[root_a.max(root_b)] = root_a.min(root_b);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut union_find = UnionFind::new(6);
+        assert!(union_find.union(4, 5));
+        assert!(union_find.union(5, 2));
+        assert!(!union_find.union(4, 2));
+        assert!(union_find.union(0, 1));
+        assert_eq!(union_find.find(4), 2);
+        assert_eq!(union_find.find(5), union_find.find(2));
+        assert_ne!(union_find.find(1), union_find.find(2));
+        assert_eq!(union_find.find(3), 3);
+    }
+}